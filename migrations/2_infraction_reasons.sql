@@ -0,0 +1,2 @@
+alter table history
+    add column reason varchar(2000) not null default '';
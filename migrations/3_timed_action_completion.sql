@@ -0,0 +1,12 @@
+alter table timedAction
+    add column completed bool not null default false;
+
+-- views don't pick up new columns on their own, so this one also needs to be rebuilt to include the reasons
+drop view pendingActions;
+
+create view pendingActions as
+select h.*
+from timedAction
+         inner join history h on timedAction.history_id = h.id
+where not timedAction.completed
+order by "end";
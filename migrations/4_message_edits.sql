@@ -0,0 +1,2 @@
+alter table message
+    add column edits int not null default 0;
@@ -0,0 +1,19 @@
+create type configSource as enum (
+    'command',
+    'api',
+    'migration'
+    );
+
+-- every row is a config as it was right before it got replaced, together with who replaced it and how
+create table guildconfig_history
+(
+    id        serial primary key not null,
+    guild_id  bigint             not null,
+    revision  int                not null,
+    config    jsonb              not null,
+    author_id bigint             not null,
+    source    configSource       not null,
+    replaced  timestamptz        not null default now()
+);
+
+create unique index guildconfig_history_revision_unique on guildconfig_history (guild_id, revision);
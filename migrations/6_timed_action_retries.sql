@@ -0,0 +1,4 @@
+-- timed actions that couldn't be carried out are tried again later, waiting longer after every failed attempt
+alter table timedAction
+    add column attempts     int         not null default 0,
+    add column next_attempt timestamptz null;
@@ -209,6 +209,20 @@ impl CachedChannel {
         }
     }
 
+    /// The category this channel is in, if any.
+    pub fn get_parent_id(&self) -> Option<ChannelId> {
+        match self {
+            CachedChannel::TextChannel { parent_id, .. } => *parent_id,
+            CachedChannel::DM { .. } => None,
+            CachedChannel::VoiceChannel { parent_id, .. } => *parent_id,
+            CachedChannel::GroupDM { .. } => None,
+            CachedChannel::Category { .. } => None,
+            CachedChannel::AnnouncementsChannel { parent_id, .. } => *parent_id,
+            CachedChannel::StoreChannel { parent_id, .. } => *parent_id,
+            CachedChannel::StageChannel { parent_id, .. } => *parent_id,
+        }
+    }
+
     pub fn is_nsfw(&self) -> bool {
         match self {
             CachedChannel::TextChannel { nsfw, .. } => *nsfw,
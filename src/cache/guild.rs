@@ -242,6 +242,20 @@ impl CachedGuild {
     pub async fn get_member(&self, user_id: &UserId) -> Option<Arc<CachedMember>> {
         self.members.read().await.get(user_id).cloned()
     }
+
+    /// Returns the position of the highest role of a member.
+    ///
+    /// Members without any roles only have @everyone, which always sits at position 0.
+    pub async fn get_top_role_position(&self, member: &CachedMember) -> i64 {
+        let roles = self.roles.read().await;
+        member
+            .roles
+            .iter()
+            .filter_map(|role_id| roles.get(role_id))
+            .map(|role| role.position)
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1,12 +1,142 @@
-use crate::core::CommandContext;
+use std::sync::Arc;
+
+use twilight_embed_builder::{EmbedAuthorBuilder, EmbedBuilder, EmbedFieldBuilder};
+
+use crate::commands::meta::arguments;
+use crate::commands::meta::nodes::{CommandGroup, CommandNode};
+use crate::commands::ROOT_NODE;
+use crate::core::reactors::{command_line, gen_help_page, help_pages};
+use crate::core::{CommandContext, Reactor};
 use crate::error::CommandResult;
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+pub async fn help(mut ctx: CommandContext) -> CommandResult {
+    if !ctx.parser.has_next() {
+        return reply_with_pages(&ctx, None, "").await;
+    }
+
+    let query = ctx.parser.get_remaining();
+    let mut nodes: Vec<Arc<CommandNode>> = vec![];
+    while ctx.parser.has_next() {
+        let name = ctx.parser.get_next()?.to_string();
+        let found = match nodes.last() {
+            Some(parent) => parent.sub_nodes.get(&name),
+            None => ROOT_NODE.all_commands.get(&name),
+        }
+        .cloned();
+
+        // commands they can't use are treated like they don't exist
+        match found.filter(|node| node.is_visible_to(ctx.permissions)) {
+            Some(node) => nodes.push(node),
+            None => {
+                if nodes.is_empty() && !ctx.parser.has_next() {
+                    if let Some(group) = ROOT_NODE.find_group(&name) {
+                        return reply_with_pages(&ctx, Some(group.clone()), &query).await;
+                    }
+                }
+
+                return reply_not_found(&ctx, &query).await;
+            }
+        }
+    }
 
-pub async fn help(ctx: CommandContext) -> CommandResult {
-    if let Some(_thing) = ctx.parser.peek() {
-        // user is asking about something
-    } else {
-        // list everything
+    reply_with_command(&ctx, &nodes).await
+}
+
+async fn reply_with_pages(ctx: &CommandContext, group: Option<CommandGroup>, query: &str) -> CommandResult {
+    let pages = help_pages(ctx.permissions, group.as_ref());
+    if pages.is_empty() {
+        return reply_not_found(ctx, query).await;
+    }
+
+    let embed = gen_help_page(&ctx.bot_context, &ctx.get_config()?, &pages, 0)?;
+    let message = ctx.reply_embed(embed).await?;
+
+    if pages.len() > 1 {
+        let reactor = Reactor::new_help(ctx.message.author.id, ctx.permissions, group);
+        reactor.save(&ctx.bot_context, message.id).await?;
+
+        ctx.bot_context
+            .http
+            .create_reaction(message.channel_id, message.id, Emoji::Left.to_reaction())
+            .await?;
+
+        ctx.bot_context
+            .http
+            .create_reaction(message.channel_id, message.id, Emoji::Right.to_reaction())
+            .await?;
     }
 
     Ok(())
 }
+
+/// Explains the last of the nodes, the others are the commands it is a subcommand of.
+async fn reply_with_command(ctx: &CommandContext, nodes: &[Arc<CommandNode>]) -> CommandResult {
+    let config = ctx.get_config()?;
+    let lang = &config.language;
+    let command = nodes.last().unwrap();
+    let names: Vec<&str> = nodes.iter().map(|node| node.name.as_str()).collect();
+    let path = names.join("__");
+    let invocation = format!("{}{}", config.prefix, names.join(" "));
+
+    let translations = &ctx.bot_context.translations;
+    let header_args = FluArgs::with_capacity(1).add("command", names.join(" ")).generate();
+    let mut embed = EmbedBuilder::new()
+        .author(
+            EmbedAuthorBuilder::new().name(ctx.translate_with_args(GearBotString::HelpCommandHeader, &header_args))?,
+        )
+        .description(translations.get_command_description(lang, &path).unwrap_or_default())?;
+
+    // without a handler there is nothing to use, only subcommands
+    if command.handler.is_some() {
+        let usage = if command.arguments.is_empty() {
+            format!("``{}``", invocation)
+        } else {
+            format!(
+                "``{} {}``",
+                invocation,
+                arguments::usage(&command.arguments, translations, lang, &path)
+            )
+        };
+        embed = embed.field(EmbedFieldBuilder::new(ctx.translate(GearBotString::HelpUsage), usage)?.build());
+    }
+
+    if !command.aliases.is_empty() {
+        let aliases: Vec<String> = command.aliases.iter().map(|alias| format!("``{}``", alias)).collect();
+        embed =
+            embed.field(EmbedFieldBuilder::new(ctx.translate(GearBotString::HelpAliases), aliases.join(", "))?.build());
+    }
+
+    let sub_nodes = command.visible_sub_nodes(ctx.permissions);
+    if !sub_nodes.is_empty() {
+        let lines: Vec<String> = sub_nodes
+            .iter()
+            .map(|node| {
+                command_line(
+                    &ctx.bot_context,
+                    lang,
+                    &format!("{} ", invocation),
+                    &format!("{}__{}", path, node.name),
+                    node,
+                )
+            })
+            .collect();
+        embed = embed
+            .field(EmbedFieldBuilder::new(ctx.translate(GearBotString::HelpSubcommands), lines.join("\n"))?.build());
+    }
+
+    ctx.reply_embed(embed.build()?).await?;
+
+    Ok(())
+}
+
+async fn reply_not_found(ctx: &CommandContext, query: &str) -> CommandResult {
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("query", query.to_string())
+        .generate();
+    ctx.reply(GearBotString::HelpNotFound, args).await?;
+
+    Ok(())
+}
@@ -1,20 +1,14 @@
-use twilight_model::id::MessageId;
-
 use crate::core::CommandContext;
-use crate::error::{CommandResult, ParseError};
+use crate::error::CommandResult;
 use crate::translation::{FluArgs, GearBotString};
 use crate::utils;
 
 pub async fn quote(mut ctx: CommandContext) -> CommandResult {
-    let msg_id = ctx
-        .parser
-        .get_next()?
-        .parse::<u64>()
-        .map_err(|_| ParseError::MissingArgument)?;
+    let msg_id = ctx.parser.get_message_id()?;
 
     let guild_id = ctx.get_guild().id;
 
-    match ctx.bot_context.fetch_user_message(MessageId(msg_id), guild_id).await? {
+    match ctx.bot_context.fetch_user_message(msg_id, guild_id).await? {
         Some(msg) => {
             let message = utils::clean(&msg.content, true, true, false, false);
             ctx.reply_raw(message).await?;
@@ -1,10 +1,33 @@
-use crate::core::{CommandContext, GuildConfig};
-use crate::error::{CommandResult, DatabaseError};
+use serde_json::Value;
 
-pub async fn get_config(ctx: CommandContext) -> CommandResult {
-    let stringified_config = serde_json::to_string(&ctx.get_config()?).map_err(DatabaseError::Deserializing)?;
+use twilight_model::id::{ChannelId, RoleId};
 
-    ctx.reply_raw(stringified_config).await?;
+use crate::commands::meta::arguments;
+use crate::core::config_transfer::{ConfigExport, ImportedId};
+use crate::core::{config_editing, config_migrations, CommandContext, GuildConfig};
+use crate::database::structures::ConfigRevision;
+use crate::error::{CommandError, CommandResult, DatabaseError, ParseError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::{self, Emoji};
+
+/// Leaves room for the rest of the reply around the diff.
+const DIFF_LENGTH: usize = 1800;
+/// Exported configs are nowhere near this big, anything that is can't be one.
+const MAX_IMPORT_KB: u64 = 256;
+
+pub async fn get_config(mut ctx: CommandContext) -> CommandResult {
+    if !ctx.parser.has_next() {
+        let stringified_config = serde_json::to_string(&ctx.get_config()?).map_err(DatabaseError::Deserializing)?;
+        ctx.reply_raw(stringified_config).await?;
+        return Ok(());
+    }
+
+    let path = ctx.parser.get_next()?.to_string();
+    let config = serde_json::to_value(&*ctx.get_config()?).map_err(DatabaseError::Deserializing)?;
+    let value = config_editing::get(&config, &path)?;
+    let stringified_value = serde_json::to_string_pretty(value).map_err(DatabaseError::Deserializing)?;
+
+    ctx.reply_raw(format!("```json\n{}```", stringified_value)).await?;
 
     Ok(())
 }
@@ -18,15 +41,335 @@ pub async fn get_config_pretty(ctx: CommandContext) -> CommandResult {
 }
 
 pub async fn set_config(mut ctx: CommandContext) -> CommandResult {
-    let config: GuildConfig =
-        serde_json::from_str(&ctx.parser.get_remaining()).map_err(DatabaseError::Deserializing)?;
-    ctx.set_config(config).await?;
-    ctx.reply_raw("Config updated").await?;
+    let path = ctx.parser.get_next()?.to_string();
+    let input = ctx.parser.get_text()?;
+
+    edit_config(&ctx, Some(&path), |config| {
+        let value = config_editing::parse_value(&input, config_editing::get(config, &path).ok());
+        config_editing::set(config, &path, value)
+    })
+    .await
+}
+
+pub async fn add_config(mut ctx: CommandContext) -> CommandResult {
+    let path = ctx.parser.get_next()?.to_string();
+    let input = ctx.parser.get_text()?;
+
+    edit_config(&ctx, Some(&path), |config| {
+        let value = config_editing::parse_value(&input, first_entry(config, &path));
+        config_editing::add_to_list(config, &path, value)
+    })
+    .await
+}
+
+pub async fn remove_config(mut ctx: CommandContext) -> CommandResult {
+    let path = ctx.parser.get_next()?.to_string();
+    let input = ctx.parser.get_text()?;
+
+    edit_config(&ctx, Some(&path), |config| {
+        let value = config_editing::parse_value(&input, first_entry(config, &path));
+        config_editing::remove_from_list(config, &path, &value)
+    })
+    .await
+}
+
+pub async fn reset_config(mut ctx: CommandContext) -> CommandResult {
+    let defaults = serde_json::to_value(GuildConfig::default()).map_err(DatabaseError::Deserializing)?;
+
+    if !ctx.parser.has_next() {
+        return edit_config(&ctx, None, |config| {
+            *config = defaults;
+            Ok(())
+        })
+        .await;
+    }
+
+    let path = ctx.parser.get_next()?.to_string();
+    edit_config(&ctx, None, |config| config_editing::reset(config, &defaults, &path)).await
+}
+
+pub async fn config_history(mut ctx: CommandContext) -> CommandResult {
+    let page = if ctx.parser.has_next() {
+        ctx.parser.get_number(1..=u32::MAX)? - 1
+    } else {
+        0
+    };
+
+    let revisions = ctx
+        .bot_context
+        .datastore
+        .get_config_revisions(ctx.get_guild().id, page)
+        .await?;
+    if revisions.is_empty() {
+        ctx.reply_raw(ctx.translate(GearBotString::ConfigHistoryEmpty)).await?;
+        return Ok(());
+    }
+
+    let args = FluArgs::with_capacity(1).add("page", page + 1).generate();
+    let mut content = ctx.translate_with_args(GearBotString::ConfigHistoryHeader, &args);
+    for revision in &revisions {
+        content += &format!(
+            "\n``[{}]`` {} | by <@{}> | {}",
+            revision.revision,
+            revision.replaced.format("%F %T"),
+            revision.author,
+            revision.source.get_name()
+        );
+    }
+
+    ctx.reply_raw(content).await?;
+
+    Ok(())
+}
+
+pub async fn config_diff(mut ctx: CommandContext) -> CommandResult {
+    let number = get_revision_number(&mut ctx)?;
+    let old = match get_revision(&ctx, number).await? {
+        Some(revision) => revision_config(revision)?,
+        None => return reply_revision_not_found(&ctx, number).await,
+    };
+    let current = serde_json::to_value(&*ctx.get_config()?).map_err(DatabaseError::Deserializing)?;
+
+    let lines = config_editing::diff(&old, &current);
+    if lines.is_empty() {
+        let args = FluArgs::with_capacity(1).add("revision", number).generate();
+        ctx.reply(GearBotString::ConfigRevisionSame, args).await?;
+        return Ok(());
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("revision", number)
+        .add("diff", config_editing::shorten_diff(lines, DIFF_LENGTH))
+        .generate();
+    ctx.reply(GearBotString::ConfigRevisionDiff, args).await?;
+
     Ok(())
 }
 
-pub async fn reset_config(ctx: CommandContext) -> CommandResult {
-    ctx.set_config(GuildConfig::default()).await?;
-    ctx.reply_raw("Config reset to default").await?;
+/// Puts an old revision back, the config it replaces ends up in the history as well so this can be undone in turn.
+pub async fn config_rollback(mut ctx: CommandContext) -> CommandResult {
+    let number = get_revision_number(&mut ctx)?;
+    let old = match get_revision(&ctx, number).await? {
+        Some(revision) => revision_config(revision)?,
+        None => return reply_revision_not_found(&ctx, number).await,
+    };
+
+    edit_config(&ctx, None, |config| {
+        *config = old;
+        Ok(())
+    })
+    .await
+}
+
+pub async fn export_config(ctx: CommandContext) -> CommandResult {
+    let (roles, channels) = guild_names(&ctx).await;
+    let export = ConfigExport::new(&ctx.get_config()?, &roles, &channels).map_err(DatabaseError::Serializing)?;
+    let file = serde_json::to_vec_pretty(&export).map_err(DatabaseError::Serializing)?;
+
+    let args = FluArgs::with_capacity(1)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .generate();
+    let name = format!("config-{}.json", ctx.get_guild().id);
+    ctx.reply_with_attachment(GearBotString::ConfigExported, args, name, file)
+        .await?;
+
+    Ok(())
+}
+
+/// Replaces the config with an exported one. Roles and channels that aren't on this server are swapped for the ones
+/// with the same name, or left out, and the reply says which.
+pub async fn import_config(ctx: CommandContext) -> CommandResult {
+    let attachment = match ctx.message.attachments.first() {
+        Some(attachment) => attachment,
+        None => {
+            let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
+            ctx.reply(GearBotString::ConfigImportNoFile, args).await?;
+            return Ok(());
+        }
+    };
+
+    if attachment.size > MAX_IMPORT_KB * 1024 {
+        let args = FluArgs::with_capacity(2)
+            .add("gearno", Emoji::No.for_chat())
+            .add("max", MAX_IMPORT_KB)
+            .generate();
+        ctx.reply(GearBotString::ConfigImportTooBig, args).await?;
+        return Ok(());
+    }
+
+    let file = utils::download(&attachment.url).await?;
+    let export: ConfigExport =
+        serde_json::from_slice(&file).map_err(|e| ParseError::InvalidConfigFile(e.to_string()))?;
+    let (roles, channels) = guild_names(&ctx).await;
+    let (config, report) = export.import(&roles, &channels)?;
+    let new = serde_json::to_value(&config).map_err(DatabaseError::Serializing)?;
+
+    edit_config(&ctx, None, |current| {
+        *current = new;
+        Ok(())
+    })
+    .await?;
+
+    let matched: Vec<String> = report
+        .roles
+        .iter()
+        .filter_map(|role| describe_import(role, "@&"))
+        .chain(
+            report
+                .channels
+                .iter()
+                .filter_map(|channel| describe_import(channel, "#")),
+        )
+        .collect();
+    if !matched.is_empty() {
+        let args = FluArgs::with_capacity(1)
+            .add("matched", config_editing::shorten_diff(matched, DIFF_LENGTH))
+            .generate();
+        ctx.reply(GearBotString::ConfigImportMatched, args).await?;
+    }
+
+    let dropped: Vec<String> = report
+        .roles
+        .iter()
+        .chain(&report.channels)
+        .filter(|imported| imported.replacement.is_none())
+        .map(describe_id)
+        .collect();
+    if !dropped.is_empty() {
+        let args = FluArgs::with_capacity(2)
+            .add("gearwarn", Emoji::Warn.for_chat())
+            .add("dropped", config_editing::shorten_diff(dropped, DIFF_LENGTH))
+            .generate();
+        ctx.reply(GearBotString::ConfigImportDropped, args).await?;
+    }
+
+    if !report.permission_groups.is_empty() {
+        let args = FluArgs::with_capacity(2)
+            .add("gearwarn", Emoji::Warn.for_chat())
+            .add(
+                "groups",
+                config_editing::shorten_diff(report.permission_groups, DIFF_LENGTH),
+            )
+            .generate();
+        ctx.reply(GearBotString::ConfigImportDroppedGroups, args).await?;
+    }
+
+    Ok(())
+}
+
+async fn guild_names(ctx: &CommandContext) -> (Vec<(RoleId, String)>, Vec<(ChannelId, String)>) {
+    let guild = ctx.get_guild();
+    let roles = guild
+        .roles
+        .read()
+        .await
+        .values()
+        .map(|role| (role.id, role.name.clone()))
+        .collect();
+    let channels = guild
+        .channels
+        .read()
+        .await
+        .values()
+        .map(|channel| (channel.get_id(), channel.get_name().to_string()))
+        .collect();
+
+    (roles, channels)
+}
+
+/// Says what a role or channel was swapped for, `mention` is what goes in front of the id to mention it.
+fn describe_import(imported: &ImportedId, mention: &str) -> Option<String> {
+    imported
+        .replacement
+        .map(|replacement| format!("{} -> <{}{}>", describe_id(imported), mention, replacement))
+}
+
+fn describe_id(imported: &ImportedId) -> String {
+    match &imported.name {
+        Some(name) => format!("``{}`` (``{}``)", name.replace('`', "ˋ"), imported.id),
+        None => format!("``{}``", imported.id),
+    }
+}
+
+fn get_revision_number(ctx: &mut CommandContext) -> Result<i32, ParseError> {
+    arguments::parse_number(ctx.parser.get_next()?.trim_start_matches('#'), 1..=i32::MAX)
+}
+
+async fn get_revision(ctx: &CommandContext, number: i32) -> Result<Option<ConfigRevision>, CommandError> {
+    Ok(ctx
+        .bot_context
+        .datastore
+        .get_config_revision(ctx.get_guild().id, number)
+        .await?)
+}
+
+async fn reply_revision_not_found(ctx: &CommandContext, number: i32) -> CommandResult {
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("revision", number)
+        .generate();
+    ctx.reply(GearBotString::ConfigRevisionNotFound, args).await?;
+
+    Ok(())
+}
+
+/// Old revisions are in whatever format the config had back then, they're migrated and go through the current format
+/// so they compare to the current config.
+fn revision_config(revision: ConfigRevision) -> Result<Value, CommandError> {
+    let mut value = revision.config;
+    config_migrations::migrate(&mut value).map_err(|e| ParseError::InvalidConfigValue(e.to_string()))?;
+    let config: GuildConfig =
+        serde_json::from_value(value).map_err(|e| ParseError::InvalidConfigValue(e.to_string()))?;
+
+    Ok(serde_json::to_value(&config).map_err(DatabaseError::Deserializing)?)
+}
+
+/// Lists are typed after what is already in them.
+fn first_entry<'v>(config: &'v Value, path: &str) -> Option<&'v Value> {
+    config_editing::get(config, &format!("{}.0", path)).ok()
+}
+
+/// Applies an edit to the serde representation of the config, and only saves it if it still makes a valid config.
+///
+/// When a path is given it has to survive the round trip, otherwise serde silently dropped a typo. Whether the config
+/// makes sense for the guild is checked when saving it.
+async fn edit_config<F>(ctx: &CommandContext, path: Option<&str>, edit: F) -> CommandResult
+where
+    F: FnOnce(&mut Value) -> Result<(), ParseError>,
+{
+    let old = serde_json::to_value(&*ctx.get_config()?).map_err(DatabaseError::Deserializing)?;
+
+    let mut new = old.clone();
+    edit(&mut new)?;
+    let new_config: GuildConfig =
+        serde_json::from_value(new).map_err(|e| ParseError::InvalidConfigValue(e.to_string()))?;
+    let new = serde_json::to_value(&new_config).map_err(DatabaseError::Deserializing)?;
+    if let Some(path) = path {
+        config_editing::get(&new, path)?;
+    }
+
+    let lines = config_editing::diff(&old, &new);
+    if lines.is_empty() {
+        let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
+        ctx.reply(GearBotString::ConfigUnchanged, args).await?;
+        return Ok(());
+    }
+
+    let warnings = ctx.set_config(new_config).await?;
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("diff", config_editing::shorten_diff(lines, DIFF_LENGTH))
+        .generate();
+    ctx.reply(GearBotString::ConfigUpdated, args).await?;
+
+    if !warnings.is_empty() {
+        let args = FluArgs::with_capacity(2)
+            .add("gearwarn", Emoji::Warn.for_chat())
+            .add("warnings", ctx.issue_list(&warnings))
+            .generate();
+        ctx.reply(GearBotString::ConfigWarnings, args).await?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,242 @@
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use chrono::Duration;
+use twilight_model::id::{GuildId, MessageId};
+use unic_langid::LanguageIdentifier;
+
+use crate::error::ParseError;
+use crate::translation::Translations;
+use crate::utils::matchers;
+
+/// One of the arguments a command takes. Commands declare these on their node so help can show how they are used, the
+/// parser has a matching getter for every type.
+pub struct Argument {
+    /// Also used to look up the translated name, under `command__<command>__arg__<name>`.
+    pub name: &'static str,
+    pub kind: ArgumentType,
+    pub optional: bool,
+}
+
+pub enum ArgumentType {
+    User,
+    Member,
+    Role,
+    Channel,
+    Duration,
+    /// A message id or a jump link to a message.
+    Message,
+    Number {
+        min: i64,
+        max: Option<i64>,
+    },
+    Choice(&'static [&'static str]),
+    /// A single word, or multiple between quotes.
+    Word,
+    /// Everything that is left, so this can only be the last argument.
+    Text,
+}
+
+impl Argument {
+    pub const fn required(name: &'static str, kind: ArgumentType) -> Self {
+        Argument {
+            name,
+            kind,
+            optional: false,
+        }
+    }
+
+    pub const fn optional(name: &'static str, kind: ArgumentType) -> Self {
+        Argument {
+            name,
+            kind,
+            optional: true,
+        }
+    }
+
+    /// The name in the language, `command` being the path of the command it belongs to. Falls back to the name it
+    /// was declared with.
+    pub fn translated_name(&self, translations: &Translations, lang: &LanguageIdentifier, command: &str) -> String {
+        translations
+            .get_command_argument(lang, command, self.name)
+            .unwrap_or_else(|| self.name.to_string())
+    }
+
+    /// What goes between the brackets in the usage, also used as the description of slash command options.
+    pub fn description(&self, translations: &Translations, lang: &LanguageIdentifier, command: &str) -> String {
+        let name = self.translated_name(translations, lang, command);
+        match &self.kind {
+            ArgumentType::Number { min, max: Some(max) } => format!("{} ({}-{})", name, min, max),
+            ArgumentType::Choice(choices) => choices.join("|"),
+            ArgumentType::Text => format!("{}...", name),
+            _ => name,
+        }
+    }
+
+    pub fn usage(&self, translations: &Translations, lang: &LanguageIdentifier, command: &str) -> String {
+        let description = self.description(translations, lang, command);
+        if self.optional {
+            format!("[{}]", description)
+        } else {
+            format!("<{}>", description)
+        }
+    }
+}
+
+/// How the arguments are typed after the command, like `<member> <duration> [reason...]`.
+pub fn usage(arguments: &[Argument], translations: &Translations, lang: &LanguageIdentifier, command: &str) -> String {
+    arguments
+        .iter()
+        .map(|argument| argument.usage(translations, lang, command))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Enums that can be given as an argument by one of their names.
+pub trait ArgumentChoice: Sized {
+    /// The names, in the order they are listed in the usage.
+    const CHOICES: &'static [&'static str];
+
+    /// Gets the variant for a name, which is always one of the choices in lowercase.
+    fn from_choice(choice: &str) -> Option<Self>;
+}
+
+pub fn parse_number<T>(input: &str, range: RangeInclusive<T>) -> Result<T, ParseError>
+where
+    T: FromStr + PartialOrd + Display,
+{
+    let out_of_range = || ParseError::NumberOutOfRange {
+        input: input.to_string(),
+        min: range.start().to_string(),
+        max: range.end().to_string(),
+    };
+
+    match input.parse::<T>() {
+        Ok(number) if range.contains(&number) => Ok(number),
+        Ok(_) => Err(out_of_range()),
+        // too big (or small) to even fit
+        Err(_) if is_whole_number(input) => Err(out_of_range()),
+        Err(_) => Err(ParseError::NotANumber(input.to_string())),
+    }
+}
+
+pub fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    matchers::parse_duration(input).ok_or_else(|| ParseError::InvalidDuration(input.to_string()))
+}
+
+/// Takes the id of a message, or a jump link to it. Links have to point to the guild the command is used in.
+pub fn parse_message_id(input: &str, guild_id: GuildId) -> Result<MessageId, ParseError> {
+    if let Some((link_guild, _, message)) = matchers::get_jump_link(input) {
+        return if link_guild == guild_id.0 {
+            Ok(MessageId(message))
+        } else {
+            Err(ParseError::ForeignMessageLink)
+        };
+    }
+
+    input
+        .parse()
+        .map(MessageId)
+        .map_err(|_| ParseError::InvalidMessage(input.to_string()))
+}
+
+pub fn parse_choice<T: ArgumentChoice>(input: &str) -> Result<T, ParseError> {
+    T::from_choice(&input.to_lowercase()).ok_or_else(|| ParseError::InvalidChoice {
+        input: input.to_string(),
+        choices: T::CHOICES.join(", "),
+    })
+}
+
+fn is_whole_number(input: &str) -> bool {
+    let digits = input.strip_prefix('-').unwrap_or(input);
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::ROOT_NODE;
+    use crate::translation::{load_translations, DEFAULT_LANG};
+
+    #[derive(Debug, PartialEq)]
+    enum Target {
+        Users,
+        Bots,
+    }
+
+    impl ArgumentChoice for Target {
+        const CHOICES: &'static [&'static str] = &["users", "bots"];
+
+        fn from_choice(choice: &str) -> Option<Self> {
+            match choice {
+                "users" => Some(Target::Users),
+                "bots" => Some(Target::Bots),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn arguments_are_parsed() {
+        assert_eq!(parse_number("12", 1..=500usize).unwrap(), 12);
+        assert!(matches!(
+            parse_number("0", 1..=500usize),
+            Err(ParseError::NumberOutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse_number("99999999999999999999", 1..=u32::MAX),
+            Err(ParseError::NumberOutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse_number("twelve", 1..=500usize),
+            Err(ParseError::NotANumber(_))
+        ));
+
+        assert_eq!(parse_duration("1d2h").unwrap(), Duration::hours(26));
+        assert!(matches!(parse_duration("soon"), Err(ParseError::InvalidDuration(_))));
+
+        let guild = GuildId(365498559174410241);
+        let link = "https://discord.com/channels/365498559174410241/365498559723732993/606145193766551552";
+        assert_eq!(parse_message_id(link, guild).unwrap(), MessageId(606145193766551552));
+        assert_eq!(
+            parse_message_id("606145193766551552", guild).unwrap(),
+            MessageId(606145193766551552)
+        );
+        assert!(matches!(
+            parse_message_id(link, GuildId(1)),
+            Err(ParseError::ForeignMessageLink)
+        ));
+        assert!(matches!(
+            parse_message_id("yesterday", guild),
+            Err(ParseError::InvalidMessage(_))
+        ));
+
+        assert_eq!(parse_choice::<Target>("Bots").unwrap(), Target::Bots);
+        assert!(matches!(
+            parse_choice::<Target>("everyone"),
+            Err(ParseError::InvalidChoice { .. })
+        ));
+    }
+
+    #[test]
+    fn usage_is_generated() {
+        let translations = load_translations();
+        let usage_of = |arguments: &[Argument], command: &str| usage(arguments, &translations, &DEFAULT_LANG, command);
+
+        // arguments without a translation keep their own name
+        let arguments = [
+            Argument::required("member", ArgumentType::Member),
+            Argument::required("amount", ArgumentType::Number { min: 1, max: Some(500) }),
+            Argument::optional("target", ArgumentType::Choice(Target::CHOICES)),
+            Argument::optional("reason", ArgumentType::Text),
+        ];
+        assert_eq!(
+            usage_of(&arguments, "unknown"),
+            "<member> <amount (1-500)> [users|bots] [reason...]"
+        );
+
+        let quote = ROOT_NODE.all_commands.get("quote").unwrap();
+        assert_eq!(usage_of(&quote.arguments, "quote"), "<message id>");
+    }
+}
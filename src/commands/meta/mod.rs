@@ -1,4 +1,6 @@
+pub mod arguments;
 pub mod nodes;
+pub mod slash_commands;
 
 #[macro_use]
 pub mod macros {
@@ -11,7 +13,7 @@ pub mod macros {
 
     #[macro_export]
     macro_rules! command_with_aliases {
-        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr) => {{
+        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr, args: [$($arg: expr),*]) => {{
             Arc::new(CommandNode {
                 name: String::from($name),
                 handler: Some(Box::new(move |ctx| Box::pin($e(ctx)))),
@@ -21,12 +23,19 @@ pub mod macros {
                 command_permission: $command_permission,
                 group: $group,
                 aliases: $a,
+                arguments: vec![$($arg),*],
             })
         }};
+        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr) => {
+            $crate::command_with_aliases!($name, $a, $e, $bot_permissions, $command_permission, $group, args: [])
+        };
     }
 
     #[macro_export]
     macro_rules! command {
+        ($name: literal, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr, args: [$($arg: expr),*]) => {
+            $crate::command_with_aliases!($name, vec![], $e, $bot_permissions, $command_permission, $group, args: [$($arg),*])
+        };
         ($name: literal, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr) => {
             $crate::command_with_aliases!($name, vec![], $e, $bot_permissions, $command_permission, $group)
         };
@@ -34,7 +43,7 @@ pub mod macros {
 
     #[macro_export]
     macro_rules! command_with_subcommands_and_handler_and_aliases {
-        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr, $($node: expr),*) => {
+        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr, args: [$($arg: expr),*], $($node: expr),*) => {
         {
         let mut map = ::std::collections::HashMap::new();
         let mut list = vec![];
@@ -60,10 +69,14 @@ pub mod macros {
                 bot_permissions: $bot_permissions,
                 command_permission: $command_permission,
                 group: $group,
-                aliases: $a
+                aliases: $a,
+                arguments: vec![$($arg),*]
             })
         }
-        }
+        };
+        ($name: literal, $a: expr, $e: expr, $bot_permissions: expr, $command_permission: expr, $group: expr, $($node: expr),*) => {
+            $crate::command_with_subcommands_and_handler_and_aliases!($name, $a, $e, $bot_permissions, $command_permission, $group, args: [], $($node),*)
+        };
     }
 
     #[macro_export]
@@ -94,7 +107,8 @@ pub mod macros {
                 bot_permissions: $bot_permissions,
                 command_permission: $command_permission,
                 group: $group,
-                aliases: vec![]
+                aliases: vec![],
+                arguments: vec![]
             })
         }
         }
@@ -128,7 +142,8 @@ pub mod macros {
                 command_permission: $command_permission,
                 group: $group,
                 aliases: $a,
-                node_list: list
+                node_list: list,
+                arguments: vec![]
             })
         }
         }
@@ -162,7 +177,8 @@ pub mod macros {
                 command_permission: $command_permission,
                 group: $group,
                 aliases: vec![],
-                node_list: list
+                node_list: list,
+                arguments: vec![]
             })
         }
         }
@@ -7,6 +7,7 @@ use bitflags::bitflags;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use twilight_model::guild::Permissions;
 
+use crate::commands::meta::arguments::Argument;
 use crate::core::CommandContext;
 use crate::error::CommandResult;
 
@@ -20,7 +21,27 @@ pub struct RootNode {
     pub groups: Vec<CommandGroup>,
 }
 
-#[derive(Clone, Hash, Eq, PartialEq)]
+impl RootNode {
+    pub fn find_group(&self, name: &str) -> Option<&CommandGroup> {
+        self.groups.iter().find(|group| group.get_name() == name)
+    }
+
+    /// The commands of a group that someone with these permissions can use, sorted by name.
+    pub fn visible_commands(&self, group: &CommandGroup, permissions: GearBotPermissions) -> Vec<Arc<CommandNode>> {
+        self.by_group
+            .get(group)
+            .map(|commands| {
+                commands
+                    .iter()
+                    .filter(|command| command.is_visible_to(permissions))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Deserialize, Serialize)]
 pub enum CommandGroup {
     Basic,
     GuildAdmin,
@@ -53,23 +74,48 @@ impl CommandGroup {
 
 bitflags! {
     pub struct GearBotPermissions: u64 {
-        const BOT_ADMIN             = 0x000_001;
-        const BASIC_GROUP           = 0x000_002;
-        const ABOUT_COMMAND         = 0x000_004;
-        const COINFLIP_COMMAND      = 0x000_008;
-        const PING_COMMAND          = 0x000_010;
-        const QUOTE_COMMAND         = 0x000_020;
-        const UID_COMMAND           = 0x000_040;
-        const GUILD_ADMIN_GROUP     = 0x000_080;
-        const CONFIG_COMMAND        = 0x000_100;
-        const READ_CONFIG           = 0x000_200;
-        const WRITE_CONFIG          = 0x000_400;
-        const MODERATION_GROUP      = 0x000_800;
-        const USERINFO_COMMAND      = 0x001_000;
-        const HELP_COMMAND          = 0x002_000;
-        const MISC_GROUP            = 0x004_000;
-        const EMOJI_COMMAND         = 0x008_000;
-        const EMOJI_LIST_COMMAND    = 0x010_000;
+        const BOT_ADMIN                 = 0x000_001;
+        const BASIC_GROUP               = 0x000_002;
+        const ABOUT_COMMAND             = 0x000_004;
+        const COINFLIP_COMMAND          = 0x000_008;
+        const PING_COMMAND              = 0x000_010;
+        const QUOTE_COMMAND             = 0x000_020;
+        const UID_COMMAND               = 0x000_040;
+        const GUILD_ADMIN_GROUP         = 0x000_080;
+        const CONFIG_COMMAND            = 0x000_100;
+        const READ_CONFIG               = 0x000_200;
+        const WRITE_CONFIG              = 0x000_400;
+        const MODERATION_GROUP          = 0x000_800;
+        const USERINFO_COMMAND          = 0x001_000;
+        const HELP_COMMAND              = 0x002_000;
+        const MISC_GROUP                = 0x004_000;
+        const EMOJI_COMMAND             = 0x008_000;
+        const EMOJI_LIST_COMMAND        = 0x010_000;
+        const INF_COMMAND               = 0x020_000;
+        const INF_SEARCH_COMMAND        = 0x040_000;
+        const INF_INFO_COMMAND          = 0x080_000;
+        const INF_UPDATE_COMMAND        = 0x100_000;
+        const WARN_COMMAND              = 0x200_000;
+        const KICK_COMMAND              = 0x400_000;
+        const BAN_COMMAND               = 0x800_000;
+        const UNBAN_COMMAND             = 0x1_000_000;
+        const FORCEBAN_COMMAND          = 0x2_000_000;
+        const MUTE_COMMAND              = 0x4_000_000;
+        const UNMUTE_COMMAND            = 0x8_000_000;
+        const MUTE_SETUP_COMMAND        = 0x10_000_000;
+        const CLEAN_COMMAND             = 0x20_000_000;
+        const CLEAN_USER_COMMAND        = 0x40_000_000;
+        const CLEAN_BOTS_COMMAND        = 0x80_000_000;
+        const CLEAN_ALL_COMMAND         = 0x100_000_000;
+        const CLEAN_UNTIL_COMMAND       = 0x200_000_000;
+        const CLEAN_CONTAINS_COMMAND    = 0x400_000_000;
+        const CLEAN_ATTACHMENTS_COMMAND = 0x800_000_000;
+        const RAID_COMMAND              = 0x1_000_000_000;
+        const RAID_END_COMMAND          = 0x2_000_000_000;
+        const CUSTOM_COMMANDS           = 0x4_000_000_000;
+        const CUSTOM_COMMANDS_LIST      = 0x8_000_000_000;
+        const CUSTOM_COMMANDS_EDIT      = 0x10_000_000_000;
+        const TEMPBAN_COMMAND           = 0x20_000_000_000;
     }
 }
 
@@ -97,4 +143,22 @@ pub struct CommandNode {
     pub command_permission: GearBotPermissions,
     pub group: CommandGroup,
     pub aliases: Vec<String>,
+    pub arguments: Vec<Argument>,
+}
+
+impl CommandNode {
+    /// Commands that can't be run themselves are only there for their subcommands, so they are hidden when none of
+    /// those can be used either.
+    pub fn is_visible_to(&self, permissions: GearBotPermissions) -> bool {
+        permissions.contains(self.command_permission)
+            && (self.handler.is_some() || !self.visible_sub_nodes(permissions).is_empty())
+    }
+
+    pub fn visible_sub_nodes(&self, permissions: GearBotPermissions) -> Vec<Arc<CommandNode>> {
+        self.node_list
+            .iter()
+            .filter(|node| node.is_visible_to(permissions))
+            .cloned()
+            .collect()
+    }
 }
@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use twilight_model::applications::command::{
+    BaseCommandOptionData, ChoiceCommandOptionData, Command, CommandOption, CommandOptionChoice,
+    OptionsCommandOptionData,
+};
+use twilight_model::applications::interaction::application_command::{CommandData, CommandDataOption};
+
+use crate::commands::meta::arguments::{Argument, ArgumentType};
+use crate::commands::meta::nodes::{CommandGroup, CommandNode};
+use crate::commands::ROOT_NODE;
+use crate::translation::{Translations, DEFAULT_LANG};
+
+/// Discord refuses commands and options with longer descriptions.
+const MAX_DESCRIPTION_LENGTH: usize = 100;
+
+/// Turns the command tree into slash commands, bot admin commands are left out. On discord a command either runs
+/// itself or only holds subcommands, so the subcommands of commands that can also be run themselves stay prefix only.
+pub fn application_commands(translations: &Translations) -> Vec<Command> {
+    ROOT_NODE
+        .command_list
+        .iter()
+        .filter(|command| command.group != CommandGroup::BotAdmin)
+        .map(|command| Command {
+            application_id: None,
+            guild_id: None,
+            id: None,
+            name: command.name.clone(),
+            description: description(translations, &command.name),
+            options: command_options(translations, command, &command.name),
+        })
+        .collect()
+}
+
+/// The nodes of the command that was used and the values it was given, in the order its arguments are declared in so
+/// the parser can take them as if they were typed out. `None` if the command doesn't exist (anymore).
+pub fn resolve(data: &CommandData) -> Option<(Vec<Arc<CommandNode>>, Vec<String>)> {
+    let mut nodes = vec![Arc::clone(ROOT_NODE.all_commands.get(&data.name)?)];
+    let mut options = &data.options;
+
+    // subcommands come in as the only option, holding the options for that subcommand
+    while let [CommandDataOption::SubCommand {
+        name,
+        options: sub_options,
+    }] = options.as_slice()
+    {
+        let node = Arc::clone(nodes.last().unwrap().sub_nodes.get(name)?);
+        nodes.push(node);
+        options = sub_options;
+    }
+
+    let mut values = vec![];
+    for argument in &nodes.last().unwrap().arguments {
+        let name = option_name(argument);
+        let value = options.iter().find_map(|option| match option {
+            CommandDataOption::String { name: option, value } if *option == name => Some(value.clone()),
+            CommandDataOption::Integer { name: option, value } if *option == name => Some(value.to_string()),
+            CommandDataOption::Boolean { name: option, value } if *option == name => Some(value.to_string()),
+            _ => None,
+        });
+
+        match value {
+            Some(value) => values.push(value),
+            // anything after a skipped optional argument would end up in its place
+            None => break,
+        }
+    }
+
+    Some((nodes, values))
+}
+
+fn command_options(translations: &Translations, node: &CommandNode, path: &str) -> Vec<CommandOption> {
+    if node.handler.is_some() {
+        return node
+            .arguments
+            .iter()
+            .map(|argument| argument_option(translations, argument, path))
+            .collect();
+    }
+
+    node.node_list
+        .iter()
+        .map(|sub_node| {
+            let path = format!("{}__{}", path, sub_node.name);
+            let data = OptionsCommandOptionData {
+                name: sub_node.name.clone(),
+                description: description(translations, &path),
+                required: false,
+                options: command_options(translations, sub_node, &path),
+            };
+
+            if sub_node.handler.is_some() {
+                CommandOption::SubCommand(data)
+            } else {
+                CommandOption::SubCommandGroup(data)
+            }
+        })
+        .collect()
+}
+
+fn argument_option(translations: &Translations, argument: &Argument, path: &str) -> CommandOption {
+    let name = option_name(argument);
+    let description = truncate(argument.description(translations, &DEFAULT_LANG, path));
+    let required = !argument.optional;
+    let base = || BaseCommandOptionData {
+        name: name.clone(),
+        description: description.clone(),
+        required,
+    };
+    let with_choices = |choices: Vec<CommandOptionChoice>| ChoiceCommandOptionData {
+        name: name.clone(),
+        description: description.clone(),
+        required,
+        choices,
+    };
+
+    match &argument.kind {
+        ArgumentType::User | ArgumentType::Member => CommandOption::User(base()),
+        ArgumentType::Role => CommandOption::Role(base()),
+        ArgumentType::Channel => CommandOption::Channel(base()),
+        ArgumentType::Number { .. } => CommandOption::Integer(with_choices(vec![])),
+        ArgumentType::Choice(choices) => CommandOption::String(with_choices(
+            choices
+                .iter()
+                .map(|choice| CommandOptionChoice::String {
+                    name: choice.to_string(),
+                    value: choice.to_string(),
+                })
+                .collect(),
+        )),
+        ArgumentType::Duration | ArgumentType::Message | ArgumentType::Word | ArgumentType::Text => {
+            CommandOption::String(with_choices(vec![]))
+        }
+    }
+}
+
+/// Option names can't contain spaces.
+fn option_name(argument: &Argument) -> String {
+    argument.name.replace(' ', "_")
+}
+
+/// Slash commands are registered once for every server, so their descriptions are always in the default language.
+fn description(translations: &Translations, path: &str) -> String {
+    truncate(
+        translations
+            .get_command_description(&DEFAULT_LANG, path)
+            .unwrap_or_default(),
+    )
+}
+
+fn truncate(description: String) -> String {
+    if description.chars().count() <= MAX_DESCRIPTION_LENGTH {
+        description
+    } else {
+        let mut truncated: String = description.chars().take(MAX_DESCRIPTION_LENGTH - 3).collect();
+        truncated += "...";
+        truncated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::translation::load_translations;
+    use twilight_model::id::CommandId;
+
+    /// The limits discord puts on names, descriptions and how many options there can be.
+    fn assert_valid(name: &str, description: &str, options: &[CommandOption]) {
+        assert!(
+            !name.is_empty()
+                && name.len() <= 32
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-'),
+            "invalid slash command name: {}",
+            name
+        );
+        assert!(
+            !description.is_empty() && description.chars().count() <= MAX_DESCRIPTION_LENGTH,
+            "invalid description for {}",
+            name
+        );
+        assert!(options.len() <= 25, "{} has too many options", name);
+
+        let mut optional_seen = false;
+        for option in options {
+            match option {
+                CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+                    assert_valid(&data.name, &data.description, &data.options)
+                }
+                CommandOption::String(data) | CommandOption::Integer(data) => {
+                    assert!(
+                        !optional_seen || !data.required,
+                        "{} has a required option after an optional one",
+                        name
+                    );
+                    optional_seen |= !data.required;
+                    assert_valid(&data.name, &data.description, &[]);
+                }
+                CommandOption::Boolean(data)
+                | CommandOption::User(data)
+                | CommandOption::Channel(data)
+                | CommandOption::Role(data) => {
+                    assert!(
+                        !optional_seen || !data.required,
+                        "{} has a required option after an optional one",
+                        name
+                    );
+                    optional_seen |= !data.required;
+                    assert_valid(&data.name, &data.description, &[]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn commands_are_valid_slash_commands() {
+        let commands = application_commands(&load_translations());
+        assert!(commands.len() <= 100, "too many slash commands");
+
+        for command in &commands {
+            assert_valid(&command.name, &command.description, &command.options);
+        }
+    }
+
+    #[test]
+    fn options_are_resolved() {
+        let data = CommandData {
+            id: CommandId(1),
+            name: String::from("clean"),
+            options: vec![CommandDataOption::SubCommand {
+                name: String::from("user"),
+                options: vec![
+                    CommandDataOption::Integer {
+                        name: String::from("amount"),
+                        value: 50,
+                    },
+                    CommandDataOption::String {
+                        name: String::from("user"),
+                        value: String::from("106354106196570112"),
+                    },
+                ],
+            }],
+        };
+
+        let (nodes, values) = resolve(&data).unwrap();
+        assert_eq!(nodes.last().unwrap().name, "user");
+        assert_eq!(values, vec!["106354106196570112", "50"]);
+    }
+}
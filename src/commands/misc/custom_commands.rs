@@ -0,0 +1,144 @@
+use crate::commands::ROOT_NODE;
+use crate::core::custom_commands::{MAX_CUSTOM_COMMANDS, MAX_RESPONSE_LENGTH, MAX_TRIGGER_LENGTH};
+use crate::core::CommandContext;
+use crate::error::{CommandError, CommandResult};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+pub async fn command_create(mut ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+    let trigger = match get_trigger(&mut ctx).await? {
+        Some(trigger) => trigger,
+        None => return Ok(()),
+    };
+
+    if ROOT_NODE.all_commands.contains_key(&trigger) {
+        return reply_for_trigger(&ctx, GearBotString::CustomCommandBuiltIn, &trigger).await;
+    }
+
+    let response = match get_response(&mut ctx).await? {
+        Some(response) => response,
+        None => return Ok(()),
+    };
+
+    if ctx.bot_context.get_custom_commands(guild_id).await?.len() >= MAX_CUSTOM_COMMANDS {
+        let args = FluArgs::with_capacity(2)
+            .add("gearno", Emoji::No.for_chat())
+            .add("max", MAX_CUSTOM_COMMANDS)
+            .generate();
+        ctx.reply(GearBotString::CustomCommandLimit, args).await?;
+        return Ok(());
+    }
+
+    if ctx
+        .bot_context
+        .add_custom_command(guild_id, &trigger, &response)
+        .await?
+    {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandCreated, &trigger).await
+    } else {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandExists, &trigger).await
+    }
+}
+
+pub async fn command_edit(mut ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+    let trigger = match get_trigger(&mut ctx).await? {
+        Some(trigger) => trigger,
+        None => return Ok(()),
+    };
+    let response = match get_response(&mut ctx).await? {
+        Some(response) => response,
+        None => return Ok(()),
+    };
+
+    if ctx
+        .bot_context
+        .edit_custom_command(guild_id, &trigger, &response)
+        .await?
+    {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandEdited, &trigger).await
+    } else {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandNotFound, &trigger).await
+    }
+}
+
+pub async fn command_remove(mut ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+    let trigger = ctx.parser.get_next()?.to_lowercase();
+
+    if ctx.bot_context.remove_custom_command(guild_id, &trigger).await? {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandRemoved, &trigger).await
+    } else {
+        reply_for_trigger(&ctx, GearBotString::CustomCommandNotFound, &trigger).await
+    }
+}
+
+pub async fn command_list(ctx: CommandContext) -> CommandResult {
+    let commands = ctx.bot_context.get_custom_commands(ctx.get_guild().id).await?;
+    if commands.is_empty() {
+        ctx.reply(
+            GearBotString::CustomCommandListEmpty,
+            FluArgs::with_capacity(0).generate(),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let mut triggers: Vec<&String> = commands.keys().collect();
+    triggers.sort();
+    let triggers: Vec<String> = triggers.iter().map(|trigger| format!("``{}``", trigger)).collect();
+
+    let args = FluArgs::with_capacity(3)
+        .add("count", commands.len())
+        .add("max", MAX_CUSTOM_COMMANDS)
+        .add("triggers", triggers.join(", "))
+        .generate();
+    ctx.reply(GearBotString::CustomCommandList, args).await?;
+
+    Ok(())
+}
+
+/// Takes the trigger to create or edit, which is matched case insensitively. Tells the author when it can't be used,
+/// `None` is returned then.
+async fn get_trigger(ctx: &mut CommandContext) -> Result<Option<String>, CommandError> {
+    let trigger = ctx.parser.get_next()?.to_lowercase();
+    // quoted arguments can have spaces, but messages are split on those before the triggers are looked up
+    if trigger.chars().count() <= MAX_TRIGGER_LENGTH && !trigger.contains(char::is_whitespace) {
+        return Ok(Some(trigger));
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("max", MAX_TRIGGER_LENGTH)
+        .generate();
+    ctx.reply(GearBotString::CustomCommandInvalidTrigger, args).await?;
+    Ok(None)
+}
+
+/// Takes the rest of the message as the response, `None` if it's too long after telling the author.
+async fn get_response(ctx: &mut CommandContext) -> Result<Option<String>, CommandError> {
+    let response = ctx.parser.get_text()?;
+    if response.chars().count() <= MAX_RESPONSE_LENGTH {
+        return Ok(Some(response));
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("max", MAX_RESPONSE_LENGTH)
+        .generate();
+    ctx.reply(GearBotString::CustomCommandResponseTooLong, args).await?;
+    Ok(None)
+}
+
+/// Replies with one of the strings about a single custom command, these either start with `gearyes` or `gearno`.
+async fn reply_for_trigger(ctx: &CommandContext, key: GearBotString, trigger: &str) -> CommandResult {
+    let args = FluArgs::with_capacity(3)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("gearno", Emoji::No.for_chat())
+        .add("trigger", trigger.to_string())
+        .generate();
+    ctx.reply(key, args).await?;
+
+    Ok(())
+}
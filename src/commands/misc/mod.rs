@@ -1,3 +1,5 @@
+pub use custom_commands::*;
 pub use emoji::*;
 
+mod custom_commands;
 mod emoji;
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use lazy_static::lazy_static;
 use twilight_model::guild::Permissions;
 
+use crate::commands::meta::arguments::{Argument, ArgumentType};
 use crate::commands::meta::nodes::{CommandGroup, CommandNode, GearBotPermissions, RootNode};
 use crate::{
     command, command_with_aliases, command_with_subcommands, command_with_subcommands_and_aliases,
@@ -32,7 +33,16 @@ lazy_static! {
                 basic::coinflip,
                 Permissions::empty(),
                 GearBotPermissions::COINFLIP_COMMAND,
-                CommandGroup::Basic
+                CommandGroup::Basic,
+                args: [Argument::optional("thing to do", ArgumentType::Text)]
+            ),
+            command!(
+                "help",
+                basic::help,
+                Permissions::EMBED_LINKS | Permissions::ADD_REACTIONS,
+                GearBotPermissions::HELP_COMMAND,
+                CommandGroup::Basic,
+                args: [Argument::optional("group or command", ArgumentType::Text)]
             ),
             command!(
                 "ping",
@@ -46,14 +56,16 @@ lazy_static! {
                 basic::quote,
                 Permissions::EMBED_LINKS,
                 GearBotPermissions::QUOTE_COMMAND,
-                CommandGroup::Basic
+                CommandGroup::Basic,
+                args: [Argument::required("message", ArgumentType::Message)]
             ),
             command!(
                 "uid",
                 basic::uid,
                 Permissions::empty(),
                 GearBotPermissions::UID_COMMAND,
-                CommandGroup::Basic
+                CommandGroup::Basic,
+                args: [Argument::required("user mention", ArgumentType::Word)]
             ),
             command_with_subcommands_and_aliases!(
                 "config",
@@ -68,6 +80,7 @@ lazy_static! {
                     Permissions::empty(),
                     GearBotPermissions::READ_CONFIG,
                     CommandGroup::GuildAdmin,
+                    args: [Argument::optional("path", ArgumentType::Word)],
                     command_with_aliases!(
                         "pretty",
                         vec![String::from("p")],
@@ -82,14 +95,79 @@ lazy_static! {
                     debug::set_config,
                     Permissions::empty(),
                     GearBotPermissions::WRITE_CONFIG,
-                    CommandGroup::GuildAdmin
+                    CommandGroup::GuildAdmin,
+                    args: [
+                        Argument::required("path", ArgumentType::Word),
+                        Argument::required("value", ArgumentType::Text)
+                    ]
+                ),
+                command!(
+                    "add",
+                    debug::add_config,
+                    Permissions::empty(),
+                    GearBotPermissions::WRITE_CONFIG,
+                    CommandGroup::GuildAdmin,
+                    args: [
+                        Argument::required("path", ArgumentType::Word),
+                        Argument::required("value", ArgumentType::Text)
+                    ]
+                ),
+                command!(
+                    "remove",
+                    debug::remove_config,
+                    Permissions::empty(),
+                    GearBotPermissions::WRITE_CONFIG,
+                    CommandGroup::GuildAdmin,
+                    args: [
+                        Argument::required("path", ArgumentType::Word),
+                        Argument::required("value", ArgumentType::Text)
+                    ]
                 ),
                 command!(
                 "reset",
                 debug::reset_config,
                 Permissions::empty(),
                 GearBotPermissions::WRITE_CONFIG,
-                CommandGroup::GuildAdmin
+                CommandGroup::GuildAdmin,
+                args: [Argument::optional("path", ArgumentType::Word)]
+                ),
+                command!(
+                    "history",
+                    debug::config_history,
+                    Permissions::empty(),
+                    GearBotPermissions::READ_CONFIG,
+                    CommandGroup::GuildAdmin,
+                    args: [Argument::optional("page", ArgumentType::Number { min: 1, max: None })]
+                ),
+                command!(
+                    "diff",
+                    debug::config_diff,
+                    Permissions::empty(),
+                    GearBotPermissions::READ_CONFIG,
+                    CommandGroup::GuildAdmin,
+                    args: [Argument::required("revision", ArgumentType::Number { min: 1, max: None })]
+                ),
+                command!(
+                    "rollback",
+                    debug::config_rollback,
+                    Permissions::empty(),
+                    GearBotPermissions::WRITE_CONFIG,
+                    CommandGroup::GuildAdmin,
+                    args: [Argument::required("revision", ArgumentType::Number { min: 1, max: None })]
+                ),
+                command!(
+                    "export",
+                    debug::export_config,
+                    Permissions::ATTACH_FILES,
+                    GearBotPermissions::READ_CONFIG,
+                    CommandGroup::GuildAdmin
+                ),
+                command!(
+                    "import",
+                    debug::import_config,
+                    Permissions::empty(),
+                    GearBotPermissions::WRITE_CONFIG,
+                    CommandGroup::GuildAdmin
                 )
             ),
             command!(
@@ -97,7 +175,221 @@ lazy_static! {
                 moderation::userinfo,
                 Permissions::EMBED_LINKS,
                 GearBotPermissions::USERINFO_COMMAND,
-                CommandGroup::Moderation
+                CommandGroup::Moderation,
+                args: [Argument::optional("user", ArgumentType::User)]
+            ),
+            command_with_subcommands!(
+                "inf",
+                GearBotPermissions::INF_COMMAND,
+                CommandGroup::Moderation,
+                command_with_subcommands_and_handler_and_aliases!(
+                    "search",
+                    vec![String::from("s")],
+                    moderation::inf_search,
+                    Permissions::empty(),
+                    GearBotPermissions::INF_SEARCH_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [
+                        Argument::optional("user", ArgumentType::User),
+                        Argument::optional("page", ArgumentType::Number { min: 1, max: None })
+                    ],
+                    command!(
+                        "mod",
+                        moderation::inf_search_mod,
+                        Permissions::empty(),
+                        GearBotPermissions::INF_SEARCH_COMMAND,
+                        CommandGroup::Moderation,
+                        args: [
+                            Argument::required("moderator", ArgumentType::User),
+                            Argument::optional("page", ArgumentType::Number { min: 1, max: None })
+                        ]
+                    )
+                ),
+                command!(
+                    "info",
+                    moderation::inf_info,
+                    Permissions::EMBED_LINKS,
+                    GearBotPermissions::INF_INFO_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("infraction", ArgumentType::Number { min: 1, max: None })]
+                ),
+                command!(
+                    "update",
+                    moderation::inf_update,
+                    Permissions::empty(),
+                    GearBotPermissions::INF_UPDATE_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [
+                        Argument::required("infraction", ArgumentType::Number { min: 1, max: None }),
+                        Argument::required("reason", ArgumentType::Text)
+                    ]
+                )
+            ),
+            command!(
+                "warn",
+                moderation::warn,
+                Permissions::empty(),
+                GearBotPermissions::WARN_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::required("reason", ArgumentType::Text)
+                ]
+            ),
+            command!(
+                "kick",
+                moderation::kick,
+                Permissions::KICK_MEMBERS,
+                GearBotPermissions::KICK_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command!(
+                "ban",
+                moderation::ban,
+                Permissions::BAN_MEMBERS,
+                GearBotPermissions::BAN_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command!(
+                "tempban",
+                moderation::tempban,
+                Permissions::BAN_MEMBERS,
+                GearBotPermissions::TEMPBAN_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::required("duration", ArgumentType::Duration),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command!(
+                "forceban",
+                moderation::forceban,
+                Permissions::BAN_MEMBERS,
+                GearBotPermissions::FORCEBAN_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("user", ArgumentType::User),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command!(
+                "unban",
+                moderation::unban,
+                Permissions::BAN_MEMBERS,
+                GearBotPermissions::UNBAN_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("user", ArgumentType::User),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command_with_subcommands_and_handler_and_aliases!(
+                "mute",
+                vec![],
+                moderation::mute,
+                Permissions::MANAGE_ROLES,
+                GearBotPermissions::MUTE_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::required("duration", ArgumentType::Duration),
+                    Argument::optional("reason", ArgumentType::Text)
+                ],
+                command!(
+                    "setup",
+                    moderation::mute_setup,
+                    Permissions::MANAGE_ROLES | Permissions::MANAGE_CHANNELS,
+                    GearBotPermissions::MUTE_SETUP_COMMAND,
+                    CommandGroup::Moderation
+                )
+            ),
+            command!(
+                "unmute",
+                moderation::unmute,
+                Permissions::MANAGE_ROLES,
+                GearBotPermissions::UNMUTE_COMMAND,
+                CommandGroup::Moderation,
+                args: [
+                    Argument::required("member", ArgumentType::Member),
+                    Argument::optional("reason", ArgumentType::Text)
+                ]
+            ),
+            command_with_subcommands!(
+                "clean",
+                GearBotPermissions::CLEAN_COMMAND,
+                CommandGroup::Moderation,
+                command!(
+                    "user",
+                    moderation::clean_user,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_USER_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [
+                        Argument::required("user", ArgumentType::User),
+                        Argument::required("amount", ArgumentType::Number { min: 1, max: Some(moderation::MAX_CLEAN as i64) })
+                    ]
+                ),
+                command!(
+                    "bots",
+                    moderation::clean_bots,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_BOTS_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("amount", ArgumentType::Number { min: 1, max: Some(moderation::MAX_CLEAN as i64) })]
+                ),
+                command!(
+                    "all",
+                    moderation::clean_all,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_ALL_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("amount", ArgumentType::Number { min: 1, max: Some(moderation::MAX_CLEAN as i64) })]
+                ),
+                command!(
+                    "until",
+                    moderation::clean_until,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_UNTIL_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("message", ArgumentType::Message)]
+                ),
+                command!(
+                    "contains",
+                    moderation::clean_contains,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_CONTAINS_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("text", ArgumentType::Text)]
+                ),
+                command!(
+                    "attachments",
+                    moderation::clean_attachments,
+                    Permissions::MANAGE_MESSAGES,
+                    GearBotPermissions::CLEAN_ATTACHMENTS_COMMAND,
+                    CommandGroup::Moderation,
+                    args: [Argument::required("amount", ArgumentType::Number { min: 1, max: Some(moderation::MAX_CLEAN as i64) })]
+                )
+            ),
+            command_with_subcommands!(
+                "raid",
+                GearBotPermissions::RAID_COMMAND,
+                CommandGroup::Moderation,
+                command!(
+                    "end",
+                    moderation::raid_end,
+                    Permissions::ADD_REACTIONS,
+                    GearBotPermissions::RAID_END_COMMAND,
+                    CommandGroup::Moderation
+                )
             ),
             command_with_subcommands!(
                 "check",
@@ -123,9 +415,52 @@ lazy_static! {
             debug::get_perms,
             Permissions::empty(),
             GearBotPermissions::BOT_ADMIN,
-            CommandGroup::BotAdmin
+            CommandGroup::BotAdmin,
+            args: [Argument::optional("member", ArgumentType::Member)]
             ),
             command!("test", debug::test, Permissions::empty(), GearBotPermissions::BOT_ADMIN, CommandGroup::BotAdmin),
+            command_with_subcommands!(
+                "command",
+                GearBotPermissions::CUSTOM_COMMANDS,
+                CommandGroup::Misc,
+                command!(
+                    "create",
+                    misc::command_create,
+                    Permissions::empty(),
+                    GearBotPermissions::CUSTOM_COMMANDS_EDIT,
+                    CommandGroup::Misc,
+                    args: [
+                        Argument::required("trigger", ArgumentType::Word),
+                        Argument::required("response", ArgumentType::Text)
+                    ]
+                ),
+                command!(
+                    "edit",
+                    misc::command_edit,
+                    Permissions::empty(),
+                    GearBotPermissions::CUSTOM_COMMANDS_EDIT,
+                    CommandGroup::Misc,
+                    args: [
+                        Argument::required("trigger", ArgumentType::Word),
+                        Argument::required("response", ArgumentType::Text)
+                    ]
+                ),
+                command!(
+                    "remove",
+                    misc::command_remove,
+                    Permissions::empty(),
+                    GearBotPermissions::CUSTOM_COMMANDS_EDIT,
+                    CommandGroup::Misc,
+                    args: [Argument::required("trigger", ArgumentType::Word)]
+                ),
+                command!(
+                    "list",
+                    misc::command_list,
+                    Permissions::empty(),
+                    GearBotPermissions::CUSTOM_COMMANDS_LIST,
+                    CommandGroup::Misc
+                )
+            ),
             command_with_subcommands!("emoji", GearBotPermissions::EMOJI_COMMAND, CommandGroup::Misc, command!("list", misc::emoji_list, Permissions::EMBED_LINKS, GearBotPermissions::EMOJI_LIST_COMMAND, CommandGroup::Misc))
         ];
 
@@ -169,7 +504,7 @@ lazy_static! {
             all_commands,
             command_list,
             by_group,
-            groups: vec![CommandGroup::Basic, CommandGroup::Moderation, CommandGroup::GuildAdmin]
+            groups: vec![CommandGroup::Basic, CommandGroup::Moderation, CommandGroup::GuildAdmin, CommandGroup::Misc, CommandGroup::BotAdmin]
         }
     };
 }
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use twilight_model::guild::Permissions;
+
+use crate::cache::{CachedMember, CachedUser};
+use crate::core::CommandContext;
+use crate::error::{CommandResult, ParseError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+pub async fn ban(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+    let reason = ctx.parser.get_remaining();
+
+    ban_member(&ctx, &member, &user, reason).await
+}
+
+/// Bans a member for a while, the scheduler unbans them once the duration has passed.
+pub async fn tempban(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+    let duration = ctx.parser.get_duration()?;
+    let reason = ctx.parser.get_remaining();
+
+    if !super::bot_can(&ctx, Permissions::BAN_MEMBERS).await? || !super::can_act_on(&ctx, &member, &user).await? {
+        return Ok(());
+    }
+
+    let end = Utc::now()
+        .checked_add_signed(duration)
+        .ok_or_else(|| ParseError::WrongArgumentType(String::from("duration")))?;
+    let infraction = super::moderator(&ctx).tempban(user.id, &reason, end).await?;
+
+    let args = FluArgs::with_capacity(5)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("user", user.full_name())
+        .add("user_id", user.id.to_string())
+        .add("id", infraction.id)
+        .add("end", end.format("%F %T").to_string())
+        .generate();
+    ctx.reply(GearBotString::TempbanConfirmation, args).await?;
+
+    Ok(())
+}
+
+/// Bans a user that is not (or no longer) on the server, members get a regular ban instead.
+pub async fn forceban(mut ctx: CommandContext) -> CommandResult {
+    let user = ctx.parser.get_user().await?;
+    let reason = ctx.parser.get_remaining();
+
+    if let Some(member) = ctx.get_member(&user.id).await {
+        return ban_member(&ctx, &member, &user, reason).await;
+    }
+
+    if !super::bot_can(&ctx, Permissions::BAN_MEMBERS).await? {
+        return Ok(());
+    }
+
+    if ctx.get_ban(user.id).await?.is_some() {
+        return reply_ban_state(&ctx, GearBotString::ForcebanAlreadyBanned, &user).await;
+    }
+
+    let infraction = super::moderator(&ctx).forceban(user.id, &reason).await?;
+    super::reply_confirmation(&ctx, GearBotString::ForcebanConfirmation, &user, &infraction).await
+}
+
+pub async fn unban(mut ctx: CommandContext) -> CommandResult {
+    let user = ctx.parser.get_user().await?;
+    let reason = ctx.parser.get_remaining();
+
+    if !super::bot_can(&ctx, Permissions::BAN_MEMBERS).await? {
+        return Ok(());
+    }
+
+    if ctx.get_ban(user.id).await?.is_none() {
+        return reply_ban_state(&ctx, GearBotString::UnbanNotBanned, &user).await;
+    }
+
+    let infraction = super::moderator(&ctx).unban(user.id, &reason).await?;
+    super::reply_confirmation(&ctx, GearBotString::UnbanConfirmation, &user, &infraction).await
+}
+
+async fn ban_member(
+    ctx: &CommandContext,
+    member: &Arc<CachedMember>,
+    user: &Arc<CachedUser>,
+    reason: String,
+) -> CommandResult {
+    if !super::bot_can(ctx, Permissions::BAN_MEMBERS).await? || !super::can_act_on(ctx, member, user).await? {
+        return Ok(());
+    }
+
+    let infraction = super::moderator(ctx).ban(user.id, &reason).await?;
+    super::reply_confirmation(ctx, GearBotString::BanConfirmation, user, &infraction).await
+}
+
+async fn reply_ban_state(ctx: &CommandContext, key: GearBotString, user: &Arc<CachedUser>) -> CommandResult {
+    let args = FluArgs::with_capacity(3)
+        .add("gearno", Emoji::No.for_chat())
+        .add("user", user.full_name())
+        .add("user_id", user.id.to_string())
+        .generate();
+    ctx.reply(key, args).await?;
+
+    Ok(())
+}
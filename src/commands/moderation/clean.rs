@@ -0,0 +1,146 @@
+use chrono::{Duration, Utc};
+use twilight_model::channel::Message;
+use twilight_model::guild::Permissions;
+use twilight_model::id::MessageId;
+
+use crate::core::CommandContext;
+use crate::error::{CommandResult, ParseError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::{self, Emoji};
+
+/// The most messages that can be removed with a single command.
+pub const MAX_CLEAN: usize = 500;
+/// How far back in the history we look for messages that match, so a filter that barely matches doesn't go on forever.
+const MAX_SCAN: usize = 2_000;
+/// Discord only hands out (and bulk deletes) up to 100 messages per request.
+const CHUNK_SIZE: usize = 100;
+/// Messages older than 14 days can't be bulk deleted anymore, leave a bit of margin for the time spent scanning.
+const MAX_MESSAGE_AGE_HOURS: i64 = 14 * 24 - 1;
+
+pub async fn clean_user(mut ctx: CommandContext) -> CommandResult {
+    let user = ctx.parser.get_user().await?;
+    let amount = get_amount(&mut ctx)?;
+
+    clean(&ctx, amount, None, |message| message.author.id == user.id).await
+}
+
+pub async fn clean_bots(mut ctx: CommandContext) -> CommandResult {
+    let amount = get_amount(&mut ctx)?;
+
+    clean(&ctx, amount, None, |message| message.author.bot).await
+}
+
+pub async fn clean_all(mut ctx: CommandContext) -> CommandResult {
+    let amount = get_amount(&mut ctx)?;
+
+    clean(&ctx, amount, None, |_| true).await
+}
+
+pub async fn clean_until(mut ctx: CommandContext) -> CommandResult {
+    let until = ctx.parser.get_message_id()?;
+
+    clean(&ctx, MAX_CLEAN, Some(until), |_| true).await
+}
+
+pub async fn clean_contains(mut ctx: CommandContext) -> CommandResult {
+    let text = ctx.parser.get_text()?.to_lowercase();
+
+    clean(&ctx, MAX_CLEAN, None, |message| {
+        message.content.to_lowercase().contains(&text)
+    })
+    .await
+}
+
+pub async fn clean_attachments(mut ctx: CommandContext) -> CommandResult {
+    let amount = get_amount(&mut ctx)?;
+
+    clean(&ctx, amount, None, |message| !message.attachments.is_empty()).await
+}
+
+fn get_amount(ctx: &mut CommandContext) -> Result<usize, ParseError> {
+    ctx.parser.get_number(1..=MAX_CLEAN)
+}
+
+/// Walks back through the channel history, starting at the command, and removes up to `amount` messages that match
+/// the filter. When `until` is set it stops there, without removing that message itself.
+///
+/// The removals show up as regular (bulk) deletes, so they get logged and archived like any other.
+async fn clean<F>(ctx: &CommandContext, amount: usize, until: Option<MessageId>, filter: F) -> CommandResult
+where
+    F: Fn(&Message) -> bool,
+{
+    if !super::bot_can(ctx, Permissions::MANAGE_MESSAGES).await? {
+        return Ok(());
+    }
+
+    let http = &ctx.bot_context.http;
+    let channel_id = ctx.message.channel.get_id();
+    let cutoff = Utc::now() - Duration::hours(MAX_MESSAGE_AGE_HOURS);
+
+    let mut to_remove = vec![];
+    let mut reached_old = false;
+    let mut scanned = 0;
+    let mut before = ctx.message.id;
+
+    'scan: while scanned < MAX_SCAN {
+        let messages = http
+            .channel_messages(channel_id)
+            .before(before)
+            .limit(CHUNK_SIZE as u64)?
+            .await?;
+
+        for message in &messages {
+            scanned += 1;
+
+            if until.map_or(false, |until| message.id.0 <= until.0) {
+                break 'scan;
+            }
+
+            if utils::snowflake_timestamp(message.id.0) < cutoff {
+                reached_old = true;
+                break 'scan;
+            }
+
+            if filter(message) {
+                to_remove.push(message.id);
+                if to_remove.len() >= amount {
+                    break 'scan;
+                }
+            }
+        }
+
+        match messages.last() {
+            Some(last) if messages.len() == CHUNK_SIZE => before = last.id,
+            // Reached the start of the channel
+            _ => break,
+        }
+    }
+
+    for chunk in to_remove.chunks(CHUNK_SIZE) {
+        if chunk.len() == 1 {
+            http.delete_message(channel_id, chunk[0]).await?;
+        } else {
+            http.delete_messages(channel_id, chunk.to_vec()).await?;
+        }
+    }
+
+    if to_remove.is_empty() {
+        let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
+        ctx.reply(GearBotString::CleanNothingFound, args).await?;
+        return Ok(());
+    }
+
+    let key = if reached_old && to_remove.len() < amount {
+        GearBotString::CleanDoneTooOld
+    } else {
+        GearBotString::CleanDone
+    };
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("count", to_remove.len())
+        .generate();
+    ctx.reply(key, args).await?;
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+use twilight_embed_builder::{EmbedBuilder, EmbedFieldBuilder};
+
+use crate::commands::meta::arguments;
+use crate::core::CommandContext;
+use crate::database::structures::Infraction;
+use crate::error::{CommandResult, ParseError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::{self, Emoji};
+
+const INFRACTION_COLOR: u32 = 0x00_cea2;
+const LISTED_REASON_LENGTH: usize = 100;
+
+pub async fn inf_search(mut ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+
+    let user = if ctx.parser.has_next() && peek_page(&ctx).is_none() {
+        Some(ctx.parser.get_user().await?)
+    } else {
+        None
+    };
+    let page = get_page(&mut ctx)?;
+
+    let (target, infractions) = match &user {
+        Some(user) => (
+            user.full_name(),
+            ctx.bot_context
+                .datastore
+                .get_user_infractions(guild_id, user.id, page)
+                .await?,
+        ),
+        None => (
+            ctx.get_guild().name.clone(),
+            ctx.bot_context.datastore.get_infractions(guild_id, page).await?,
+        ),
+    };
+
+    reply_with_list(&ctx, target, page, &infractions).await
+}
+
+pub async fn inf_search_mod(mut ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+    let moderator = ctx.parser.get_user().await?;
+    let page = get_page(&mut ctx)?;
+
+    let infractions = ctx
+        .bot_context
+        .datastore
+        .get_mod_infractions(guild_id, moderator.id, page)
+        .await?;
+
+    reply_with_list(&ctx, moderator.full_name(), page, &infractions).await
+}
+
+pub async fn inf_info(mut ctx: CommandContext) -> CommandResult {
+    let id = get_infraction_id(&mut ctx)?;
+
+    let infraction = match ctx.bot_context.datastore.get_infraction(ctx.get_guild().id, id).await? {
+        Some(infraction) => infraction,
+        None => return reply_not_found(&ctx, id).await,
+    };
+
+    let end = match infraction.end {
+        Some(end) => end.format("%A %d %B %Y (%T)").to_string(),
+        None => String::from("-"),
+    };
+
+    let reason = if infraction.reason.is_empty() {
+        String::from("-")
+    } else {
+        infraction.reason.clone()
+    };
+
+    let embed = EmbedBuilder::new()
+        .color(INFRACTION_COLOR)?
+        .timestamp(infraction.start.to_rfc3339())
+        .field(
+            EmbedFieldBuilder::new("Type", infraction.kind.get_name())?
+                .inline()
+                .build(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                "User",
+                format!("<@{}> (``{}``)", infraction.user_id, infraction.user_id),
+            )?
+            .inline()
+            .build(),
+        )
+        .field(
+            EmbedFieldBuilder::new(
+                "Moderator",
+                format!("<@{}> (``{}``)", infraction.mod_id, infraction.mod_id),
+            )?
+            .inline()
+            .build(),
+        )
+        .field(
+            EmbedFieldBuilder::new("Start", infraction.start.format("%A %d %B %Y (%T)").to_string())?
+                .inline()
+                .build(),
+        )
+        .field(EmbedFieldBuilder::new("End", end)?.inline().build())
+        .field(EmbedFieldBuilder::new("Reason", reason)?.build())
+        .build()?;
+
+    let args = FluArgs::with_capacity(1).add("id", id).generate();
+    ctx.reply_with_embed(GearBotString::InfInfoHeader, args, embed).await?;
+
+    Ok(())
+}
+
+pub async fn inf_update(mut ctx: CommandContext) -> CommandResult {
+    let id = get_infraction_id(&mut ctx)?;
+
+    let reason = ctx.parser.get_text()?;
+
+    let updated = ctx
+        .bot_context
+        .datastore
+        .update_infraction_reason(ctx.get_guild().id, id, &reason)
+        .await?;
+
+    if updated {
+        let args = FluArgs::with_capacity(2)
+            .add("gearyes", Emoji::Yes.for_chat())
+            .add("id", id)
+            .generate();
+        ctx.reply(GearBotString::InfUpdated, args).await?;
+        Ok(())
+    } else {
+        reply_not_found(&ctx, id).await
+    }
+}
+
+fn get_infraction_id(ctx: &mut CommandContext) -> Result<i32, ParseError> {
+    arguments::parse_number(ctx.parser.get_next()?.trim_start_matches('#'), 1..=i32::MAX)
+}
+
+/// Page numbers are never valid snowflakes, so a number that fits in an `u32` is a page and not a user.
+fn peek_page(ctx: &CommandContext) -> Option<u32> {
+    ctx.parser.peek().and_then(|part| part.parse::<u32>().ok())
+}
+
+/// Gets the (zero indexed) page that was requested, defaulting to the first one.
+fn get_page(ctx: &mut CommandContext) -> Result<u32, ParseError> {
+    if !ctx.parser.has_next() {
+        return Ok(0);
+    }
+
+    Ok(ctx.parser.get_number(1..=u32::MAX)? - 1)
+}
+
+async fn reply_not_found(ctx: &CommandContext, id: i32) -> CommandResult {
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("id", id)
+        .generate();
+    ctx.reply(GearBotString::InfNotFound, args).await?;
+
+    Ok(())
+}
+
+async fn reply_with_list(ctx: &CommandContext, target: String, page: u32, infractions: &[Infraction]) -> CommandResult {
+    if infractions.is_empty() {
+        let args = FluArgs::with_capacity(1).add("target", target).generate();
+        ctx.reply(GearBotString::InfSearchEmpty, args).await?;
+        return Ok(());
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("target", target)
+        .add("page", page + 1)
+        .generate();
+    let mut content = ctx.translate_with_args(GearBotString::InfSearchHeader, &args);
+
+    for infraction in infractions {
+        content += "\n";
+        content += &format_infraction(infraction);
+    }
+
+    ctx.reply_raw(content).await?;
+
+    Ok(())
+}
+
+fn format_infraction(infraction: &Infraction) -> String {
+    let mut reason = utils::clean(&infraction.reason, true, true, false, false);
+    if reason.chars().count() > LISTED_REASON_LENGTH {
+        reason = reason.chars().take(LISTED_REASON_LENGTH).collect::<String>() + "...";
+    }
+
+    format!(
+        "``[{}]`` **{}** | <@{}> | by <@{}> | {} | {}",
+        infraction.id,
+        infraction.kind.get_name(),
+        infraction.user_id,
+        infraction.mod_id,
+        infraction.start.format("%F %T"),
+        reason
+    )
+}
@@ -0,0 +1,18 @@
+use twilight_model::guild::Permissions;
+
+use crate::core::CommandContext;
+use crate::error::CommandResult;
+use crate::translation::GearBotString;
+
+pub async fn kick(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+    let reason = ctx.parser.get_remaining();
+
+    if !super::bot_can(&ctx, Permissions::KICK_MEMBERS).await? || !super::can_act_on(&ctx, &member, &user).await? {
+        return Ok(());
+    }
+
+    let infraction = super::moderator(&ctx).kick(user.id, &reason).await?;
+    super::reply_confirmation(&ctx, GearBotString::KickConfirmation, &user, &infraction).await
+}
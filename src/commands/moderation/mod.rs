@@ -1,3 +1,100 @@
+pub use ban::{ban, forceban, tempban, unban};
+pub use clean::{clean_all, clean_attachments, clean_bots, clean_contains, clean_until, clean_user, MAX_CLEAN};
+pub use infractions::{inf_info, inf_search, inf_search_mod, inf_update};
+pub use kick::kick;
+pub use mute::{mute, mute_setup, unmute};
+pub use raid::raid_end;
 pub use userinfo::userinfo;
+pub use warn::warn;
 
+use std::sync::Arc;
+
+use twilight_model::guild::Permissions;
+
+use crate::cache::{CachedMember, CachedUser};
+use crate::core::moderation::Moderator;
+use crate::core::CommandContext;
+use crate::database::structures::Infraction;
+use crate::error::{CommandError, CommandResult};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+mod ban;
+mod clean;
+mod infractions;
+mod kick;
+mod mute;
+mod raid;
 mod userinfo;
+mod warn;
+
+/// Checks if both the moderator and the bot sit above the target in the role hierarchy.
+///
+/// If either of them does not, the moderator is told why and `false` is returned.
+async fn can_act_on(
+    ctx: &CommandContext,
+    member: &Arc<CachedMember>,
+    user: &Arc<CachedUser>,
+) -> Result<bool, CommandError> {
+    let key = if !ctx.author_outranks(member).await {
+        GearBotString::ModerationHierarchyAuthor
+    } else if !ctx.bot_outranks(member).await {
+        GearBotString::ModerationHierarchyBot
+    } else {
+        return Ok(true);
+    };
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("user", user.full_name())
+        .generate();
+    ctx.reply(key, args).await?;
+
+    Ok(false)
+}
+
+/// Checks if the bot has the guild wide permission it needs to carry out the action, telling the moderator if not.
+async fn bot_can(ctx: &CommandContext, permission: Permissions) -> Result<bool, CommandError> {
+    if ctx.bot_has_guild_permissions(permission).await {
+        return Ok(true);
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("permission", format!("{:?}", permission))
+        .generate();
+    ctx.reply(GearBotString::ModerationBotMissingPermission, args).await?;
+
+    Ok(false)
+}
+
+/// The author of the command, acting from the channel it was used in.
+fn moderator(ctx: &CommandContext) -> Moderator {
+    Moderator::new(
+        &ctx.bot_context,
+        ctx.get_guild().id,
+        &ctx.message.author,
+        Some(ctx.message.channel.get_id()),
+    )
+}
+
+fn audit_log_reason(ctx: &CommandContext, reason: &str) -> String {
+    moderator(ctx).audit_log_reason(reason)
+}
+
+async fn reply_confirmation(
+    ctx: &CommandContext,
+    key: GearBotString,
+    user: &Arc<CachedUser>,
+    infraction: &Infraction,
+) -> CommandResult {
+    let args = FluArgs::with_capacity(4)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("user", user.full_name())
+        .add("user_id", user.id.to_string())
+        .add("id", infraction.id)
+        .generate();
+    ctx.reply(key, args).await?;
+
+    Ok(())
+}
@@ -0,0 +1,232 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use twilight_http::request::AuditLogReason;
+use twilight_model::channel::permission_overwrite::PermissionOverwriteType;
+use twilight_model::guild::Permissions;
+use twilight_model::id::RoleId;
+
+use crate::cache::{CachedChannel, CachedRole};
+use crate::core::logpump::LogType;
+use crate::core::{moderation, CommandContext};
+use crate::error::{CommandError, CommandResult, ParseError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+/// What the mute role is denied in every channel.
+const MUTED_PERMISSIONS: Permissions = Permissions::from_bits_truncate(
+    Permissions::SEND_MESSAGES.bits() | Permissions::ADD_REACTIONS.bits() | Permissions::SPEAK.bits(),
+);
+const MUTE_ROLE_NAME: &str = "Muted";
+
+pub async fn mute(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+    let duration = ctx.parser.get_duration()?;
+    let reason = ctx.parser.get_remaining();
+
+    let role = match get_mute_role(&ctx).await? {
+        Some(role) => role,
+        None => return Ok(()),
+    };
+
+    if !super::bot_can(&ctx, Permissions::MANAGE_ROLES).await? || !super::can_act_on(&ctx, &member, &user).await? {
+        return Ok(());
+    }
+
+    let end = Utc::now()
+        .checked_add_signed(duration)
+        .ok_or_else(|| ParseError::WrongArgumentType(String::from("duration")))?;
+
+    let infraction = match super::moderator(&ctx).mute(user.id, role, &reason, end).await? {
+        Some(infraction) => infraction,
+        None => {
+            let args = FluArgs::with_capacity(3)
+                .add("gearno", Emoji::No.for_chat())
+                .add("user", user.full_name())
+                .add("user_id", user.id.to_string())
+                .generate();
+            ctx.reply(GearBotString::MuteAlreadyMuted, args).await?;
+            return Ok(());
+        }
+    };
+
+    let args = FluArgs::with_capacity(5)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("user", user.full_name())
+        .add("user_id", user.id.to_string())
+        .add("id", infraction.id)
+        .add("end", end.format("%F %T").to_string())
+        .generate();
+    ctx.reply(GearBotString::MuteConfirmation, args).await?;
+
+    Ok(())
+}
+
+pub async fn unmute(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+    let reason = ctx.parser.get_remaining();
+
+    let role = match get_mute_role(&ctx).await? {
+        Some(role) => role,
+        None => return Ok(()),
+    };
+
+    if !super::bot_can(&ctx, Permissions::MANAGE_ROLES).await? || !super::can_act_on(&ctx, &member, &user).await? {
+        return Ok(());
+    }
+
+    let guild_id = ctx.get_guild().id;
+    let active_mute = ctx.bot_context.datastore.get_active_mute(guild_id, user.id).await?;
+    let has_role = member.roles.contains(&role);
+
+    let args = FluArgs::with_capacity(3)
+        .add("user", user.full_name())
+        .add("user_id", user.id.to_string());
+    if active_mute.is_none() && !has_role {
+        let args = args.add("gearno", Emoji::No.for_chat()).generate();
+        ctx.reply(GearBotString::UnmuteNotMuted, args).await?;
+        return Ok(());
+    }
+
+    if has_role {
+        ctx.bot_context
+            .http
+            .remove_guild_member_role(guild_id, user.id, role)
+            .reason(super::audit_log_reason(&ctx, &reason))?
+            .await?;
+    }
+
+    if let Some(mute) = active_mute {
+        ctx.bot_context.datastore.complete_timed_action(mute.id).await?;
+    }
+
+    ctx.log(
+        LogType::Unmuted {
+            moderator: ctx.message.author.full_name(),
+            moderator_id: ctx.message.author.id,
+            reason,
+        },
+        Some(ctx.message.channel.get_id()),
+        user.id,
+    );
+
+    let args = args.add("gearyes", Emoji::Yes.for_chat()).generate();
+    ctx.reply(GearBotString::UnmuteConfirmation, args).await?;
+
+    Ok(())
+}
+
+/// Creates the mute role if there isn't one yet, and makes sure it is denied from talking in every channel.
+pub async fn mute_setup(ctx: CommandContext) -> CommandResult {
+    if !super::bot_can(&ctx, Permissions::MANAGE_ROLES).await?
+        || !super::bot_can(&ctx, Permissions::MANAGE_CHANNELS).await?
+    {
+        return Ok(());
+    }
+
+    let config = ctx.get_config()?;
+    let existing_role = match config.mute.role {
+        Some(role) => ctx.get_role(&role).await.map(|role| role.id),
+        None => None,
+    };
+
+    let role = match existing_role {
+        Some(role) => role,
+        None => {
+            let role = ctx
+                .bot_context
+                .http
+                .create_role(ctx.get_guild().id)
+                .name(MUTE_ROLE_NAME)
+                .permissions(Permissions::empty())
+                .reason(super::audit_log_reason(&ctx, "Setting up the mute role"))?
+                .await?;
+
+            // The gateway might not have told us about it yet, but the config can't be saved with a role that isn't known
+            ctx.get_guild()
+                .roles
+                .write()
+                .await
+                .insert(role.id, Arc::new(CachedRole::from_role(&role)));
+
+            let mut new_config = (*config).clone();
+            new_config.mute.role = Some(role.id);
+            ctx.set_config(new_config).await?;
+
+            role.id
+        }
+    };
+
+    let channels: Vec<Arc<CachedChannel>> = ctx.get_guild().channels.read().await.values().cloned().collect();
+    let mut updated = 0;
+    let mut failed = vec![];
+    for channel in channels {
+        match deny_in_channel(&ctx, &channel, role).await {
+            Ok(true) => updated += 1,
+            Ok(false) => {}
+            Err(_) => failed.push(format!("<#{}>", channel.get_id())),
+        }
+    }
+
+    let args = FluArgs::with_capacity(3)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("role_id", role.to_string())
+        .add("count", updated)
+        .generate();
+    ctx.reply(GearBotString::MuteSetupDone, args).await?;
+
+    if !failed.is_empty() {
+        let args = FluArgs::with_capacity(2)
+            .add("gearwarn", Emoji::Warn.for_chat())
+            .add("channels", failed.join(", "))
+            .generate();
+        ctx.reply(GearBotString::MuteSetupFailed, args).await?;
+    }
+
+    Ok(())
+}
+
+/// Gets the configured mute role, telling the moderator to set one up if there isn't a valid one.
+async fn get_mute_role(ctx: &CommandContext) -> Result<Option<RoleId>, CommandError> {
+    if let Some(role) = moderation::get_mute_role(&ctx.bot_context, ctx.get_guild().id).await? {
+        return Ok(Some(role));
+    }
+
+    let args = FluArgs::with_capacity(2)
+        .add("gearno", Emoji::No.for_chat())
+        .add("prefix", ctx.get_config()?.prefix.clone())
+        .generate();
+    ctx.reply(GearBotString::MuteNotConfigured, args).await?;
+
+    Ok(None)
+}
+
+/// Adds the muted permissions to the deny list of the role in this channel, keeping whatever else was in the override.
+///
+/// Returns `false` if the channel was already set up correctly.
+async fn deny_in_channel(ctx: &CommandContext, channel: &CachedChannel, role: RoleId) -> Result<bool, CommandError> {
+    let (allow, deny) = match channel
+        .get_permission_overrides()
+        .iter()
+        .find(|overwrite| overwrite.kind == PermissionOverwriteType::Role(role))
+    {
+        Some(overwrite) => {
+            if overwrite.deny.contains(MUTED_PERMISSIONS) && !overwrite.allow.intersects(MUTED_PERMISSIONS) {
+                return Ok(false);
+            }
+            (overwrite.allow - MUTED_PERMISSIONS, overwrite.deny | MUTED_PERMISSIONS)
+        }
+        None => (Permissions::empty(), MUTED_PERMISSIONS),
+    };
+
+    ctx.bot_context
+        .http
+        .update_channel_permission(channel.get_id(), allow, deny)
+        .role(role)
+        .reason(super::audit_log_reason(ctx, "Setting up the mute role"))?
+        .await?;
+
+    Ok(true)
+}
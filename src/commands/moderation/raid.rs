@@ -0,0 +1,78 @@
+use twilight_http::request::AuditLogReason;
+
+use crate::core::logpump::LogType;
+use crate::core::{CommandContext, Reactor};
+use crate::error::CommandResult;
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::{self, Emoji};
+
+/// How many of the caught accounts are listed when a raid ends, the rest is only counted.
+const RAID_LIST_LENGTH: usize = 20;
+
+/// Turns raid mode off again, and offers to ban everyone that got caught in it.
+pub async fn raid_end(ctx: CommandContext) -> CommandResult {
+    let guild_id = ctx.get_guild().id;
+    let raid = match ctx.bot_context.raid_tracker.end(guild_id).await {
+        Some(raid) => raid,
+        None => {
+            let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
+            ctx.reply(GearBotString::RaidNotActive, args).await?;
+            return Ok(());
+        }
+    };
+
+    if let Some(level) = raid.previous_verification {
+        ctx.bot_context
+            .http
+            .update_guild(guild_id)
+            .verification_level(Some(level))
+            .reason(super::audit_log_reason(&ctx, "Raid ended"))?
+            .await?;
+    }
+
+    ctx.log(
+        LogType::RaidEnded {
+            caught: raid.caught.len() as u32,
+            moderator: ctx.message.author.full_name(),
+            moderator_id: ctx.message.author.id,
+        },
+        Some(ctx.message.channel.get_id()),
+        ctx.message.author.id,
+    );
+
+    let mut users = vec![];
+    for user_id in raid.caught.iter().take(RAID_LIST_LENGTH) {
+        users.push(match ctx.bot_context.cache.get_user(*user_id).await {
+            Some(user) => utils::clean(&user.full_name_with_id(), true, true, true, true),
+            None => user_id.to_string(),
+        });
+    }
+    if raid.caught.len() > RAID_LIST_LENGTH {
+        let args = FluArgs::with_capacity(1)
+            .add("count", raid.caught.len() - RAID_LIST_LENGTH)
+            .generate();
+        users.push(ctx.translate_with_args(GearBotString::RaidEndedMore, &args));
+    }
+
+    let args = FluArgs::with_capacity(3)
+        .add("gearyes", Emoji::Yes.for_chat())
+        .add("count", raid.caught.len())
+        .add("users", users.join("\n"))
+        .generate();
+    let message = ctx.reply(GearBotString::RaidEnded, args).await?;
+
+    let args = FluArgs::with_capacity(1)
+        .add("start", raid.started.format("%F %T").to_string())
+        .generate();
+    let reason = ctx.translate_with_args(GearBotString::RaidBanReason, &args);
+    Reactor::new_raid_ban(ctx.message.author.id, reason, raid.caught)
+        .save(&ctx.bot_context, message.id)
+        .await?;
+
+    ctx.bot_context
+        .http
+        .create_reaction(message.channel_id, message.id, Emoji::Yes.to_reaction())
+        .await?;
+
+    Ok(())
+}
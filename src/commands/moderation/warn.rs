@@ -0,0 +1,43 @@
+use crate::core::CommandContext;
+use crate::error::CommandResult;
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+pub async fn warn(mut ctx: CommandContext) -> CommandResult {
+    let member = ctx.parser.get_member().await?;
+    let user = member.user(&ctx.bot_context.cache).await;
+
+    let reason = ctx.parser.get_text()?;
+
+    // the bot itself doesn't have to act on the member here, so only the moderator needs to outrank them
+    if !ctx.author_outranks(&member).await {
+        let args = FluArgs::with_capacity(2)
+            .add("gearno", Emoji::No.for_chat())
+            .add("user", user.full_name())
+            .generate();
+        ctx.reply(GearBotString::ModerationHierarchyAuthor, args).await?;
+        return Ok(());
+    }
+
+    let infraction = super::moderator(&ctx).warn(user.id, &reason).await?;
+
+    let args = FluArgs::with_capacity(3)
+        .add("gearwarn", Emoji::Warn.for_chat())
+        .add("guild", ctx.get_guild().name.clone())
+        .add("reason", reason)
+        .generate();
+    let dm_delivered = match ctx.get_dm_for_user(user.id).await {
+        Ok(channel) => ctx
+            .send_message(channel.get_id(), GearBotString::WarnDm, args)
+            .await
+            .is_ok(),
+        Err(_) => false,
+    };
+
+    let key = if dm_delivered {
+        GearBotString::WarnConfirmation
+    } else {
+        GearBotString::WarnConfirmationNoDm
+    };
+    super::reply_confirmation(&ctx, key, &user, &infraction).await
+}
@@ -2,7 +2,7 @@ use std::fs;
 use std::{collections::HashMap, convert::TryFrom};
 
 use serde::Deserialize;
-use twilight_model::id::{EmojiId, WebhookId};
+use twilight_model::id::{ApplicationId, EmojiId, WebhookId};
 use twilight_util::link::webhook::parse as parse_webhook;
 
 use crate::error::StartupError;
@@ -17,6 +17,8 @@ pub struct BotConfig {
     pub emoji: HashMap<String, String>,
     pub global_admins: Vec<u64>,
     pub proxy_url: Option<String>,
+    /// Only needed to register the slash commands, older applications don't share their id with the bot user.
+    pub application_id: Option<ApplicationId>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
@@ -8,9 +9,12 @@ use twilight_model::{
 
 use super::BotContext;
 use crate::cache::CachedUser;
-use crate::core::GuildConfig;
-use crate::database::structures::UserMessage;
-use crate::error::{DatabaseError, ParseError};
+use crate::core::config_editing;
+use crate::core::config_validation::ConfigIssue;
+use crate::core::logpump::{LogData, LogType};
+use crate::core::{CensorFilters, GuildConfig};
+use crate::database::structures::{ConfigSource, UserMessage};
+use crate::error::{ConfigError, DatabaseError, ParseError};
 
 #[derive(Serialize, Deserialize)]
 #[serde(untagged)]
@@ -20,6 +24,8 @@ enum UserHolder {
 }
 
 const USER_CACHE_DURATION: u32 = 3600;
+/// How much of what changed goes into the config change log, leaving room for the rest of it.
+const LOG_DIFF_LENGTH: usize = 1000;
 
 impl BotContext {
     pub async fn get_user(&self, user_id: UserId) -> Result<Arc<CachedUser>, ParseError> {
@@ -61,14 +67,18 @@ impl BotContext {
         }
     }
 
+    /// Only looks at the configs that are already loaded, for background work that shouldn't pull in the configs of
+    /// guilds the bot might not even be in anymore.
+    pub async fn get_cached_config(&self, guild_id: GuildId) -> Option<Arc<GuildConfig>> {
+        self.configs.read().await.get(&guild_id).cloned()
+    }
+
     pub async fn get_config(&self, guild_id: GuildId) -> Result<Arc<GuildConfig>, DatabaseError> {
-        // Clone the option so we can release the lock much faster
-        let config = self.configs.read().await.get(&guild_id).cloned();
-        match config {
+        match self.get_cached_config(guild_id).await {
             Some(config) => Ok(config),
             None => {
                 let datastore = &self.datastore;
-                let config = match datastore.get_guild_config(guild_id.0).await? {
+                let config = match datastore.get_guild_config(guild_id.0, self.bot_user.id).await? {
                     Some(c) => c,
                     None => datastore.create_new_guild_config(guild_id.0).await?,
                 };
@@ -80,11 +90,135 @@ impl BotContext {
         }
     }
 
-    pub async fn set_config(&self, guild_id: GuildId, config: GuildConfig) -> Result<(), DatabaseError> {
-        //TODO: validate values? or do we leave that to whoever edited it?
-        self.datastore.set_guild_config(guild_id.0, &config).await?;
+    /// Hands out the compiled censor filters of a guild, they are only compiled again when the config got replaced.
+    pub async fn get_censor_filters(&self, guild_id: GuildId) -> Result<Arc<CensorFilters>, DatabaseError> {
+        let config = self.get_config(guild_id).await?;
+        if let Some((compiled_from, filters)) = self.censor_filters.read().await.get(&guild_id) {
+            if Arc::ptr_eq(compiled_from, &config) {
+                return Ok(Arc::clone(filters));
+            }
+        }
+
+        let filters = Arc::new(config.censoring.compile_filters());
+        self.censor_filters
+            .write()
+            .await
+            .insert(guild_id, (config, Arc::clone(&filters)));
+        Ok(filters)
+    }
+
+    /// Validates and saves a new config, no matter who edited it. Errors the old config already had don't block it, a
+    /// role that got deleted shouldn't keep every other setting from being changed.
+    ///
+    /// The old config goes into the history and what changed gets logged. Returns the warnings that were found, the
+    /// config was saved regardless of those.
+    pub async fn set_config(
+        &self,
+        guild_id: GuildId,
+        config: GuildConfig,
+        author: UserId,
+        source: ConfigSource,
+    ) -> Result<Vec<ConfigIssue>, ConfigError> {
+        let old_config = self.get_config(guild_id).await?;
+        let (old_issues, issues) = match self.cache.get_guild(&guild_id).await {
+            Some(guild) => (
+                old_config.validate(self, &guild).await,
+                config.validate(self, &guild).await,
+            ),
+            // Without the guild only the settings themselves can be checked
+            None => (old_config.check_settings(), config.check_settings()),
+        };
+
+        let (errors, warnings): (Vec<ConfigIssue>, Vec<ConfigIssue>) =
+            issues.into_iter().partition(|issue| issue.is_error());
+        let errors: Vec<ConfigIssue> = errors.into_iter().filter(|error| !old_issues.contains(error)).collect();
+        if !errors.is_empty() {
+            return Err(ConfigError::Invalid(errors));
+        }
+
+        let revision = self
+            .datastore
+            .set_guild_config(guild_id.0, &config, author, source)
+            .await?;
+
+        let old = serde_json::to_value(&*old_config).map_err(DatabaseError::Serializing)?;
+        let new = serde_json::to_value(&config).map_err(DatabaseError::Serializing)?;
         self.configs.write().await.insert(guild_id, Arc::new(config));
-        Ok(())
+
+        let lines = config_editing::diff(&old, &new);
+        if !lines.is_empty() {
+            self.log(LogData {
+                log_type: LogType::ConfigChanged {
+                    revision,
+                    source,
+                    diff: config_editing::shorten_diff(lines, LOG_DIFF_LENGTH),
+                },
+                guild: guild_id,
+                source_channel: None,
+                source_user: author,
+            });
+        }
+
+        Ok(warnings)
+    }
+
+    /// The custom commands of a guild by their trigger, only loaded from the database the first time.
+    pub async fn get_custom_commands(&self, guild_id: GuildId) -> Result<Arc<HashMap<String, String>>, DatabaseError> {
+        if let Some(commands) = self.custom_commands.read().await.get(&guild_id) {
+            return Ok(Arc::clone(commands));
+        }
+
+        let commands: HashMap<String, String> = self
+            .datastore
+            .get_custom_commands(guild_id)
+            .await?
+            .into_iter()
+            .map(|command| (command.trigger, command.response))
+            .collect();
+
+        let commands = Arc::new(commands);
+        self.custom_commands
+            .write()
+            .await
+            .insert(guild_id, Arc::clone(&commands));
+        Ok(commands)
+    }
+
+    /// Adds a custom command, returns `false` if the guild already has one with that trigger.
+    pub async fn add_custom_command(
+        &self,
+        guild_id: GuildId,
+        trigger: &str,
+        response: &str,
+    ) -> Result<bool, DatabaseError> {
+        let added = self
+            .datastore
+            .insert_custom_command(guild_id, trigger, response)
+            .await?;
+        self.custom_commands.write().await.remove(&guild_id);
+        Ok(added)
+    }
+
+    /// Changes the response of a custom command, returns `false` if the guild has none with that trigger.
+    pub async fn edit_custom_command(
+        &self,
+        guild_id: GuildId,
+        trigger: &str,
+        response: &str,
+    ) -> Result<bool, DatabaseError> {
+        let edited = self
+            .datastore
+            .update_custom_command(guild_id, trigger, response)
+            .await?;
+        self.custom_commands.write().await.remove(&guild_id);
+        Ok(edited)
+    }
+
+    /// Removes a custom command, returns `false` if the guild has none with that trigger.
+    pub async fn remove_custom_command(&self, guild_id: GuildId, trigger: &str) -> Result<bool, DatabaseError> {
+        let removed = self.datastore.delete_custom_command(guild_id, trigger).await?;
+        self.custom_commands.write().await.remove(&guild_id);
+        Ok(removed)
     }
 
     pub async fn fetch_user_message(
@@ -19,7 +19,10 @@ pub use stats::BotStats;
 
 use crate::cache::Cache;
 use crate::core::logpump::LogData;
-use crate::core::GuildConfig;
+use crate::core::raids::RaidTracker;
+use crate::core::rename_limiter::RenameLimiter;
+use crate::core::spam::SpamBuckets;
+use crate::core::{CensorFilters, GuildConfig};
 use crate::database::redis::api_handlers::api_structs::{RawTeamMembers, TeamInfo, TeamMember};
 use crate::database::DataStorage;
 use crate::translation::{GearBotString, Translations};
@@ -29,7 +32,7 @@ use std::collections::HashMap;
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use tokio::sync::mpsc::UnboundedSender;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, RwLock};
 use unic_langid::LanguageIdentifier;
 
 #[derive(PartialEq, Debug)]
@@ -53,6 +56,13 @@ pub struct BotContext {
     pub status_text: RwLock<String>,
     pub bot_user: CurrentUser,
     configs: RwLock<HashMap<GuildId, Arc<GuildConfig>>>,
+    /// The compiled censor filters, together with the config they were compiled from.
+    censor_filters: RwLock<HashMap<GuildId, (Arc<GuildConfig>, Arc<CensorFilters>)>>,
+    /// The custom commands of a guild by trigger, so the database isn't asked for every message.
+    custom_commands: RwLock<HashMap<GuildId, Arc<HashMap<String, String>>>>,
+    pub spam_buckets: SpamBuckets,
+    pub raid_tracker: RaidTracker,
+    pub rename_limiter: RenameLimiter,
     pub datastore: DataStorage,
     pub translations: Translations,
     pub scheme_info: SchemeInfo,
@@ -61,6 +71,7 @@ pub struct BotContext {
     pub global_admins: Vec<UserId>,
     team_info: RawTeamMembers,
     logpump_sender: UnboundedSender<LogData>,
+    timed_action_notifier: Notify,
 }
 
 impl BotContext {
@@ -98,6 +109,11 @@ impl BotContext {
             status_text: RwLock::new(String::from("the commands turn")),
             bot_user: http_info.1,
             configs: RwLock::new(HashMap::new()),
+            censor_filters: RwLock::new(HashMap::new()),
+            custom_commands: RwLock::new(HashMap::new()),
+            spam_buckets: SpamBuckets::default(),
+            raid_tracker: RaidTracker::default(),
+            rename_limiter: RenameLimiter::default(),
             datastore,
             translations,
             scheme_info,
@@ -106,6 +122,7 @@ impl BotContext {
             global_admins,
             team_info,
             logpump_sender,
+            timed_action_notifier: Notify::new(),
         }
     }
 
@@ -151,4 +168,14 @@ impl BotContext {
         let _ = self.logpump_sender.send(data);
         self.stats.logpump_stats.pending_logs.inc();
     }
+
+    /// Wakes up the timed action scheduler, this needs to happen after adding a timed action so it doesn't
+    /// oversleep when the new action ends before the one it was waiting on.
+    pub fn reschedule_timed_actions(&self) {
+        self.timed_action_notifier.notify_one();
+    }
+
+    pub async fn timed_actions_changed(&self) {
+        self.timed_action_notifier.notified().await;
+    }
 }
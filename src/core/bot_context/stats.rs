@@ -89,6 +89,14 @@ pub struct LogpumpStats {
 
 pub struct LogTypeCounters {
     pub general: IntCounter,
+    pub moderation: IntCounter,
+    pub join_leave: IntCounter,
+    pub name_changes: IntCounter,
+    pub role_changes: IntCounter,
+    pub message_logs: IntCounter,
+    pub censored_messages: IntCounter,
+    pub spam: IntCounter,
+    pub raids: IntCounter,
 }
 
 pub struct BotStats {
@@ -103,6 +111,7 @@ pub struct BotStats {
     pub guild_counts: GuildCounters,
     pub emoji_count: IntGauge,
     pub role_count: IntGauge,
+    pub stored_messages: IntGauge,
     pub command_counts: IntCounterVec,
     pub total_command_counts: AtomicU64,
     pub logpump_stats: LogpumpStats,
@@ -116,6 +125,7 @@ impl BotStats {
         let channel_count = IntGauge::with_opts(Opts::new("channels", "Channel count")).unwrap();
         let emoji_count = IntGauge::with_opts(Opts::new("emoji", "Emoji count")).unwrap();
         let role_count = IntGauge::with_opts(Opts::new("roles", "Role count")).unwrap();
+        let stored_messages = IntGauge::with_opts(Opts::new("stored_messages", "Estimated amount of stored messages")).unwrap();
         let guild_counter = IntGaugeVec::new(Opts::new("guild_counts", "State of the guilds"), &["state"]).unwrap();
         let user_counter = IntGaugeVec::new(Opts::new("user_counts", "User counts"), &["type"]).unwrap();
         let shard_counter = IntGaugeVec::new(Opts::new("shard_counts", "State counts for our shards"), &["state"]).unwrap();
@@ -132,6 +142,7 @@ impl BotStats {
         registry.register(Box::new(channel_count.clone())).unwrap();
         registry.register(Box::new(emoji_count.clone())).unwrap();
         registry.register(Box::new(role_count.clone())).unwrap();
+        registry.register(Box::new(stored_messages.clone())).unwrap();
         registry.register(Box::new(guild_counter.clone())).unwrap();
         registry.register(Box::new(user_counter.clone())).unwrap();
         registry.register(Box::new(shard_counter.clone())).unwrap();
@@ -200,6 +211,7 @@ impl BotStats {
             channel_count,
             emoji_count,
             role_count,
+            stored_messages,
             shard_counts: ShardStats {
                 pending: shard_counter.get_metric_with_label_values(&["pending"]).unwrap(),
                 connecting: shard_counter.get_metric_with_label_values(&["connecting"]).unwrap(),
@@ -216,10 +228,26 @@ impl BotStats {
                 active_pumps,
                 pending_logs,
                 embed: LogTypeCounters {
-                    general: pumped_logs.get_metric_with_label_values(&["embed", "general"]).unwrap()
+                    general: pumped_logs.get_metric_with_label_values(&["embed", "general"]).unwrap(),
+                    moderation: pumped_logs.get_metric_with_label_values(&["embed", "moderation"]).unwrap(),
+                    join_leave: pumped_logs.get_metric_with_label_values(&["embed", "join_leave"]).unwrap(),
+                    name_changes: pumped_logs.get_metric_with_label_values(&["embed", "name_changes"]).unwrap(),
+                    role_changes: pumped_logs.get_metric_with_label_values(&["embed", "role_changes"]).unwrap(),
+                    message_logs: pumped_logs.get_metric_with_label_values(&["embed", "message_logs"]).unwrap(),
+                    censored_messages: pumped_logs.get_metric_with_label_values(&["embed", "censored_messages"]).unwrap(),
+                    spam: pumped_logs.get_metric_with_label_values(&["embed", "spam"]).unwrap(),
+                    raids: pumped_logs.get_metric_with_label_values(&["embed", "raids"]).unwrap()
                 },
                 text: LogTypeCounters {
-                    general: pumped_logs.get_metric_with_label_values(&["text", "general"]).unwrap()
+                    general: pumped_logs.get_metric_with_label_values(&["text", "general"]).unwrap(),
+                    moderation: pumped_logs.get_metric_with_label_values(&["text", "moderation"]).unwrap(),
+                    join_leave: pumped_logs.get_metric_with_label_values(&["text", "join_leave"]).unwrap(),
+                    name_changes: pumped_logs.get_metric_with_label_values(&["text", "name_changes"]).unwrap(),
+                    role_changes: pumped_logs.get_metric_with_label_values(&["text", "role_changes"]).unwrap(),
+                    message_logs: pumped_logs.get_metric_with_label_values(&["text", "message_logs"]).unwrap(),
+                    censored_messages: pumped_logs.get_metric_with_label_values(&["text", "censored_messages"]).unwrap(),
+                    spam: pumped_logs.get_metric_with_label_values(&["text", "spam"]).unwrap(),
+                    raids: pumped_logs.get_metric_with_label_values(&["text", "raids"]).unwrap()
                 }
             },
         }
@@ -245,6 +273,22 @@ impl BotStats {
         match (log_style, category) {
             (LogStyle::Text, LogCategory::GENERAL) => self.logpump_stats.text.general.inc(),
             (LogStyle::Embed, LogCategory::GENERAL) => self.logpump_stats.embed.general.inc(),
+            (LogStyle::Text, LogCategory::MODERATION) => self.logpump_stats.text.moderation.inc(),
+            (LogStyle::Embed, LogCategory::MODERATION) => self.logpump_stats.embed.moderation.inc(),
+            (LogStyle::Text, LogCategory::JOIN_LEAVE) => self.logpump_stats.text.join_leave.inc(),
+            (LogStyle::Embed, LogCategory::JOIN_LEAVE) => self.logpump_stats.embed.join_leave.inc(),
+            (LogStyle::Text, LogCategory::NAME_CHANGES) => self.logpump_stats.text.name_changes.inc(),
+            (LogStyle::Embed, LogCategory::NAME_CHANGES) => self.logpump_stats.embed.name_changes.inc(),
+            (LogStyle::Text, LogCategory::ROLE_CHANGES) => self.logpump_stats.text.role_changes.inc(),
+            (LogStyle::Embed, LogCategory::ROLE_CHANGES) => self.logpump_stats.embed.role_changes.inc(),
+            (LogStyle::Text, LogCategory::MESSAGE_LOGS) => self.logpump_stats.text.message_logs.inc(),
+            (LogStyle::Embed, LogCategory::MESSAGE_LOGS) => self.logpump_stats.embed.message_logs.inc(),
+            (LogStyle::Text, LogCategory::CENSORED_MESSAGES) => self.logpump_stats.text.censored_messages.inc(),
+            (LogStyle::Embed, LogCategory::CENSORED_MESSAGES) => self.logpump_stats.embed.censored_messages.inc(),
+            (LogStyle::Text, LogCategory::SPAM) => self.logpump_stats.text.spam.inc(),
+            (LogStyle::Embed, LogCategory::SPAM) => self.logpump_stats.embed.spam.inc(),
+            (LogStyle::Text, LogCategory::RAIDS) => self.logpump_stats.text.raids.inc(),
+            (LogStyle::Embed, LogCategory::RAIDS) => self.logpump_stats.embed.raids.inc(),
         }
     }
 }
@@ -1,13 +1,14 @@
 use fluent_bundle::FluentArgs;
+use twilight_http::Client as HttpClient;
 use twilight_model::{
     channel::{embed::Embed, Message},
-    id::{ChannelId, MessageId},
+    id::{ChannelId, MessageId, WebhookId},
 };
 
 use crate::translation::GearBotString;
 
-use super::CommandContext;
-use crate::error::CommandError;
+use super::{CommandContext, CommandSource};
+use crate::error::{CommandError, OtherFailure};
 
 impl CommandContext {
     pub async fn send_message(
@@ -83,36 +84,43 @@ impl CommandContext {
 
     pub async fn reply(&self, key: GearBotString, args: FluentArgs<'_>) -> Result<Message, CommandError> {
         let translated = self.translate_with_args(key, &args);
-        let sent_msg_handle = self
-            .bot_context
-            .http
-            .create_message(self.message.channel.get_id())
-            .content(translated)?
-            .await?;
+        self.message
+            .source
+            .respond(&self.bot_context.http, Some(translated), None, None)
+            .await
+    }
 
-        Ok(sent_msg_handle)
+    pub async fn reply_with_attachment(
+        &self,
+        key: GearBotString,
+        args: FluentArgs<'_>,
+        name: impl Into<String>,
+        file: Vec<u8>,
+    ) -> Result<Message, CommandError> {
+        let translated = self.translate_with_args(key, &args);
+        self.message
+            .source
+            .respond(
+                &self.bot_context.http,
+                Some(translated),
+                None,
+                Some((name.into(), file)),
+            )
+            .await
     }
 
     pub async fn reply_raw<T: std::fmt::Display>(&self, message: T) -> Result<Message, CommandError> {
-        let sent_msg_handle = self
-            .bot_context
-            .http
-            .create_message(self.message.channel.get_id())
-            .content(message.to_string())?
-            .await?;
-
-        Ok(sent_msg_handle)
+        self.message
+            .source
+            .respond(&self.bot_context.http, Some(message.to_string()), None, None)
+            .await
     }
 
     pub async fn reply_embed(&self, embed: Embed) -> Result<Message, CommandError> {
-        let sent_embed_handle = self
-            .bot_context
-            .http
-            .create_message(self.message.channel.get_id())
-            .embed(embed)?
-            .await?;
-
-        Ok(sent_embed_handle)
+        self.message
+            .source
+            .respond(&self.bot_context.http, None, Some(embed), None)
+            .await
     }
 
     pub async fn reply_with_embed(
@@ -122,15 +130,10 @@ impl CommandContext {
         embed: Embed,
     ) -> Result<Message, CommandError> {
         let translated = self.translate_with_args(key, &args);
-        let sent_handle = self
-            .bot_context
-            .http
-            .create_message(self.message.channel.get_id())
-            .content(translated)?
-            .embed(embed)?
-            .await?;
-
-        Ok(sent_handle)
+        self.message
+            .source
+            .respond(&self.bot_context.http, Some(translated), Some(embed), None)
+            .await
     }
 
     pub async fn reply_raw_with_embed(
@@ -138,14 +141,54 @@ impl CommandContext {
         message: impl Into<String>,
         embed: Embed,
     ) -> Result<Message, CommandError> {
-        let sent_handle = self
-            .bot_context
-            .http
-            .create_message(self.message.channel.get_id())
-            .content(message)?
-            .embed(embed)?
-            .await?;
+        self.message
+            .source
+            .respond(&self.bot_context.http, Some(message.into()), Some(embed), None)
+            .await
+    }
+}
 
-        Ok(sent_handle)
+impl CommandSource {
+    /// Answers the command, with a message in the channel it was used in or a follow up to the interaction. Follow ups
+    /// are sent through the webhook of the interaction, so they don't need permission to send messages there.
+    pub async fn respond(
+        &self,
+        http: &HttpClient,
+        content: Option<String>,
+        embed: Option<Embed>,
+        attachment: Option<(String, Vec<u8>)>,
+    ) -> Result<Message, CommandError> {
+        match self {
+            CommandSource::Message(channel_id) => {
+                let mut request = http.create_message(*channel_id);
+                if let Some(content) = content {
+                    request = request.content(content)?;
+                }
+                if let Some(embed) = embed {
+                    request = request.embed(embed)?;
+                }
+                if let Some((name, file)) = attachment {
+                    request = request.attachment(name, file);
+                }
+
+                Ok(request.await?)
+            }
+            CommandSource::Interaction { application_id, token } => {
+                let mut request = http.execute_webhook(WebhookId(application_id.0), token).wait(true);
+                if let Some(content) = content {
+                    request = request.content(content);
+                }
+                if let Some(embed) = embed {
+                    request = request.embeds(vec![embed]);
+                }
+                if let Some((name, file)) = attachment {
+                    request = request.file(name, file);
+                }
+
+                request
+                    .await?
+                    .ok_or(CommandError::OtherFailure(OtherFailure::MissingFollowup))
+            }
+        }
     }
 }
@@ -9,14 +9,16 @@ use twilight_model::channel::Attachment;
 use twilight_model::{id::MessageId, user::CurrentUser};
 
 use super::bot_context::BotContext;
+use super::config_validation::ConfigIssue;
 use super::logpump::{LogData, LogType};
 use super::GuildConfig;
 use crate::cache::{CachedChannel, CachedGuild, CachedMember, CachedUser};
 use crate::commands::meta::nodes::GearBotPermissions;
-use crate::error::{CommandError, OtherFailure};
+use crate::database::structures::ConfigSource;
+use crate::error::{CommandError, ConfigError, OtherFailure};
 use crate::parser::Parser;
-use crate::translation::GearBotString;
-use twilight_model::id::{ChannelId, UserId};
+use crate::translation::{FluArgs, GearBotString};
+use twilight_model::id::{ApplicationId, ChannelId, UserId};
 
 mod messaging;
 mod object_fetcher;
@@ -34,6 +36,20 @@ pub struct CommandMessage {
     pub kind: MessageType,
     pub mention_everyone: bool,
     pub tts: bool,
+    pub source: CommandSource,
+}
+
+/// Where a command came from, which decides how it gets answered.
+#[derive(Clone)]
+pub enum CommandSource {
+    /// Typed out in a channel, replies are sent to that channel.
+    Message(ChannelId),
+    /// Used as a slash command. The interaction gets acknowledged before the handler runs, so replies are follow ups to
+    /// it.
+    Interaction {
+        application_id: ApplicationId,
+        token: String,
+    },
 }
 
 impl CommandMessage {
@@ -102,12 +118,34 @@ impl CommandContext {
             .replace("\\n", "\n")
     }
 
-    pub async fn set_config(&self, new_config: GuildConfig) -> Result<(), CommandError> {
+    /// Saves the config if it is valid, returns the warnings that came up so they can be shown.
+    pub async fn set_config(&self, new_config: GuildConfig) -> Result<Vec<ConfigIssue>, CommandError> {
         // This updates it both in the DB and handles our element guard
-        self.bot_context
-            .set_config(self.guild.id, new_config)
-            .await
-            .map_err(|e| CommandError::OtherFailure(OtherFailure::DatabaseError(e)))
+        let result = self
+            .bot_context
+            .set_config(self.guild.id, new_config, self.message.author.id, ConfigSource::Command)
+            .await;
+        match result {
+            Ok(warnings) => Ok(warnings),
+            Err(ConfigError::Invalid(issues)) => {
+                let args = FluArgs::with_capacity(1)
+                    .add("issues", self.issue_list(&issues))
+                    .generate();
+                Err(CommandError::InvalidConfig(
+                    self.translate_with_args(GearBotString::ConfigInvalid, &args),
+                ))
+            }
+            Err(ConfigError::Database(e)) => Err(CommandError::OtherFailure(OtherFailure::DatabaseError(e))),
+        }
+    }
+
+    /// Puts every issue on its own line, in the language of the guild.
+    pub fn issue_list(&self, issues: &[ConfigIssue]) -> String {
+        issues
+            .iter()
+            .map(|issue| format!("- {}", issue.to_text(&self.bot_context, &self.config.language)))
+            .collect::<Vec<String>>()
+            .join("\n")
     }
 
     pub fn get_config(&self) -> Result<Arc<GuildConfig>, CommandError> {
@@ -1,9 +1,13 @@
+use std::sync::Arc;
+
 use twilight_model::{
     guild::Permissions,
     id::{ChannelId, UserId},
 };
 
 use super::CommandContext;
+use crate::cache::CachedMember;
+use crate::core::moderation;
 
 impl CommandContext {
     pub async fn bot_has_guild_permissions(&self, permissions: Permissions) -> bool {
@@ -72,4 +76,28 @@ impl CommandContext {
     pub async fn author_has_guild_permissions(&self, permissions: Permissions) -> bool {
         self.get_author_guild_permissions().await.contains(permissions)
     }
+
+    /// Checks if the author of the command sits above the target in the role hierarchy.
+    ///
+    /// The guild owner outranks everyone and can never be outranked themselves.
+    pub async fn author_outranks(&self, target: &Arc<CachedMember>) -> bool {
+        let author = match &self.message.author_as_member {
+            Some(author) => author,
+            None => return false,
+        };
+
+        self.outranks(author, target).await
+    }
+
+    /// Checks if the bot sits above the target in the role hierarchy, and is thus able to moderate them.
+    pub async fn bot_outranks(&self, target: &Arc<CachedMember>) -> bool {
+        match self.get_member(&self.get_bot_user().id).await {
+            Some(bot) => self.outranks(&bot, target).await,
+            None => false,
+        }
+    }
+
+    async fn outranks(&self, member: &Arc<CachedMember>, target: &Arc<CachedMember>) -> bool {
+        moderation::outranks(self.get_guild(), member, target).await
+    }
 }
@@ -0,0 +1,262 @@
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::error::ParseError;
+
+/// Keeps a single big change, like a whole list being replaced, from pushing everything else out of a shortened diff.
+const DIFF_LINE_LENGTH: usize = 200;
+
+/// Turns what was typed in chat into a json value. Anything that isn't valid json is taken as a plain string, as is
+/// everything that replaces or joins a string, so `config set prefix 123` doesn't end up with a number.
+pub fn parse_value(input: &str, current: Option<&Value>) -> Value {
+    match current {
+        Some(Value::String(_)) => Value::String(input.to_string()),
+        _ => serde_json::from_str(input).unwrap_or_else(|_| Value::String(input.to_string())),
+    }
+}
+
+/// Follows a dotted path like `censoring.word_list` or `permission_groups.0.roles` through the serde representation
+/// of a config.
+pub fn get<'v>(value: &'v Value, path: &str) -> Result<&'v Value, ParseError> {
+    let mut current = value;
+    for key in segments(path)? {
+        current = match current {
+            Value::Object(map) => map.get(key),
+            Value::Array(list) => key.parse::<usize>().ok().and_then(|index| list.get(index)),
+            _ => None,
+        }
+        .ok_or_else(|| unknown_path(path))?;
+    }
+
+    Ok(current)
+}
+
+fn get_mut<'v>(value: &'v mut Value, path: &str) -> Result<&'v mut Value, ParseError> {
+    let mut current = value;
+    for key in segments(path)? {
+        current = match current {
+            Value::Object(map) => map.get_mut(key),
+            Value::Array(list) => key.parse::<usize>().ok().and_then(move |index| list.get_mut(index)),
+            _ => None,
+        }
+        .ok_or_else(|| unknown_path(path))?;
+    }
+
+    Ok(current)
+}
+
+/// Replaces the value at the path. New keys can only be added to objects, whether that object accepts them is up to
+/// deserializing the result.
+pub fn set(value: &mut Value, path: &str, new: Value) -> Result<(), ParseError> {
+    let (parent, key) = split_last(path)?;
+    let parent = match parent {
+        Some(parent) => get_mut(value, parent)?,
+        None => value,
+    };
+
+    match parent {
+        Value::Object(map) => {
+            map.insert(key.to_string(), new);
+        }
+        Value::Array(list) => {
+            let slot = key
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| list.get_mut(index))
+                .ok_or_else(|| unknown_path(path))?;
+            *slot = new;
+        }
+        _ => return Err(unknown_path(path)),
+    }
+
+    Ok(())
+}
+
+pub fn add_to_list(value: &mut Value, path: &str, new: Value) -> Result<(), ParseError> {
+    let list = get_list(value, path)?;
+    if list.iter().any(|existing| same_value(existing, &new)) {
+        return Err(ParseError::AlreadyInConfigList(path.to_string()));
+    }
+
+    list.push(new);
+    Ok(())
+}
+
+pub fn remove_from_list(value: &mut Value, path: &str, old: &Value) -> Result<(), ParseError> {
+    let list = get_list(value, path)?;
+    let before = list.len();
+    list.retain(|existing| !same_value(existing, old));
+    if list.len() == before {
+        return Err(ParseError::NotInConfigList(path.to_string()));
+    }
+
+    Ok(())
+}
+
+/// Puts the default back at the path. Entries that don't exist in the default config, like a log channel, are removed
+/// instead.
+pub fn reset(value: &mut Value, defaults: &Value, path: &str) -> Result<(), ParseError> {
+    if let Ok(default) = get(defaults, path) {
+        return set(value, path, default.clone());
+    }
+
+    let (parent, key) = split_last(path)?;
+    let parent = match parent {
+        Some(parent) => get_mut(value, parent)?,
+        None => value,
+    };
+
+    match parent {
+        Value::Object(map) if map.remove(key).is_some() => Ok(()),
+        _ => Err(unknown_path(path)),
+    }
+}
+
+/// Lists everything that changed between two versions, one line per changed path. Lines for old values start with
+/// `-` and new ones with `+`, so they can be shown in a diff code block.
+pub fn diff(old: &Value, new: &Value) -> Vec<String> {
+    let mut lines = vec![];
+    diff_at(String::new(), Some(old), Some(new), &mut lines);
+    lines
+}
+
+/// Joins the lines of a diff, cutting it off once it would no longer fit in `max_length` bytes.
+pub fn shorten_diff(lines: Vec<String>, max_length: usize) -> String {
+    let mut diff = String::new();
+    for line in lines {
+        let line = if line.chars().count() > DIFF_LINE_LENGTH {
+            line.chars().take(DIFF_LINE_LENGTH).collect::<String>() + "..."
+        } else {
+            line
+        };
+
+        if diff.len() + line.len() > max_length {
+            diff += "...";
+            break;
+        }
+        diff += &line;
+        diff.push('\n');
+    }
+
+    diff.trim_end().to_string()
+}
+
+fn diff_at(path: String, old: Option<&Value>, new: Option<&Value>, lines: &mut Vec<String>) {
+    if old == new {
+        return;
+    }
+
+    if let (Some(Value::Object(old)), Some(Value::Object(new))) = (old, new) {
+        let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        for key in keys {
+            let path = if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            diff_at(path, old.get(key), new.get(key), lines);
+        }
+        return;
+    }
+
+    if let Some(old) = old {
+        lines.push(format!("- {} = {}", path, old));
+    }
+    if let Some(new) = new {
+        lines.push(format!("+ {} = {}", path, new));
+    }
+}
+
+fn get_list<'v>(value: &'v mut Value, path: &str) -> Result<&'v mut Vec<Value>, ParseError> {
+    match get_mut(value, path)? {
+        Value::Array(list) => Ok(list),
+        _ => Err(ParseError::NotAConfigList(path.to_string())),
+    }
+}
+
+/// Ids are stored as strings but typed as numbers, those should still count as the same.
+fn same_value(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::String(s), Value::Number(n)) | (Value::Number(n), Value::String(s)) => *s == n.to_string(),
+        _ => a == b,
+    }
+}
+
+fn segments(path: &str) -> Result<Vec<&str>, ParseError> {
+    let segments: Vec<&str> = path.split('.').collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(unknown_path(path));
+    }
+
+    Ok(segments)
+}
+
+fn split_last(path: &str) -> Result<(Option<&str>, &str), ParseError> {
+    segments(path)?;
+    Ok(match path.rfind('.') {
+        Some(dot) => (Some(&path[..dot]), &path[dot + 1..]),
+        None => (None, path),
+    })
+}
+
+fn unknown_path(path: &str) -> ParseError {
+    ParseError::UnknownConfigPath(path.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn paths_are_edited() {
+        let mut config = json!({
+            "prefix": "!",
+            "mute": { "role": null },
+            "censoring": { "word_list": ["bad"], "exempt_roles": ["123"] }
+        });
+
+        assert_eq!(get(&config, "censoring.word_list.0").unwrap(), "bad");
+        assert!(get(&config, "censoring.nope").is_err());
+        assert!(get(&config, "censoring..word_list").is_err());
+
+        let old = config.clone();
+        let value = parse_value("1234", get(&config, "prefix").ok());
+        set(&mut config, "prefix", value).unwrap();
+        set(&mut config, "mute.role", parse_value("456", None)).unwrap();
+        add_to_list(&mut config, "censoring.word_list", json!("worse")).unwrap();
+        assert!(add_to_list(&mut config, "censoring.word_list", json!("bad")).is_err());
+        assert!(add_to_list(&mut config, "prefix", json!("bad")).is_err());
+        remove_from_list(&mut config, "censoring.exempt_roles", &json!(123)).unwrap();
+        assert!(remove_from_list(&mut config, "censoring.exempt_roles", &json!(123)).is_err());
+
+        assert_eq!(
+            diff(&old, &config),
+            vec![
+                "- censoring.exempt_roles = [\"123\"]",
+                "+ censoring.exempt_roles = []",
+                "- censoring.word_list = [\"bad\"]",
+                "+ censoring.word_list = [\"bad\",\"worse\"]",
+                "- mute.role = null",
+                "+ mute.role = 456",
+                "- prefix = \"!\"",
+                "+ prefix = \"1234\"",
+            ]
+        );
+
+        reset(&mut config, &old, "prefix").unwrap();
+        reset(&mut config, &old, "censoring").unwrap();
+        assert_eq!(diff(&old, &config), vec!["- mute.role = null", "+ mute.role = 456"]);
+    }
+
+    #[test]
+    fn diffs_are_shortened() {
+        let lines = vec![String::from("- prefix = \"!\""), String::from("+ prefix = \"?\"")];
+        assert_eq!(shorten_diff(lines.clone(), 100), "- prefix = \"!\"\n+ prefix = \"?\"");
+        assert_eq!(shorten_diff(lines, 20), "- prefix = \"!\"\n...");
+
+        let long = shorten_diff(vec!["+".repeat(DIFF_LINE_LENGTH * 2)], 1000);
+        assert_eq!(long.chars().count(), DIFF_LINE_LENGTH + 3);
+    }
+}
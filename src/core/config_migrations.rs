@@ -0,0 +1,196 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::guild_config::{
+    CensorConfig, ChannelFilter, MuteConfig, RaidConfig, SpamConfig, DEFAULT_MESSAGE_RETENTION_DAYS,
+};
+use crate::error::MigrationError;
+
+/// Upgrades the stored form of a config by one version. They work on the json as it was stored back then, and should
+/// never change once released since stored configs (and their history) can be of any version.
+type Migration = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+/// Migration `n` upgrades a config from version `n` to `n + 1`.
+const MIGRATIONS: [Migration; 2] = [add_missing_sections, fill_censor_fields];
+
+/// The version configs are written in by this build.
+pub const CONFIG_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Brings a stored config up to the current version, returns if anything had to be done for that.
+///
+/// Configs from before versioning don't have a version at all, they count as version 0.
+pub fn migrate(config: &mut Value) -> Result<bool, MigrationError> {
+    run_migrations(config, &MIGRATIONS)
+}
+
+fn run_migrations(config: &mut Value, migrations: &[Migration]) -> Result<bool, MigrationError> {
+    let config = config.as_object_mut().ok_or(MigrationError::NotAnObject)?;
+    let version = match config.get("version") {
+        None => 0,
+        Some(version) => version
+            .as_u64()
+            .ok_or_else(|| MigrationError::InvalidVersion(version.to_string()))?,
+    };
+
+    if version > migrations.len() as u64 {
+        return Err(MigrationError::TooNew(version));
+    }
+
+    for (from, migration) in migrations.iter().enumerate().skip(version as usize) {
+        migration(config).map_err(|reason| MigrationError::Failed {
+            version: from as u32,
+            reason,
+        })?;
+        config.insert(String::from("version"), Value::from(from as u32 + 1));
+    }
+
+    Ok(version < migrations.len() as u64)
+}
+
+/// 0 -> 1: Configs from before versioning. The sections that were added before then were filled in by serde defaults
+/// on every load, they are written out now so the stored config is complete.
+fn add_missing_sections(config: &mut Map<String, Value>) -> Result<(), String> {
+    config
+        .get_mut("message_logs")
+        .and_then(Value::as_object_mut)
+        .ok_or("message_logs is missing")?
+        .entry("retention_days")
+        .or_insert_with(|| Value::from(DEFAULT_MESSAGE_RETENTION_DAYS));
+
+    insert_missing(config, "mute", MuteConfig::default())?;
+    insert_missing(config, "censoring", CensorConfig::default())?;
+    insert_missing(config, "spam", SpamConfig::default())?;
+    insert_missing(config, "raids", RaidConfig::default())
+}
+
+/// 1 -> 2: The word and regex lists, name censoring and the channel filters were added to the censoring section after
+/// it existed, older sections (and filters) relied on serde defaults for them.
+fn fill_censor_fields(config: &mut Map<String, Value>) -> Result<(), String> {
+    let censoring = config
+        .get_mut("censoring")
+        .and_then(Value::as_object_mut)
+        .ok_or("censoring is missing")?;
+    fill_missing_fields(censoring, CensorConfig::default())?;
+
+    let filters = censoring
+        .get_mut("channel_filters")
+        .and_then(Value::as_object_mut)
+        .ok_or("channel_filters is not an object")?;
+    for filter in filters.values_mut() {
+        let filter = filter.as_object_mut().ok_or("channel filter is not an object")?;
+        fill_missing_fields(filter, ChannelFilter::default())?;
+    }
+
+    Ok(())
+}
+
+/// Adds the fields of `defaults` that the stored object doesn't have yet, leaving the ones it does have alone.
+fn fill_missing_fields<T: Serialize>(object: &mut Map<String, Value>, defaults: T) -> Result<(), String> {
+    let defaults = match serde_json::to_value(defaults).map_err(|e| e.to_string())? {
+        Value::Object(defaults) => defaults,
+        _ => return Err(String::from("defaults are not an object")),
+    };
+
+    for (key, value) in defaults {
+        object.entry(key).or_insert(value);
+    }
+
+    Ok(())
+}
+
+fn insert_missing<T: Serialize>(config: &mut Map<String, Value>, key: &str, section: T) -> Result<(), String> {
+    if !config.contains_key(key) {
+        let section = serde_json::to_value(section).map_err(|e| e.to_string())?;
+        config.insert(key.to_string(), section);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::GuildConfig;
+
+    #[test]
+    fn old_configs_are_upgraded() {
+        let mut config = serde_json::to_value(GuildConfig::default()).unwrap();
+        let current = config.clone();
+        assert!(!migrate(&mut config).unwrap());
+        assert_eq!(config, current);
+
+        // the shape configs were stored in before they had a version
+        let old = config.as_object_mut().unwrap();
+        for key in &["version", "mute", "censoring", "spam", "raids"] {
+            old.remove(*key);
+        }
+        old["message_logs"].as_object_mut().unwrap().remove("retention_days");
+
+        assert!(migrate(&mut config).unwrap());
+        assert_eq!(config["version"], CONFIG_VERSION);
+        assert_eq!(config["message_logs"]["retention_days"], DEFAULT_MESSAGE_RETENTION_DAYS);
+        serde_json::from_value::<GuildConfig>(config).unwrap();
+    }
+
+    #[test]
+    fn censor_fields_are_filled_in() {
+        let mut config = serde_json::to_value(GuildConfig::default()).unwrap();
+        config["version"] = Value::from(1);
+        config["censoring"] = serde_json::json!({
+            "censor_invites": true,
+            "invite_whitelist": [],
+            "word_list": ["bad"],
+            "channel_filters": { "1234": { "blocked_extensions": ["exe"] } },
+            "exempt_roles": [],
+            "exempt_channels": []
+        });
+
+        assert!(migrate(&mut config).unwrap());
+        let censoring = &config["censoring"];
+        assert_eq!(censoring["word_list"], serde_json::json!(["bad"]));
+        assert_eq!(censoring["regex_list"], serde_json::json!([]));
+        assert_eq!(
+            censoring["name_fallback"],
+            crate::core::guild_config::DEFAULT_NAME_FALLBACK
+        );
+        assert_eq!(
+            censoring["channel_filters"]["1234"]["blocked_extensions"],
+            serde_json::json!(["exe"])
+        );
+        assert_eq!(
+            censoring["channel_filters"]["1234"]["allowed_domains"],
+            serde_json::json!([])
+        );
+        serde_json::from_value::<GuildConfig>(config).unwrap();
+    }
+
+    #[test]
+    fn migrations_run_in_order() {
+        fn first(config: &mut Map<String, Value>) -> Result<(), String> {
+            config.insert(String::from("steps"), Value::from("first"));
+            Ok(())
+        }
+        fn second(config: &mut Map<String, Value>) -> Result<(), String> {
+            match config.get("steps") {
+                Some(steps) if steps == "first" => Err(String::from("first already ran")),
+                _ => Ok(()),
+            }
+        }
+
+        let mut config = serde_json::json!({ "version": 1 });
+        assert!(run_migrations(&mut config, &[first, second]).unwrap());
+        assert_eq!(config, serde_json::json!({ "version": 2 }));
+
+        let mut config = serde_json::json!({});
+        match run_migrations(&mut config, &[first, second]) {
+            Err(MigrationError::Failed { version: 1, .. }) => {}
+            other => panic!("expected the second migration to fail, got {:?}", other),
+        }
+
+        let mut config = serde_json::json!({ "version": 3 });
+        assert!(matches!(
+            run_migrations(&mut config, &[first, second]),
+            Err(MigrationError::TooNew(3))
+        ));
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use twilight_model::id::{ChannelId, RoleId};
+
+use crate::core::{config_migrations, GuildConfig};
+use crate::error::ParseError;
+
+/// What `config export` produces. The names of the roles and channels the config refers to come along, so they can be
+/// matched up with the ones of the guild it gets imported into.
+#[derive(Deserialize, Serialize)]
+pub struct ConfigExport {
+    /// Kept as json so exports from older versions can still be migrated on import.
+    pub config: Value,
+    pub roles: HashMap<RoleId, String>,
+    pub channels: HashMap<ChannelId, String>,
+}
+
+/// What happened to a role or channel of the exporting guild that doesn't exist in the importing one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedId {
+    pub id: u64,
+    pub name: Option<String>,
+    /// The role or channel with the same name it was swapped for, if there was exactly one.
+    pub replacement: Option<u64>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportReport {
+    pub roles: Vec<ImportedId>,
+    pub channels: Vec<ImportedId>,
+    /// Names of the permission groups that were left out because their roles aren't all on this server.
+    pub permission_groups: Vec<String>,
+}
+
+impl ConfigExport {
+    /// Packs up the config, with the names of the roles and channels (out of all the given ones) that it uses.
+    pub fn new(
+        config: &GuildConfig,
+        roles: &[(RoleId, String)],
+        channels: &[(ChannelId, String)],
+    ) -> Result<Self, serde_json::Error> {
+        let referenced_roles = config.referenced_roles();
+        let referenced_channels = config.referenced_channels();
+
+        Ok(ConfigExport {
+            config: serde_json::to_value(config)?,
+            roles: roles
+                .iter()
+                .filter(|(id, _)| referenced_roles.contains(id))
+                .cloned()
+                .collect(),
+            channels: channels
+                .iter()
+                .filter(|(id, _)| referenced_channels.contains(id))
+                .cloned()
+                .collect(),
+        })
+    }
+
+    /// Turns the export into a config for the guild with the given roles and channels. Ids that exist there are kept,
+    /// the others are swapped for the role or channel with the same name or left out when there is no single match.
+    pub fn import(
+        self,
+        roles: &[(RoleId, String)],
+        channels: &[(ChannelId, String)],
+    ) -> Result<(GuildConfig, ImportReport), ParseError> {
+        let mut value = self.config;
+        config_migrations::migrate(&mut value).map_err(|e| ParseError::InvalidConfigFile(e.to_string()))?;
+        let mut config: GuildConfig =
+            serde_json::from_value(value).map_err(|e| ParseError::InvalidConfigFile(e.to_string()))?;
+
+        let mut role_matcher = IdMatcher::new(
+            self.roles.into_iter().map(|(id, name)| (id.0, name)).collect(),
+            roles.iter().map(|(id, name)| (id.0, name.as_str())),
+        );
+        let mut channel_matcher = IdMatcher::new(
+            self.channels.into_iter().map(|(id, name)| (id.0, name)).collect(),
+            channels.iter().map(|(id, name)| (id.0, name.as_str())),
+        );
+        let permission_groups = config.remap_ids(
+            |id| role_matcher.find(id.0).map(RoleId),
+            |id| channel_matcher.find(id.0).map(ChannelId),
+        );
+        config.permission_groups.sort_by(|a, b| a.priority.cmp(&b.priority));
+
+        let report = ImportReport {
+            roles: role_matcher.imported,
+            channels: channel_matcher.imported,
+            permission_groups,
+        };
+        Ok((config, report))
+    }
+}
+
+/// Finds what ids of one guild are in another one, remembering everything that didn't stay the same.
+struct IdMatcher {
+    names: HashMap<u64, String>,
+    existing: HashSet<u64>,
+    by_name: HashMap<String, Vec<u64>>,
+    imported: Vec<ImportedId>,
+}
+
+impl IdMatcher {
+    fn new<'a>(names: HashMap<u64, String>, existing: impl Iterator<Item = (u64, &'a str)>) -> Self {
+        let mut ids = HashSet::new();
+        let mut by_name: HashMap<String, Vec<u64>> = HashMap::new();
+        for (id, name) in existing {
+            ids.insert(id);
+            by_name.entry(name.to_string()).or_default().push(id);
+        }
+
+        IdMatcher {
+            names,
+            existing: ids,
+            by_name,
+            imported: vec![],
+        }
+    }
+
+    fn find(&mut self, id: u64) -> Option<u64> {
+        if self.existing.contains(&id) {
+            return Some(id);
+        }
+
+        // the same id can be in the config multiple times, it only has to be reported once
+        if let Some(imported) = self.imported.iter().find(|imported| imported.id == id) {
+            return imported.replacement;
+        }
+
+        let name = self.names.get(&id).cloned();
+        let replacement = match name.as_ref().and_then(|name| self.by_name.get(name)) {
+            Some(matches) if matches.len() == 1 => Some(matches[0]),
+            _ => None,
+        };
+
+        self.imported.push(ImportedId { id, name, replacement });
+        replacement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RaidAction;
+
+    #[test]
+    fn ids_are_matched_by_name() {
+        let mut config = GuildConfig::default();
+        config.mute.role = Some(RoleId(1));
+        config.censoring.exempt_roles = vec![RoleId(1), RoleId(2), RoleId(3)];
+        config.raids.action = RaidAction::Quarantine(RoleId(3));
+        config.spam.exempt_channels = vec![ChannelId(10), ChannelId(11)];
+
+        let source_roles = vec![
+            (RoleId(1), String::from("Muted")),
+            (RoleId(2), String::from("Staff")),
+            (RoleId(3), String::from("Quarantine")),
+            (RoleId(4), String::from("Unused")),
+        ];
+        let source_channels = vec![
+            (ChannelId(10), String::from("spam")),
+            (ChannelId(11), String::from("bots")),
+        ];
+        let export = ConfigExport::new(&config, &source_roles, &source_channels).unwrap();
+        assert_eq!(export.roles.len(), 3);
+
+        let target_roles = vec![
+            (RoleId(2), String::from("Staff")),
+            (RoleId(21), String::from("Muted")),
+            (RoleId(30), String::from("Quarantine")),
+            (RoleId(31), String::from("Quarantine")),
+        ];
+        let target_channels = vec![(ChannelId(110), String::from("bots"))];
+        let (imported, report) = export.import(&target_roles, &target_channels).unwrap();
+
+        assert_eq!(imported.mute.role, Some(RoleId(21)));
+        assert_eq!(imported.censoring.exempt_roles, vec![RoleId(21), RoleId(2)]);
+        assert_eq!(imported.raids.action, RaidAction::Nothing);
+        assert_eq!(imported.spam.exempt_channels, vec![ChannelId(110)]);
+
+        let muted = ImportedId {
+            id: 1,
+            name: Some(String::from("Muted")),
+            replacement: Some(21),
+        };
+        assert!(report.roles.contains(&muted));
+        assert_eq!(report.roles.len(), 2);
+        assert_eq!(report.channels.len(), 2);
+        assert!(report.permission_groups.is_empty());
+    }
+
+    #[test]
+    fn groups_needing_missing_roles_are_dropped() {
+        let mut config = GuildConfig::default();
+        let mut admins = config.permission_groups[0].clone();
+        admins.name = String::from("Admin");
+        admins.roles = vec![RoleId(1), RoleId(2)];
+        admins.needs_all = true;
+        let mut helpers = admins.clone();
+        helpers.name = String::from("Helpers");
+        helpers.roles = vec![RoleId(3)];
+        helpers.needs_all = false;
+        config.permission_groups = vec![admins, helpers];
+
+        let source_roles = vec![
+            (RoleId(1), String::from("Staff")),
+            (RoleId(2), String::from("Admin")),
+            (RoleId(3), String::from("Helper")),
+        ];
+        let export = ConfigExport::new(&config, &source_roles, &[]).unwrap();
+
+        let target_roles = vec![(RoleId(10), String::from("Staff"))];
+        let (imported, report) = export.import(&target_roles, &[]).unwrap();
+
+        assert!(imported.permission_groups.is_empty());
+        assert_eq!(
+            report.permission_groups,
+            vec![String::from("Admin"), String::from("Helpers")]
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::HashSet;
+
+use twilight_model::guild::Permissions;
+use twilight_model::id::{ChannelId, RoleId};
+use unic_langid::LanguageIdentifier;
+
+use crate::cache::CachedGuild;
+use crate::core::config_migrations::CONFIG_VERSION;
+use crate::core::{BotContext, GuildConfig};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils;
+
+pub const MAX_PREFIX_LENGTH: usize = 25;
+/// Discord doesn't allow nicknames any longer then this.
+pub const MAX_NICKNAME_LENGTH: usize = 32;
+
+/// A problem with a config. Errors keep a config from being saved, warnings are only pointed out.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigIssue {
+    WrongVersion,
+    EmptyPrefix,
+    PrefixTooLong,
+    UnsupportedLanguage(LanguageIdentifier),
+    DuplicatePriority(u8),
+    UnknownLogChannel(ChannelId),
+    UnknownChannel(ChannelId),
+    UnknownRole(RoleId),
+    MissingLogPermissions { channel: ChannelId, missing: Permissions },
+    InvalidNameFallback,
+    CensoredNameFallback,
+}
+
+impl ConfigIssue {
+    pub fn is_error(&self) -> bool {
+        !matches!(self, ConfigIssue::MissingLogPermissions { .. })
+    }
+
+    pub fn to_text(&self, ctx: &BotContext, lang: &LanguageIdentifier) -> String {
+        let (key, args) = match self {
+            ConfigIssue::WrongVersion => (GearBotString::ConfigIssueWrongVersion, FluArgs::with_capacity(0)),
+            ConfigIssue::EmptyPrefix => (GearBotString::ConfigIssueEmptyPrefix, FluArgs::with_capacity(0)),
+            ConfigIssue::PrefixTooLong => (
+                GearBotString::ConfigIssuePrefixTooLong,
+                FluArgs::with_capacity(1).add("max", MAX_PREFIX_LENGTH),
+            ),
+            ConfigIssue::UnsupportedLanguage(language) => (
+                GearBotString::ConfigIssueUnsupportedLanguage,
+                FluArgs::with_capacity(1).add("language", language.to_string()),
+            ),
+            ConfigIssue::DuplicatePriority(priority) => (
+                GearBotString::ConfigIssueDuplicatePriority,
+                FluArgs::with_capacity(1).add("priority", *priority),
+            ),
+            ConfigIssue::UnknownLogChannel(channel) => (
+                GearBotString::ConfigIssueUnknownLogChannel,
+                FluArgs::with_capacity(1).add("channel", channel.to_string()),
+            ),
+            ConfigIssue::UnknownChannel(channel) => (
+                GearBotString::ConfigIssueUnknownChannel,
+                FluArgs::with_capacity(1).add("channel", channel.to_string()),
+            ),
+            ConfigIssue::UnknownRole(role) => (
+                GearBotString::ConfigIssueUnknownRole,
+                FluArgs::with_capacity(1).add("role", role.to_string()),
+            ),
+            ConfigIssue::MissingLogPermissions { channel, missing } => (
+                GearBotString::ConfigIssueMissingLogPermissions,
+                FluArgs::with_capacity(2)
+                    .add("channel", channel.to_string())
+                    .add("permissions", format!("{:?}", missing)),
+            ),
+            ConfigIssue::InvalidNameFallback => (
+                GearBotString::ConfigIssueInvalidNameFallback,
+                FluArgs::with_capacity(1).add("max", MAX_NICKNAME_LENGTH),
+            ),
+            ConfigIssue::CensoredNameFallback => (
+                GearBotString::ConfigIssueCensoredNameFallback,
+                FluArgs::with_capacity(0),
+            ),
+        };
+
+        ctx.translate_with_args(lang, key, &args.generate())
+    }
+}
+
+impl GuildConfig {
+    /// Looks for everything that is wrong with this config when used in the given guild.
+    pub async fn validate(&self, ctx: &BotContext, guild: &CachedGuild) -> Vec<ConfigIssue> {
+        let mut issues = self.check_settings();
+
+        if !ctx.translations.supports(&self.language) {
+            issues.push(ConfigIssue::UnsupportedLanguage(self.language.clone()));
+        }
+
+        for role in self.referenced_roles() {
+            let issue = ConfigIssue::UnknownRole(role);
+            if guild.get_role(&role).await.is_none() && !issues.contains(&issue) {
+                issues.push(issue);
+            }
+        }
+
+        // What the bot needs in a log channel to be able to post there, in every log style
+        let log_permissions = Permissions::SEND_MESSAGES | Permissions::MANAGE_WEBHOOKS;
+        let channels: HashSet<ChannelId> = guild.channels.read().await.keys().copied().collect();
+        for channel in self.log_channels.keys() {
+            if !channels.contains(channel) {
+                issues.push(ConfigIssue::UnknownLogChannel(*channel));
+                continue;
+            }
+
+            let permissions = ctx.get_channel_permissions_for(ctx.bot_user.id, *channel).await;
+            if !permissions.contains(log_permissions) {
+                issues.push(ConfigIssue::MissingLogPermissions {
+                    channel: *channel,
+                    missing: log_permissions - permissions,
+                });
+            }
+        }
+
+        for channel in self.referenced_channels() {
+            let issue = ConfigIssue::UnknownChannel(channel);
+            if !self.log_channels.contains_key(&channel) && !channels.contains(&channel) && !issues.contains(&issue) {
+                issues.push(issue);
+            }
+        }
+
+        issues
+    }
+
+    /// The checks that don't need to know anything about the guild.
+    pub fn check_settings(&self) -> Vec<ConfigIssue> {
+        let mut issues = vec![];
+
+        if self.version != CONFIG_VERSION {
+            issues.push(ConfigIssue::WrongVersion);
+        }
+
+        if self.prefix.trim().is_empty() {
+            issues.push(ConfigIssue::EmptyPrefix);
+        } else if self.prefix.chars().count() > MAX_PREFIX_LENGTH {
+            issues.push(ConfigIssue::PrefixTooLong);
+        }
+
+        let mut priorities = vec![];
+        for group in &self.permission_groups {
+            let issue = ConfigIssue::DuplicatePriority(group.priority);
+            if priorities.contains(&group.priority) && !issues.contains(&issue) {
+                issues.push(issue);
+            }
+            priorities.push(group.priority);
+        }
+
+        // members would otherwise be renamed to something discord refuses, or keep getting renamed over and over
+        let fallback = &self.censoring.name_fallback;
+        if fallback.trim().is_empty() || fallback.chars().count() > MAX_NICKNAME_LENGTH {
+            issues.push(ConfigIssue::InvalidNameFallback);
+        } else if utils::is_hoisted(fallback)
+            || self
+                .censoring
+                .compile_filters()
+                .find(&utils::normalize_lookalikes(fallback).0)
+                .is_some()
+        {
+            issues.push(ConfigIssue::CensoredNameFallback);
+        }
+
+        issues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_are_checked() {
+        let mut config = GuildConfig::default();
+        assert!(config.check_settings().is_empty());
+
+        config.prefix = String::from("  ");
+        let mut group = config.permission_groups[0].clone();
+        config.permission_groups.push(group.clone());
+        group.priority = 1;
+        config.permission_groups.push(group);
+        assert_eq!(
+            config.check_settings(),
+            vec![
+                ConfigIssue::EmptyPrefix,
+                ConfigIssue::DuplicatePriority(config.permission_groups[0].priority)
+            ]
+        );
+
+        config.prefix = "!".repeat(MAX_PREFIX_LENGTH + 1);
+        assert_eq!(config.check_settings()[0], ConfigIssue::PrefixTooLong);
+    }
+
+    #[test]
+    fn name_fallback_is_checked() {
+        let mut config = GuildConfig::default();
+        config.censoring.name_fallback = String::from(" ");
+        assert_eq!(config.check_settings(), vec![ConfigIssue::InvalidNameFallback]);
+
+        config.censoring.name_fallback = String::from("!Unnamed");
+        assert_eq!(config.check_settings(), vec![ConfigIssue::CensoredNameFallback]);
+
+        config.censoring.name_fallback = String::from("Nameless");
+        config.censoring.word_list = vec![String::from("nameless")];
+        assert_eq!(config.check_settings(), vec![ConfigIssue::CensoredNameFallback]);
+    }
+}
@@ -0,0 +1,57 @@
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use twilight_model::id::{ChannelId, UserId};
+
+/// How many custom commands a single guild can have.
+pub const MAX_CUSTOM_COMMANDS: usize = 50;
+/// The longest trigger the database can store.
+pub const MAX_TRIGGER_LENGTH: usize = 30;
+/// The longest response the database can store, which is also all that fits in a message.
+pub const MAX_RESPONSE_LENGTH: usize = 2000;
+
+lazy_static! {
+    static ref PLACEHOLDER_MATCHER: Regex = Regex::new(r"\{(author|channel|args|[1-9])\}").unwrap();
+}
+
+/// Fills in the placeholders of a custom command response:
+/// - `{author}`: a mention of whoever used the command
+/// - `{channel}`: a mention of the channel it was used in
+/// - `{args}`: everything that came after the trigger
+/// - `{1}` through `{9}`: a single one of those arguments, nothing if there aren't that many
+pub fn fill_placeholders(response: &str, author: UserId, channel: ChannelId, arguments: &[String]) -> String {
+    let filled = PLACEHOLDER_MATCHER.replace_all(response, |captures: &Captures| match &captures[1] {
+        "author" => format!("<@{}>", author),
+        "channel" => format!("<#{}>", channel),
+        "args" => arguments.join(" "),
+        index => {
+            let index: usize = index.parse().unwrap();
+            arguments.get(index - 1).cloned().unwrap_or_default()
+        }
+    });
+
+    // the arguments can push it past what fits in a message
+    filled.chars().take(MAX_RESPONSE_LENGTH).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_filled() {
+        let arguments = vec![String::from("first"), String::from("second")];
+        let filled = fill_placeholders(
+            "{author} in {channel}: {args} ({2}, {1}, {3}) {unknown}",
+            UserId(1),
+            ChannelId(2),
+            &arguments,
+        );
+        assert_eq!(filled, "<@1> in <#2>: first second (second, first, ) {unknown}");
+
+        let long = vec!["a".repeat(MAX_RESPONSE_LENGTH)];
+        assert_eq!(
+            fill_placeholders("{args}!", UserId(1), ChannelId(2), &long).len(),
+            MAX_RESPONSE_LENGTH
+        );
+    }
+}
@@ -1,24 +1,148 @@
+use regex::{Match, Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
 use twilight_model::guild::Permissions;
-use twilight_model::id::{ChannelId, RoleId, UserId};
+use twilight_model::id::{ChannelId, GuildId, RoleId, UserId};
 use unic_langid::LanguageIdentifier;
 
 use crate::commands::meta::nodes::GearBotPermissions;
+use crate::core::config_migrations::CONFIG_VERSION;
 use crate::core::logpump::{DataLessLogType, LogFilter};
 use crate::translation::DEFAULT_LANG;
+use crate::utils;
+use crate::utils::matchers;
 use std::collections::HashMap;
+use std::fmt;
 
-#[derive(Deserialize, Serialize, Debug)]
+/// How long messages are stored for guilds that didn't pick a retention period themselves.
+pub const DEFAULT_MESSAGE_RETENTION_DAYS: u32 = 30;
+/// Upper bound on how much memory a single censor regex can take, so a hostile pattern can't stall everything while
+/// it compiles. Matching itself is always linear in the size of the message.
+const CENSOR_REGEX_SIZE_LIMIT: usize = 1 << 18;
+/// What members get renamed to when their name is censored and the guild didn't pick something else.
+pub const DEFAULT_NAME_FALLBACK: &str = "Unnamed";
+
+/// When adding anything to this, add a migration for it as well so stored configs keep loading.
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GuildConfig {
+    /// The format the config is stored in, see `config_migrations`.
+    pub version: u32,
     pub prefix: String,
     pub log_style: LogStyle,
     pub message_logs: MessageLogs,
     pub language: LanguageIdentifier,
     pub permission_groups: Vec<PermissionGroup>,
     pub log_channels: HashMap<ChannelId, LogChannelConfig>,
+    pub mute: MuteConfig,
+    pub censoring: CensorConfig,
+    pub spam: SpamConfig,
+    pub raids: RaidConfig,
+}
+
+impl GuildConfig {
+    /// Every role this config refers to, so they can be checked against the guild.
+    pub fn referenced_roles(&self) -> Vec<RoleId> {
+        let mut roles: Vec<RoleId> = self
+            .permission_groups
+            .iter()
+            .flat_map(|group| group.roles.iter().copied())
+            .chain(self.mute.role)
+            .chain(self.censoring.exempt_roles.iter().copied())
+            .chain(self.spam.exempt_roles.iter().copied())
+            .collect();
+        if let RaidAction::Quarantine(role) = self.raids.action {
+            roles.push(role);
+        }
+
+        roles
+    }
+
+    /// Every channel (or category) this config refers to, so they can be checked against the guild.
+    pub fn referenced_channels(&self) -> Vec<ChannelId> {
+        self.log_channels
+            .keys()
+            .copied()
+            .chain(
+                self.log_channels
+                    .values()
+                    .flat_map(|log_channel| log_channel.filters.iter())
+                    .flat_map(|filter| filter.source_channels().iter().copied()),
+            )
+            .chain(self.message_logs.ignored_channels.iter().map(|id| ChannelId(*id)))
+            .chain(self.censoring.exempt_channels.iter().copied())
+            .chain(self.censoring.channel_filters.keys().copied())
+            .chain(self.spam.exempt_channels.iter().copied())
+            .collect()
+    }
+
+    /// Replaces every role and channel this config refers to, the ones that get `None` are left out entirely. Covers
+    /// the same places as `referenced_roles` and `referenced_channels`.
+    ///
+    /// Permission groups that can't be carried over as they were are removed, and their names returned. A group that
+    /// needs all of its roles would apply to more members with any of them gone, and one that lost every role no longer
+    /// applies to who it was meant for.
+    pub fn remap_ids<R, C>(&mut self, mut role: R, mut channel: C) -> Vec<String>
+    where
+        R: FnMut(RoleId) -> Option<RoleId>,
+        C: FnMut(ChannelId) -> Option<ChannelId>,
+    {
+        let mut dropped_groups = vec![];
+        for mut group in std::mem::take(&mut self.permission_groups) {
+            let before = group.roles.len();
+            group.roles = group.roles.drain(..).filter_map(&mut role).collect();
+            let widened = if group.needs_all {
+                group.roles.len() < before
+            } else {
+                group.roles.is_empty() && before > 0
+            };
+
+            if widened {
+                dropped_groups.push(group.name);
+            } else {
+                self.permission_groups.push(group);
+            }
+        }
+        self.mute.role = self.mute.role.and_then(&mut role);
+        self.censoring.exempt_roles = self.censoring.exempt_roles.drain(..).filter_map(&mut role).collect();
+        self.spam.exempt_roles = self.spam.exempt_roles.drain(..).filter_map(&mut role).collect();
+        if let RaidAction::Quarantine(quarantine_role) = self.raids.action {
+            self.raids.action = role(quarantine_role).map_or(RaidAction::Nothing, RaidAction::Quarantine);
+        }
+
+        self.log_channels = self
+            .log_channels
+            .drain()
+            .filter_map(|(id, log_channel)| channel(id).map(|id| (id, log_channel)))
+            .collect();
+        for log_channel in self.log_channels.values_mut() {
+            for filter in &mut log_channel.filters {
+                filter.remap_channels(&mut channel);
+            }
+        }
+        self.message_logs.ignored_channels = self
+            .message_logs
+            .ignored_channels
+            .drain(..)
+            .filter_map(|id| channel(ChannelId(id)).map(|id| id.0))
+            .collect();
+        self.censoring.exempt_channels = self
+            .censoring
+            .exempt_channels
+            .drain(..)
+            .filter_map(&mut channel)
+            .collect();
+        self.censoring.channel_filters = self
+            .censoring
+            .channel_filters
+            .drain()
+            .filter_map(|(id, filter)| channel(id).map(|id| (id, filter)))
+            .collect();
+        self.spam.exempt_channels = self.spam.exempt_channels.drain(..).filter_map(&mut channel).collect();
+
+        dropped_groups
+    }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct PermissionGroup {
     pub priority: u8,
     pub name: String,
@@ -30,12 +154,262 @@ pub struct PermissionGroup {
     pub users: Vec<UserId>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct MessageLogs {
     pub enabled: bool,
     pub ignored_users: Vec<u64>,
     pub ignored_channels: Vec<u64>,
     pub ignore_bots: bool,
+    /// How many days stored messages are kept around before they get pruned.
+    pub retention_days: u32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct MuteConfig {
+    pub role: Option<RoleId>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CensorConfig {
+    pub censor_invites: bool,
+    /// Guilds that invites are allowed to point to, invites to the guild itself are always allowed.
+    pub invite_whitelist: Vec<GuildId>,
+    /// Words that are censored when they show up as a whole word, regardless of case, lookalikes or zalgo.
+    pub word_list: Vec<String>,
+    /// Patterns that are censored when they match anywhere in the (normalized) message.
+    pub regex_list: Vec<String>,
+    /// Adds a censor infraction to the history of the author for every censored message.
+    pub add_infraction: bool,
+    /// Also runs the word and regex lists over the names of members.
+    pub censor_names: bool,
+    /// Renames members whose name starts with characters that are only there to sort them to the top of the list.
+    pub dehoist: bool,
+    /// The nickname members get when their name is censored or dehoisted.
+    pub name_fallback: String,
+    /// Attachment and link rules for specific channels or whole categories, a channel's own rules replace the ones of
+    /// its category.
+    pub channel_filters: HashMap<ChannelId, ChannelFilter>,
+    pub exempt_roles: Vec<RoleId>,
+    pub exempt_channels: Vec<ChannelId>,
+}
+
+/// Restricts which files and links can be posted. Empty allow lists allow everything that isn't blocked.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ChannelFilter {
+    pub allowed_extensions: Vec<String>,
+    pub blocked_extensions: Vec<String>,
+    /// Subdomains are covered by their parent domain.
+    pub allowed_domains: Vec<String>,
+    pub blocked_domains: Vec<String>,
+}
+
+impl ChannelFilter {
+    pub fn allows_file(&self, filename: &str) -> bool {
+        let extension = matchers::file_extension(filename);
+        let listed = |list: &[String]| {
+            list.iter()
+                .any(|listed| listed.trim_start_matches('.').eq_ignore_ascii_case(&extension))
+        };
+
+        !listed(&self.blocked_extensions) && (self.allowed_extensions.is_empty() || listed(&self.allowed_extensions))
+    }
+
+    pub fn allows_domain(&self, domain: &str) -> bool {
+        let listed = |list: &[String]| list.iter().any(|listed| matchers::is_on_domain(domain, listed));
+
+        !listed(&self.blocked_domains) && (self.allowed_domains.is_empty() || listed(&self.allowed_domains))
+    }
+}
+
+impl Default for CensorConfig {
+    fn default() -> Self {
+        CensorConfig {
+            censor_invites: false,
+            invite_whitelist: vec![],
+            word_list: vec![],
+            regex_list: vec![],
+            add_infraction: false,
+            censor_names: false,
+            dehoist: false,
+            name_fallback: DEFAULT_NAME_FALLBACK.to_string(),
+            channel_filters: HashMap::new(),
+            exempt_roles: vec![],
+            exempt_channels: vec![],
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct SpamConfig {
+    pub enabled: bool,
+    pub rules: Vec<SpamRule>,
+    pub exempt_roles: Vec<RoleId>,
+    pub exempt_channels: Vec<ChannelId>,
+}
+
+/// Punishes anyone who manages to fill the bucket up to `count` within `period` seconds.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct SpamRule {
+    pub bucket: SpamBucket,
+    pub count: u32,
+    pub period: u32,
+    pub punishment: Punishment,
+}
+
+/// What a spam bucket counts for every message.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpamBucket {
+    Messages,
+    /// Messages that have the exact same content as another one in the bucket.
+    DuplicateMessages,
+    Mentions,
+    Emoji,
+    Newlines,
+    Links,
+    Attachments,
+}
+
+impl fmt::Display for SpamBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SpamBucket::Messages => "messages",
+            SpamBucket::DuplicateMessages => "duplicate messages",
+            SpamBucket::Mentions => "mentions",
+            SpamBucket::Emoji => "emoji",
+            SpamBucket::Newlines => "newlines",
+            SpamBucket::Links => "links",
+            SpamBucket::Attachments => "attachments",
+        };
+        f.write_str(name)
+    }
+}
+
+/// What happens to members that trip one of the automatic moderation rules.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punishment {
+    Warn,
+    /// Mutes for the given amount of seconds, using the mute role.
+    Mute(u32),
+    Kick,
+    Ban,
+    /// Only removes the offending messages.
+    Delete,
+}
+
+impl fmt::Display for Punishment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Punishment::Warn => f.write_str("warn"),
+            Punishment::Mute(seconds) => write!(f, "mute ({} seconds)", seconds),
+            Punishment::Kick => f.write_str("kick"),
+            Punishment::Ban => f.write_str("ban"),
+            Punishment::Delete => f.write_str("delete"),
+        }
+    }
+}
+
+/// A raid starts when `threshold` members join within `period` seconds.
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct RaidConfig {
+    pub enabled: bool,
+    pub threshold: u32,
+    pub period: u32,
+    /// When set, only accounts that are younger than this many days count towards the threshold.
+    pub max_account_age_days: Option<u32>,
+    /// Raises the verification level of the guild to the highest one for as long as the raid lasts.
+    pub raise_verification: bool,
+    pub action: RaidAction,
+}
+
+/// What happens to everyone that joins while a raid is going on.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaidAction {
+    Nothing,
+    Quarantine(RoleId),
+    Kick,
+}
+
+impl Default for RaidAction {
+    fn default() -> Self {
+        RaidAction::Nothing
+    }
+}
+
+/// The word and regex lists of a guild, ready to be matched against messages.
+pub struct CensorFilters {
+    pub words: Option<Regex>,
+    pub regexes: Vec<Regex>,
+}
+
+impl CensorConfig {
+    /// Compiles the word and regex lists. Patterns that are invalid or too big are left out.
+    ///
+    /// This is expensive compared to the matching itself, so it should only be done once for every version of a config.
+    pub fn compile_filters(&self) -> CensorFilters {
+        let words = self
+            .word_list
+            .iter()
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let normalized = utils::normalize_lookalikes(word).0;
+                // Only anchor to word boundaries on the sides that are actually part of a word
+                let boundary = |c: Option<char>| match c {
+                    Some(c) if c.is_alphanumeric() || c == '_' => "\\b",
+                    _ => "",
+                };
+                format!(
+                    "{}{}{}",
+                    boundary(normalized.chars().next()),
+                    regex::escape(&normalized),
+                    boundary(normalized.chars().last())
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let words = if words.is_empty() {
+            None
+        } else {
+            compile_censor_regex(&words.join("|"))
+        };
+
+        let regexes = self
+            .regex_list
+            .iter()
+            .filter_map(|pattern| compile_censor_regex(pattern))
+            .collect();
+
+        CensorFilters { words, regexes }
+    }
+}
+
+impl CensorFilters {
+    pub fn is_empty(&self) -> bool {
+        self.words.is_none() && self.regexes.is_empty()
+    }
+
+    /// Finds the first censored part of an already normalized text.
+    pub fn find<'t>(&self, text: &'t str) -> Option<Match<'t>> {
+        // A pattern that matches nothing at all would otherwise censor everything
+        self.words
+            .iter()
+            .chain(&self.regexes)
+            .find_map(|regex| regex.find_iter(text).find(|found| !found.as_str().is_empty()))
+    }
+}
+
+fn compile_censor_regex(pattern: &str) -> Option<Regex> {
+    match RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(CENSOR_REGEX_SIZE_LIMIT)
+        .dfa_size_limit(CENSOR_REGEX_SIZE_LIMIT)
+        .build()
+    {
+        Ok(regex) => Some(regex),
+        Err(e) => {
+            log::debug!("Skipping censor pattern {:?}: {}", pattern, e);
+            None
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq)]
@@ -44,12 +418,20 @@ pub enum LogStyle {
     Embed,
 }
 
-#[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
 pub enum LogCategory {
     GENERAL,
+    MODERATION,
+    JOIN_LEAVE,
+    NAME_CHANGES,
+    ROLE_CHANGES,
+    MESSAGE_LOGS,
+    CENSORED_MESSAGES,
+    SPAM,
+    RAIDS,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct LogChannelConfig {
     pub categories: Vec<LogCategory>,
     pub disabled_keys: Vec<DataLessLogType>,
@@ -61,6 +443,7 @@ pub struct LogChannelConfig {
 impl Default for GuildConfig {
     fn default() -> Self {
         GuildConfig {
+            version: CONFIG_VERSION,
             prefix: "!".to_string(),
             log_style: LogStyle::Text,
             message_logs: MessageLogs {
@@ -68,13 +451,16 @@ impl Default for GuildConfig {
                 ignored_users: vec![],
                 ignored_channels: vec![],
                 ignore_bots: true,
+                retention_days: DEFAULT_MESSAGE_RETENTION_DAYS,
             },
             language: DEFAULT_LANG,
             permission_groups: vec![
                 PermissionGroup {
                     priority: 0,
                     name: String::from("All members"),
-                    granted_perms: GearBotPermissions::BASIC_GROUP | GearBotPermissions::EMOJI_LIST_COMMAND,
+                    granted_perms: GearBotPermissions::BASIC_GROUP
+                        | GearBotPermissions::EMOJI_LIST_COMMAND
+                        | GearBotPermissions::CUSTOM_COMMANDS_LIST,
                     denied_perms: GearBotPermissions::empty(),
                     discord_perms: Some(Permissions::empty()),
                     roles: vec![],
@@ -86,6 +472,7 @@ impl Default for GuildConfig {
                     name: String::from("Moderators"),
                     granted_perms: GearBotPermissions::BASIC_GROUP
                         | GearBotPermissions::EMOJI_LIST_COMMAND
+                        | GearBotPermissions::CUSTOM_COMMANDS_LIST
                         | GearBotPermissions::MODERATION_GROUP
                         | GearBotPermissions::READ_CONFIG,
                     denied_perms: GearBotPermissions::empty(),
@@ -109,6 +496,10 @@ impl Default for GuildConfig {
                 },
             ],
             log_channels: HashMap::new(),
+            mute: MuteConfig::default(),
+            censoring: CensorConfig::default(),
+            spam: SpamConfig::default(),
+            raids: RaidConfig::default(),
         }
     }
 }
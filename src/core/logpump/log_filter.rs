@@ -2,7 +2,7 @@ use super::DataLessLogType;
 use serde::{Deserialize, Serialize};
 use twilight_model::id::{ChannelId, UserId};
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct LogFilter {
     log_types: Vec<DataLessLogType>,
     source_channels: Vec<ChannelId>,
@@ -10,6 +10,18 @@ pub struct LogFilter {
 }
 
 impl LogFilter {
+    pub fn source_channels(&self) -> &[ChannelId] {
+        &self.source_channels
+    }
+
+    /// Swaps out the source channels, dropping the ones `remap` has no replacement for.
+    pub fn remap_channels<F>(&mut self, remap: F)
+    where
+        F: FnMut(ChannelId) -> Option<ChannelId>,
+    {
+        self.source_channels = self.source_channels.drain(..).filter_map(remap).collect();
+    }
+
     pub fn matches(
         &self,
         log_type: &DataLessLogType,
@@ -1,30 +1,221 @@
 use crate::cache::CachedUser;
-use crate::core::guild_config::{LogCategory, LogStyle};
+use crate::core::guild_config::{LogCategory, LogStyle, Punishment, SpamBucket};
 use crate::core::BotContext;
+use crate::database::structures::ConfigSource;
 use crate::error::MessageError;
 use crate::translation::{FluArgs, GearBotString};
-use crate::utils::Emoji;
+use crate::utils::{self, Emoji};
+use chrono::Utc;
+use fluent_bundle::FluentArgs;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use std::sync::Arc;
 use twilight_embed_builder::{EmbedAuthorBuilder, EmbedBuilder, EmbedFooterBuilder, ImageSource};
 use twilight_model::channel::embed::Embed;
-use twilight_model::id::ChannelId;
+use twilight_model::id::{ChannelId, UserId};
 use unic_langid::LanguageIdentifier;
 
+/// How much of a message fits in a text log, leaving room for both versions of an edit.
+const TEXT_CONTENT_LENGTH: usize = 800;
+/// How much of a message fits in an embed log, leaving room for both versions of an edit.
+const EMBED_CONTENT_LENGTH: usize = 900;
+
 #[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
 pub enum LogType {
-    CommandUsed { command: String },
+    CommandUsed {
+        command: String,
+    },
+    Warned(ModerationAction),
+    Kicked(ModerationAction),
+    Banned(ModerationAction),
+    Tempbanned(ModerationAction),
+    ForceBanned(ModerationAction),
+    Unbanned(ModerationAction),
+    Muted(ModerationAction),
+    Unmuted {
+        moderator: String,
+        moderator_id: UserId,
+        reason: String,
+    },
+    TempbanExpired {
+        infraction_id: i32,
+    },
+    MuteExpired {
+        infraction_id: i32,
+    },
+    TempbanLiftFailed {
+        infraction_id: i32,
+    },
+    MuteLiftFailed {
+        infraction_id: i32,
+    },
+    MemberJoin,
+    MemberLeave,
+    NicknameChanged {
+        before: Option<String>,
+        after: Option<String>,
+    },
+    UsernameChanged {
+        before: String,
+        after: String,
+    },
+    RolesAdded {
+        roles: Vec<String>,
+    },
+    RolesRemoved {
+        roles: Vec<String>,
+    },
+    MessageEdited {
+        before: String,
+        after: String,
+        attachments: Vec<String>,
+    },
+    MessageDeleted {
+        content: String,
+        attachments: Vec<String>,
+    },
+    BulkDelete {
+        count: usize,
+        part: usize,
+        parts: usize,
+        archive: LogFile,
+    },
+    InviteCensored {
+        code: String,
+        guild_name: String,
+        content: String,
+    },
+    /// The term is the matched text after normalizing, `start` and `end` point out where it is in the content.
+    WordCensored {
+        term: String,
+        content: String,
+        start: usize,
+        end: usize,
+    },
+    AttachmentCensored {
+        filename: String,
+        content: String,
+    },
+    LinkCensored {
+        domain: String,
+        content: String,
+    },
+    NameCensored {
+        before: String,
+        after: String,
+        reason: String,
+    },
+    SpamDetected {
+        bucket: SpamBucket,
+        amount: u32,
+        period: u32,
+        punishment: Punishment,
+    },
+    RaidStarted {
+        joins: u32,
+        period: u32,
+    },
+    RaidEnded {
+        caught: u32,
+        moderator: String,
+        moderator_id: UserId,
+    },
+    /// The revision is the one the old config was kept under, so it can be rolled back to.
+    ConfigChanged {
+        revision: i32,
+        source: ConfigSource,
+        diff: String,
+    },
+}
+
+/// A file that gets uploaded together with a log, it will always be send in a message of its own.
+#[derive(Deserialize, Serialize, Eq, PartialEq)]
+pub struct LogFile {
+    pub name: String,
+    pub content: String,
+}
+
+// These can get big, and hold decrypted message content, keep them out of debug logging.
+impl fmt::Debug for LogFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LogFile {{ name: {}, size: {} }}", self.name, self.content.len())
+    }
 }
 
+/// The details shared by all logs of moderators acting on a user, the user itself is the source user of the log.
 #[derive(Deserialize, Serialize, Debug, Eq, PartialEq)]
+pub struct ModerationAction {
+    pub moderator: String,
+    pub moderator_id: UserId,
+    pub reason: String,
+    pub infraction_id: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Eq, PartialEq)]
 pub enum DataLessLogType {
     CommandUsed,
+    Warned,
+    Kicked,
+    Banned,
+    Tempbanned,
+    ForceBanned,
+    Unbanned,
+    Muted,
+    Unmuted,
+    TempbanExpired,
+    MuteExpired,
+    TempbanLiftFailed,
+    MuteLiftFailed,
+    MemberJoin,
+    MemberLeave,
+    NicknameChanged,
+    UsernameChanged,
+    RolesAdded,
+    RolesRemoved,
+    MessageEdited,
+    MessageDeleted,
+    BulkDelete,
+    InviteCensored,
+    WordCensored,
+    AttachmentCensored,
+    LinkCensored,
+    NameCensored,
+    SpamDetected,
+    RaidStarted,
+    RaidEnded,
+    ConfigChanged,
 }
 
 impl LogType {
     pub fn get_category(&self) -> LogCategory {
         match self {
-            LogType::CommandUsed { .. } => LogCategory::GENERAL,
+            LogType::CommandUsed { .. } | LogType::ConfigChanged { .. } => LogCategory::GENERAL,
+            LogType::Warned(_)
+            | LogType::Kicked(_)
+            | LogType::Banned(_)
+            | LogType::Tempbanned(_)
+            | LogType::ForceBanned(_)
+            | LogType::Unbanned(_)
+            | LogType::Muted(_)
+            | LogType::Unmuted { .. }
+            | LogType::TempbanExpired { .. }
+            | LogType::MuteExpired { .. }
+            | LogType::TempbanLiftFailed { .. }
+            | LogType::MuteLiftFailed { .. } => LogCategory::MODERATION,
+            LogType::MemberJoin | LogType::MemberLeave => LogCategory::JOIN_LEAVE,
+            LogType::NicknameChanged { .. } | LogType::UsernameChanged { .. } | LogType::NameCensored { .. } => {
+                LogCategory::NAME_CHANGES
+            }
+            LogType::RolesAdded { .. } | LogType::RolesRemoved { .. } => LogCategory::ROLE_CHANGES,
+            LogType::MessageEdited { .. } | LogType::MessageDeleted { .. } | LogType::BulkDelete { .. } => {
+                LogCategory::MESSAGE_LOGS
+            }
+            LogType::InviteCensored { .. }
+            | LogType::WordCensored { .. }
+            | LogType::AttachmentCensored { .. }
+            | LogType::LinkCensored { .. } => LogCategory::CENSORED_MESSAGES,
+            LogType::SpamDetected { .. } => LogCategory::SPAM,
+            LogType::RaidStarted { .. } | LogType::RaidEnded { .. } => LogCategory::RAIDS,
         }
     }
 
@@ -61,6 +252,364 @@ impl LogType {
                             .icon_url(ImageSource::url(self.emoji().url())?),
                     )
             }
+            LogType::Warned(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::WarnedEmbed,
+                GearBotString::WarnedFooter,
+            )?,
+            LogType::Kicked(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::KickedEmbed,
+                GearBotString::KickedFooter,
+            )?,
+            LogType::Banned(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::BannedEmbed,
+                GearBotString::BannedFooter,
+            )?,
+            LogType::Tempbanned(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::TempbannedEmbed,
+                GearBotString::TempbannedFooter,
+            )?,
+            LogType::ForceBanned(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::ForceBannedEmbed,
+                GearBotString::ForceBannedFooter,
+            )?,
+            LogType::Unbanned(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::UnbannedEmbed,
+                GearBotString::UnbannedFooter,
+            )?,
+            LogType::Muted(action) => action.to_embed(
+                ctx,
+                lang,
+                user,
+                self.emoji(),
+                GearBotString::MutedEmbed,
+                GearBotString::MutedFooter,
+            )?,
+            LogType::Unmuted {
+                moderator,
+                moderator_id,
+                reason,
+            } => EmbedBuilder::new()
+                .description(
+                    ctx.translate_with_args(
+                        lang,
+                        GearBotString::UnmutedEmbed,
+                        &FluArgs::with_capacity(3)
+                            .add("moderator", moderator.to_string())
+                            .add("moderator_id", moderator_id.to_string())
+                            .add("reason", or_dash(reason))
+                            .generate(),
+                    ),
+                )?
+                .author(
+                    EmbedAuthorBuilder::new()
+                        .name(user.full_name_with_id())?
+                        .url(user.profile_link()),
+                )
+                .thumbnail(ImageSource::url(user.avatar_url())?)
+                .footer(
+                    EmbedFooterBuilder::new(ctx.translate(lang, GearBotString::UnmutedFooter))?
+                        .icon_url(ImageSource::url(self.emoji().url())?),
+                ),
+            LogType::TempbanExpired { infraction_id } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::TempbanExpiredEmbed,
+                &FluArgs::with_capacity(1).add("id", *infraction_id).generate(),
+                GearBotString::TempbanExpiredFooter,
+            )?,
+            LogType::MuteExpired { infraction_id } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MuteExpiredEmbed,
+                &FluArgs::with_capacity(1).add("id", *infraction_id).generate(),
+                GearBotString::MuteExpiredFooter,
+            )?,
+            LogType::TempbanLiftFailed { infraction_id } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::TempbanLiftFailedEmbed,
+                &FluArgs::with_capacity(1).add("id", *infraction_id).generate(),
+                GearBotString::TempbanLiftFailedFooter,
+            )?,
+            LogType::MuteLiftFailed { infraction_id } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MuteLiftFailedEmbed,
+                &FluArgs::with_capacity(1).add("id", *infraction_id).generate(),
+                GearBotString::MuteLiftFailedFooter,
+            )?,
+            LogType::MemberJoin => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MemberJoinEmbed,
+                &FluArgs::with_capacity(1).add("age", account_age(user)).generate(),
+                GearBotString::MemberJoinFooter,
+            )?,
+            LogType::MemberLeave => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MemberLeaveEmbed,
+                &FluArgs::with_capacity(0).generate(),
+                GearBotString::MemberLeaveFooter,
+            )?,
+            LogType::NicknameChanged { before, after } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::NicknameChangedEmbed,
+                &FluArgs::with_capacity(2)
+                    .add("before", or_dash(before.as_deref().unwrap_or_default()))
+                    .add("after", or_dash(after.as_deref().unwrap_or_default()))
+                    .generate(),
+                GearBotString::NicknameChangedFooter,
+            )?,
+            LogType::UsernameChanged { before, after } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::UsernameChangedEmbed,
+                &FluArgs::with_capacity(2)
+                    .add("before", or_dash(before))
+                    .add("after", or_dash(after))
+                    .generate(),
+                GearBotString::UsernameChangedFooter,
+            )?,
+            LogType::RolesAdded { roles } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::RolesAddedEmbed,
+                &FluArgs::with_capacity(1).add("roles", roles.join(", ")).generate(),
+                GearBotString::RolesAddedFooter,
+            )?,
+            LogType::RolesRemoved { roles } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::RolesRemovedEmbed,
+                &FluArgs::with_capacity(1).add("roles", roles.join(", ")).generate(),
+                GearBotString::RolesRemovedFooter,
+            )?,
+            LogType::MessageEdited {
+                before,
+                after,
+                attachments,
+            } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MessageEditedEmbed,
+                &FluArgs::with_capacity(4)
+                    .add("channel_id", channel.unwrap().to_string()) // message logs always come from a channel
+                    .add("before", or_dash(&shorten(before, EMBED_CONTENT_LENGTH)))
+                    .add("after", or_dash(&shorten(after, EMBED_CONTENT_LENGTH)))
+                    .add("attachments", or_dash(&attachments.join(", ")))
+                    .generate(),
+                GearBotString::MessageEditedFooter,
+            )?,
+            LogType::MessageDeleted { content, attachments } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::MessageDeletedEmbed,
+                &FluArgs::with_capacity(3)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("content", or_dash(&shorten(content, EMBED_CONTENT_LENGTH)))
+                    .add("attachments", or_dash(&attachments.join(", ")))
+                    .generate(),
+                GearBotString::MessageDeletedFooter,
+            )?,
+            LogType::BulkDelete { count, part, parts, .. } => EmbedBuilder::new()
+                .description(
+                    ctx.translate_with_args(
+                        lang,
+                        GearBotString::BulkDeleteEmbed,
+                        &FluArgs::with_capacity(4)
+                            .add("channel_id", channel.unwrap().to_string())
+                            .add("count", *count)
+                            .add("part", *part)
+                            .add("parts", *parts)
+                            .generate(),
+                    ),
+                )?
+                .footer(
+                    EmbedFooterBuilder::new(ctx.translate(lang, GearBotString::BulkDeleteFooter))?
+                        .icon_url(ImageSource::url(self.emoji().url())?),
+                ),
+            LogType::InviteCensored {
+                code,
+                guild_name,
+                content,
+            } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::InviteCensoredEmbed,
+                &FluArgs::with_capacity(4)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("code", code.to_string())
+                    .add("guild", guild_name.to_string())
+                    .add("content", or_dash(&shorten(content, EMBED_CONTENT_LENGTH)))
+                    .generate(),
+                GearBotString::InviteCensoredFooter,
+            )?,
+            LogType::WordCensored {
+                term,
+                content,
+                start,
+                end,
+            } => {
+                let highlighted = format!(
+                    "{}**{}**{}",
+                    &content[..*start],
+                    &content[*start..*end],
+                    &content[*end..]
+                );
+                self.user_embed(
+                    ctx,
+                    lang,
+                    user,
+                    GearBotString::WordCensoredEmbed,
+                    &FluArgs::with_capacity(3)
+                        .add("channel_id", channel.unwrap().to_string())
+                        .add("term", or_dash(term))
+                        .add("content", or_dash(&shorten(&highlighted, EMBED_CONTENT_LENGTH)))
+                        .generate(),
+                    GearBotString::WordCensoredFooter,
+                )?
+            }
+            LogType::AttachmentCensored { filename, content } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::AttachmentCensoredEmbed,
+                &FluArgs::with_capacity(3)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("filename", or_dash(filename))
+                    .add("content", or_dash(&shorten(content, EMBED_CONTENT_LENGTH)))
+                    .generate(),
+                GearBotString::AttachmentCensoredFooter,
+            )?,
+            LogType::LinkCensored { domain, content } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::LinkCensoredEmbed,
+                &FluArgs::with_capacity(3)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("domain", or_dash(domain))
+                    .add("content", or_dash(&shorten(content, EMBED_CONTENT_LENGTH)))
+                    .generate(),
+                GearBotString::LinkCensoredFooter,
+            )?,
+            LogType::NameCensored { before, after, reason } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::NameCensoredEmbed,
+                &FluArgs::with_capacity(3)
+                    .add("before", or_dash(before))
+                    .add("after", or_dash(after))
+                    .add("reason", reason.to_string())
+                    .generate(),
+                GearBotString::NameCensoredFooter,
+            )?,
+            LogType::SpamDetected {
+                bucket,
+                amount,
+                period,
+                punishment,
+            } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::SpamDetectedEmbed,
+                &FluArgs::with_capacity(5)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("bucket", bucket.to_string())
+                    .add("amount", *amount)
+                    .add("period", *period)
+                    .add("punishment", punishment.to_string())
+                    .generate(),
+                GearBotString::SpamDetectedFooter,
+            )?,
+            LogType::RaidStarted { joins, period } => EmbedBuilder::new()
+                .description(
+                    ctx.translate_with_args(
+                        lang,
+                        GearBotString::RaidStartedEmbed,
+                        &FluArgs::with_capacity(2)
+                            .add("joins", *joins)
+                            .add("period", *period)
+                            .generate(),
+                    ),
+                )?
+                .footer(
+                    EmbedFooterBuilder::new(ctx.translate(lang, GearBotString::RaidStartedFooter))?
+                        .icon_url(ImageSource::url(self.emoji().url())?),
+                ),
+            LogType::RaidEnded {
+                caught,
+                moderator,
+                moderator_id,
+            } => EmbedBuilder::new()
+                .description(
+                    ctx.translate_with_args(
+                        lang,
+                        GearBotString::RaidEndedEmbed,
+                        &FluArgs::with_capacity(3)
+                            .add("caught", *caught)
+                            .add("moderator", moderator.to_string())
+                            .add("moderator_id", moderator_id.to_string())
+                            .generate(),
+                    ),
+                )?
+                .footer(
+                    EmbedFooterBuilder::new(ctx.translate(lang, GearBotString::RaidEndedFooter))?
+                        .icon_url(ImageSource::url(self.emoji().url())?),
+                ),
+            LogType::ConfigChanged { revision, source, diff } => self.user_embed(
+                ctx,
+                lang,
+                user,
+                GearBotString::ConfigChangedEmbed,
+                &FluArgs::with_capacity(3)
+                    .add("revision", *revision)
+                    .add("source", source.get_name())
+                    .add("diff", diff.replace("`", "ˋ"))
+                    .generate(),
+                GearBotString::ConfigChangedFooter,
+            )?,
         }
         .timestamp(chrono::Utc::now().format("%+").to_string())
         .build()?)
@@ -83,20 +632,355 @@ impl LogType {
 
                 ctx.translate_with_args(lang, GearBotString::CommandUsedText, &args.generate())
             }
+            LogType::Warned(action) => action.to_text(ctx, lang, user, GearBotString::WarnedText),
+            LogType::Kicked(action) => action.to_text(ctx, lang, user, GearBotString::KickedText),
+            LogType::Banned(action) => action.to_text(ctx, lang, user, GearBotString::BannedText),
+            LogType::Tempbanned(action) => action.to_text(ctx, lang, user, GearBotString::TempbannedText),
+            LogType::ForceBanned(action) => action.to_text(ctx, lang, user, GearBotString::ForceBannedText),
+            LogType::Unbanned(action) => action.to_text(ctx, lang, user, GearBotString::UnbannedText),
+            LogType::Muted(action) => action.to_text(ctx, lang, user, GearBotString::MutedText),
+            LogType::Unmuted {
+                moderator,
+                moderator_id,
+                reason,
+            } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("moderator", moderator.to_string())
+                    .add("moderator_id", moderator_id.to_string())
+                    .add("reason", or_dash(reason));
+
+                ctx.translate_with_args(lang, GearBotString::UnmutedText, &args.generate())
+            }
+            LogType::TempbanExpired { infraction_id } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("id", *infraction_id);
+
+                ctx.translate_with_args(lang, GearBotString::TempbanExpiredText, &args.generate())
+            }
+            LogType::MuteExpired { infraction_id } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("id", *infraction_id);
+
+                ctx.translate_with_args(lang, GearBotString::MuteExpiredText, &args.generate())
+            }
+            LogType::TempbanLiftFailed { infraction_id } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("id", *infraction_id);
+
+                ctx.translate_with_args(lang, GearBotString::TempbanLiftFailedText, &args.generate())
+            }
+            LogType::MuteLiftFailed { infraction_id } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("id", *infraction_id);
+
+                ctx.translate_with_args(lang, GearBotString::MuteLiftFailedText, &args.generate())
+            }
+            LogType::MemberJoin => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("age", account_age(user));
+
+                ctx.translate_with_args(lang, GearBotString::MemberJoinText, &args.generate())
+            }
+            LogType::MemberLeave => {
+                let args = add_user_args(FluArgs::with_capacity(2), user);
+
+                ctx.translate_with_args(lang, GearBotString::MemberLeaveText, &args.generate())
+            }
+            LogType::NicknameChanged { before, after } => {
+                let args = add_user_args(FluArgs::with_capacity(4), user)
+                    .add("before", or_dash(before.as_deref().unwrap_or_default()))
+                    .add("after", or_dash(after.as_deref().unwrap_or_default()));
+
+                ctx.translate_with_args(lang, GearBotString::NicknameChangedText, &args.generate())
+            }
+            LogType::UsernameChanged { before, after } => {
+                let args = add_user_args(FluArgs::with_capacity(4), user)
+                    .add("before", or_dash(before))
+                    .add("after", or_dash(after));
+
+                ctx.translate_with_args(lang, GearBotString::UsernameChangedText, &args.generate())
+            }
+            LogType::RolesAdded { roles } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("roles", roles.join(", "));
+
+                ctx.translate_with_args(lang, GearBotString::RolesAddedText, &args.generate())
+            }
+            LogType::RolesRemoved { roles } => {
+                let args = add_user_args(FluArgs::with_capacity(3), user).add("roles", roles.join(", "));
+
+                ctx.translate_with_args(lang, GearBotString::RolesRemovedText, &args.generate())
+            }
+            LogType::MessageEdited {
+                before,
+                after,
+                attachments,
+            } => {
+                let args = add_user_args(FluArgs::with_capacity(6), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("before", or_dash(&shorten(before, TEXT_CONTENT_LENGTH)))
+                    .add("after", or_dash(&shorten(after, TEXT_CONTENT_LENGTH)))
+                    .add("attachments", or_dash(&attachments.join(", ")));
+
+                ctx.translate_with_args(lang, GearBotString::MessageEditedText, &args.generate())
+            }
+            LogType::MessageDeleted { content, attachments } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("content", or_dash(&shorten(content, TEXT_CONTENT_LENGTH)))
+                    .add("attachments", or_dash(&attachments.join(", ")));
+
+                ctx.translate_with_args(lang, GearBotString::MessageDeletedText, &args.generate())
+            }
+            LogType::BulkDelete { count, part, parts, .. } => {
+                let args = FluArgs::with_capacity(4)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("count", *count)
+                    .add("part", *part)
+                    .add("parts", *parts);
+
+                ctx.translate_with_args(lang, GearBotString::BulkDeleteText, &args.generate())
+            }
+            LogType::InviteCensored {
+                code,
+                guild_name,
+                content,
+            } => {
+                let args = add_user_args(FluArgs::with_capacity(6), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("code", or_dash(code))
+                    .add("guild", guild_name.to_string())
+                    .add("content", or_dash(&shorten(content, TEXT_CONTENT_LENGTH)));
+
+                ctx.translate_with_args(lang, GearBotString::InviteCensoredText, &args.generate())
+            }
+            LogType::WordCensored { term, content, .. } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("term", or_dash(term))
+                    .add("content", or_dash(&shorten(content, TEXT_CONTENT_LENGTH)));
+
+                ctx.translate_with_args(lang, GearBotString::WordCensoredText, &args.generate())
+            }
+            LogType::AttachmentCensored { filename, content } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("filename", or_dash(filename))
+                    .add("content", or_dash(&shorten(content, TEXT_CONTENT_LENGTH)));
+
+                ctx.translate_with_args(lang, GearBotString::AttachmentCensoredText, &args.generate())
+            }
+            LogType::LinkCensored { domain, content } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("domain", or_dash(domain))
+                    .add("content", or_dash(&shorten(content, TEXT_CONTENT_LENGTH)));
+
+                ctx.translate_with_args(lang, GearBotString::LinkCensoredText, &args.generate())
+            }
+            LogType::NameCensored { before, after, reason } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("before", or_dash(before))
+                    .add("after", or_dash(after))
+                    .add("reason", reason.to_string());
+
+                ctx.translate_with_args(lang, GearBotString::NameCensoredText, &args.generate())
+            }
+            LogType::SpamDetected {
+                bucket,
+                amount,
+                period,
+                punishment,
+            } => {
+                let args = add_user_args(FluArgs::with_capacity(7), user)
+                    .add("channel_id", channel.unwrap().to_string())
+                    .add("bucket", bucket.to_string())
+                    .add("amount", *amount)
+                    .add("period", *period)
+                    .add("punishment", punishment.to_string());
+
+                ctx.translate_with_args(lang, GearBotString::SpamDetectedText, &args.generate())
+            }
+            LogType::RaidStarted { joins, period } => {
+                let args = FluArgs::with_capacity(2).add("joins", *joins).add("period", *period);
+
+                ctx.translate_with_args(lang, GearBotString::RaidStartedText, &args.generate())
+            }
+            LogType::RaidEnded {
+                caught,
+                moderator,
+                moderator_id,
+            } => {
+                let args = FluArgs::with_capacity(3)
+                    .add("caught", *caught)
+                    .add("moderator", moderator.to_string())
+                    .add("moderator_id", moderator_id.to_string());
+
+                ctx.translate_with_args(lang, GearBotString::RaidEndedText, &args.generate())
+            }
+            LogType::ConfigChanged { revision, source, diff } => {
+                let args = add_user_args(FluArgs::with_capacity(5), user)
+                    .add("revision", *revision)
+                    .add("source", source.get_name())
+                    .add("diff", diff.replace("`", "ˋ"));
+
+                ctx.translate_with_args(lang, GearBotString::ConfigChangedText, &args.generate())
+            }
         }
     }
 
     pub fn emoji(&self) -> Emoji {
         match self {
             LogType::CommandUsed { .. } => Emoji::Online,
+            LogType::Warned(_) => Emoji::Warn,
+            LogType::Kicked(_) => Emoji::Kick,
+            LogType::Banned(_) | LogType::Tempbanned(_) | LogType::ForceBanned(_) => Emoji::Ban,
+            LogType::Unbanned(_) | LogType::TempbanExpired { .. } => Emoji::Unban,
+            LogType::Muted(_) => Emoji::Mute,
+            LogType::Unmuted { .. } | LogType::MuteExpired { .. } => Emoji::Unmute,
+            LogType::TempbanLiftFailed { .. } | LogType::MuteLiftFailed { .. } => Emoji::Warn,
+            LogType::MemberJoin => Emoji::Join,
+            LogType::MemberLeave => Emoji::Leave,
+            LogType::NicknameChanged { .. } | LogType::UsernameChanged { .. } => Emoji::NameChange,
+            LogType::RolesAdded { .. } => Emoji::RoleAdd,
+            LogType::RolesRemoved { .. } => Emoji::RoleRemove,
+            LogType::MessageEdited { .. } => Emoji::MessageEdit,
+            LogType::MessageDeleted { .. } | LogType::BulkDelete { .. } => Emoji::MessageDelete,
+            LogType::InviteCensored { .. }
+            | LogType::WordCensored { .. }
+            | LogType::AttachmentCensored { .. }
+            | LogType::LinkCensored { .. }
+            | LogType::NameCensored { .. } => Emoji::Censor,
+            LogType::SpamDetected { .. } => Emoji::Spam,
+            LogType::RaidStarted { .. } | LogType::RaidEnded { .. } => Emoji::Raid,
+            LogType::ConfigChanged { .. } => Emoji::GearDiamond,
         }
     }
 
     pub fn dataless(&self) -> DataLessLogType {
         match self {
             Self::CommandUsed { .. } => DataLessLogType::CommandUsed,
+            Self::Warned(_) => DataLessLogType::Warned,
+            Self::Kicked(_) => DataLessLogType::Kicked,
+            Self::Banned(_) => DataLessLogType::Banned,
+            Self::Tempbanned(_) => DataLessLogType::Tempbanned,
+            Self::ForceBanned(_) => DataLessLogType::ForceBanned,
+            Self::Unbanned(_) => DataLessLogType::Unbanned,
+            Self::Muted(_) => DataLessLogType::Muted,
+            Self::Unmuted { .. } => DataLessLogType::Unmuted,
+            Self::TempbanExpired { .. } => DataLessLogType::TempbanExpired,
+            Self::MuteExpired { .. } => DataLessLogType::MuteExpired,
+            Self::TempbanLiftFailed { .. } => DataLessLogType::TempbanLiftFailed,
+            Self::MuteLiftFailed { .. } => DataLessLogType::MuteLiftFailed,
+            Self::MemberJoin => DataLessLogType::MemberJoin,
+            Self::MemberLeave => DataLessLogType::MemberLeave,
+            Self::NicknameChanged { .. } => DataLessLogType::NicknameChanged,
+            Self::UsernameChanged { .. } => DataLessLogType::UsernameChanged,
+            Self::RolesAdded { .. } => DataLessLogType::RolesAdded,
+            Self::RolesRemoved { .. } => DataLessLogType::RolesRemoved,
+            Self::MessageEdited { .. } => DataLessLogType::MessageEdited,
+            Self::MessageDeleted { .. } => DataLessLogType::MessageDeleted,
+            Self::BulkDelete { .. } => DataLessLogType::BulkDelete,
+            Self::InviteCensored { .. } => DataLessLogType::InviteCensored,
+            Self::WordCensored { .. } => DataLessLogType::WordCensored,
+            Self::AttachmentCensored { .. } => DataLessLogType::AttachmentCensored,
+            Self::LinkCensored { .. } => DataLessLogType::LinkCensored,
+            Self::NameCensored { .. } => DataLessLogType::NameCensored,
+            Self::SpamDetected { .. } => DataLessLogType::SpamDetected,
+            Self::RaidStarted { .. } => DataLessLogType::RaidStarted,
+            Self::RaidEnded { .. } => DataLessLogType::RaidEnded,
+            Self::ConfigChanged { .. } => DataLessLogType::ConfigChanged,
         }
     }
+
+    /// The file that has to be uploaded with this log, if any.
+    pub fn file(&self) -> Option<&LogFile> {
+        match self {
+            LogType::BulkDelete { archive, .. } => Some(archive),
+            _ => None,
+        }
+    }
+
+    /// Builds the common embed for logs that are about a single user.
+    fn user_embed(
+        &self,
+        ctx: &Arc<BotContext>,
+        lang: &LanguageIdentifier,
+        user: &Arc<CachedUser>,
+        description: GearBotString,
+        args: &FluentArgs<'_>,
+        footer: GearBotString,
+    ) -> Result<EmbedBuilder, MessageError> {
+        Ok(EmbedBuilder::new()
+            .description(ctx.translate_with_args(lang, description, args))?
+            .author(
+                EmbedAuthorBuilder::new()
+                    .name(user.full_name_with_id())?
+                    .url(user.profile_link()),
+            )
+            .thumbnail(ImageSource::url(user.avatar_url())?)
+            .footer(
+                EmbedFooterBuilder::new(ctx.translate(lang, footer))?.icon_url(ImageSource::url(self.emoji().url())?),
+            ))
+    }
+}
+
+impl ModerationAction {
+    fn to_embed(
+        &self,
+        ctx: &Arc<BotContext>,
+        lang: &LanguageIdentifier,
+        user: &Arc<CachedUser>,
+        emoji: Emoji,
+        description: GearBotString,
+        footer: GearBotString,
+    ) -> Result<EmbedBuilder, MessageError> {
+        Ok(EmbedBuilder::new()
+            .description(ctx.translate_with_args(lang, description, &self.get_args(FluArgs::with_capacity(4))))?
+            .author(
+                EmbedAuthorBuilder::new()
+                    .name(user.full_name_with_id())?
+                    .url(user.profile_link()),
+            )
+            .thumbnail(ImageSource::url(user.avatar_url())?)
+            .footer(EmbedFooterBuilder::new(ctx.translate(lang, footer))?.icon_url(ImageSource::url(emoji.url())?)))
+    }
+
+    fn to_text(
+        &self,
+        ctx: &Arc<BotContext>,
+        lang: &LanguageIdentifier,
+        user: &Arc<CachedUser>,
+        key: GearBotString,
+    ) -> String {
+        let args = self.get_args(add_user_args(FluArgs::with_capacity(6), user));
+
+        ctx.translate_with_args(lang, key, &args)
+    }
+
+    fn get_args<'a>(&self, args: FluArgs<'a>) -> FluentArgs<'a> {
+        args.add("moderator", self.moderator.clone())
+            .add("moderator_id", self.moderator_id.to_string())
+            .add("reason", or_dash(&self.reason))
+            .add("id", self.infraction_id)
+            .generate()
+    }
+}
+
+/// Makes it obvious something (like a reason or nickname) wasn't there instead of leaving an empty spot.
+fn or_dash(value: &str) -> String {
+    if value.is_empty() {
+        String::from("-")
+    } else {
+        value.replace("`", "ˋ")
+    }
+}
+
+/// Cuts off message content that is too long to fit in a log, on a character boundary.
+fn shorten(content: &str, max_chars: usize) -> String {
+    if content.chars().count() > max_chars {
+        content.chars().take(max_chars).collect::<String>() + "..."
+    } else {
+        content.to_string()
+    }
+}
+
+fn account_age(user: &Arc<CachedUser>) -> String {
+    utils::age(utils::snowflake_timestamp(user.id.0), Utc::now(), 2)
 }
 
 fn add_user_args<'a>(args: FluArgs<'a>, user: &Arc<CachedUser>) -> FluArgs<'a> {
@@ -11,7 +11,9 @@ const RECV_TIMEOUT: Duration = Duration::from_secs(4);
 pub use log_data::LogData;
 pub use log_filter::LogFilter;
 pub use log_type::DataLessLogType;
+pub use log_type::LogFile;
 pub use log_type::LogType;
+pub use log_type::ModerationAction;
 
 use crate::core::bot_context::BotContext;
 use crate::core::guild_config::LogStyle;
@@ -319,8 +321,15 @@ async fn send(
     match style {
         SendStyle::Channel => {
             let mut output = String::new();
+            let mut file = None;
 
             while let Some(item) = todo.first() {
+                // Logs with a file need the message for themselves, send out what we have before getting to them.
+                let item_file = item.log_type.file();
+                if item_file.is_some() && !output.is_empty() {
+                    break;
+                }
+
                 // Get the user responsible for causing the log event.
                 let user = match ctx.get_user(item.source_user).await {
                     Ok(user) => user,
@@ -349,7 +358,12 @@ async fn send(
                 if output.len() + extra.len() < DISCORD_SIZE_LIMIT {
                     output += &extra;
                     output += "\n";
+                    file = item_file.map(|f| (f.name.clone(), f.content.clone().into_bytes()));
                     todo.remove(0);
+
+                    if file.is_some() {
+                        break;
+                    }
                 } else {
                     // The message can't grow any longer without violating the size limit, time to send it.
                     break;
@@ -357,12 +371,24 @@ async fn send(
             }
 
             // Assembly done, pack it into the future
-            ctx.http.create_message(channel_id).content(output).unwrap().await?;
+            let mut request = ctx.http.create_message(channel_id).content(output).unwrap();
+            if let Some((name, content)) = file {
+                request = request.attachment(name, content);
+            }
+            request.await?;
             Ok(None)
         }
         SendStyle::Webhook(webhook) => {
             let mut out = vec![];
-            for data in todo.drain(..) {
+            let mut file = None;
+
+            while let Some(next) = todo.first() {
+                // Logs with a file need the message for themselves, send out what we have before getting to them.
+                if next.log_type.file().is_some() && !out.is_empty() {
+                    break;
+                }
+
+                let data = todo.remove(0);
                 let user = match ctx.get_user(data.source_user).await {
                     Ok(user) => user,
                     Err(e) => {
@@ -373,20 +399,28 @@ async fn send(
                 };
 
                 match data.log_type.to_embed(&ctx, language, &user, &data.source_channel) {
-                    Ok(embed) => {
-                        out.push(embed);
-                        if out.len() == 10 {
-                            break;
-                        }
-                    }
+                    Ok(embed) => out.push(embed),
                     Err(e) => {
                         gearbot_error!("Failed to create logging embed: {} (data: {:?})", e, data);
                     }
                 }
+
+                file = data
+                    .log_type
+                    .file()
+                    .map(|f| (f.name.clone(), f.content.clone().into_bytes()));
+                if file.is_some() || out.len() == 10 {
+                    break;
+                }
             }
 
             let (webhook_id, token) = webhook;
-            match ctx.http.execute_webhook(*webhook_id, token).embeds(out).await {
+            let mut request = ctx.http.execute_webhook(*webhook_id, token).embeds(out);
+            if let Some((name, content)) = file {
+                request = request.file(name, content);
+            }
+
+            match request.await {
                 Err(Error::Response { status, .. }) if status == StatusCode::NOT_FOUND => {
                     Ok(Some(WebhookValidity::Unusable))
                 }
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use twilight_model::id::{GuildId, MessageId};
+
+use super::guild_config::DEFAULT_MESSAGE_RETENTION_DAYS;
+use crate::core::BotContext;
+use crate::error::DatabaseError;
+use crate::gearbot_error;
+use crate::utils;
+
+/// How many messages are removed per query, so the table never gets locked up for long.
+const BATCH_SIZE: i64 = 1_000;
+/// How often we go over all guilds to prune their expired messages.
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Periodically removes the stored messages that are older then their guild wants to keep them.
+///
+/// Just like the timed actions, each cluster only takes care of the guilds on its own shards.
+pub async fn run(ctx: Arc<BotContext>) {
+    log::info!("Message retention pruner started!");
+    let first_shard = ctx.scheme_info.cluster_id * ctx.scheme_info.shards_per_cluster;
+    let shards = first_shard..first_shard + ctx.scheme_info.shards_per_cluster;
+
+    loop {
+        match ctx
+            .datastore
+            .get_guild_ids(shards.clone(), ctx.scheme_info.total_shards)
+            .await
+        {
+            Ok(guilds) => {
+                let mut pruned = 0;
+                for guild_id in guilds {
+                    match prune_guild(&ctx, guild_id).await {
+                        Ok(count) => pruned += count,
+                        Err(e) => gearbot_error!("Failed to prune the stored messages of guild {}: {}", guild_id, e),
+                    }
+                }
+
+                if pruned > 0 {
+                    log::info!("Pruned {} stored messages that expired", pruned);
+                }
+            }
+            Err(e) => gearbot_error!("Failed to load the guilds to prune messages for: {}", e),
+        }
+
+        match ctx.datastore.estimate_stored_messages().await {
+            Ok(estimate) => ctx.stats.stored_messages.set(estimate),
+            Err(e) => gearbot_error!("Failed to estimate the amount of stored messages: {}", e),
+        }
+
+        tokio::time::sleep(PRUNE_INTERVAL).await;
+    }
+}
+
+async fn prune_guild(ctx: &Arc<BotContext>, guild_id: GuildId) -> Result<u64, DatabaseError> {
+    let retention = match ctx.get_cached_config(guild_id).await {
+        Some(config) => config.message_logs.retention_days,
+        // loading it would keep it in memory for good, even for guilds the bot left
+        None => ctx
+            .datastore
+            .get_message_retention(guild_id)
+            .await?
+            .unwrap_or(DEFAULT_MESSAGE_RETENTION_DAYS),
+    };
+    let cutoff = Utc::now() - chrono::Duration::days(retention as i64);
+    let before = MessageId(utils::timestamp_snowflake(cutoff));
+
+    let mut pruned = 0;
+    loop {
+        let removed = ctx.datastore.prune_messages(guild_id, before, BATCH_SIZE).await?;
+        pruned += removed;
+
+        if removed < BATCH_SIZE as u64 {
+            return Ok(pruned);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 pub use bot_config::BotConfig;
 pub use cold_resume_data::ColdRebootData;
-pub use guild_config::GuildConfig;
+pub use guild_config::{CensorConfig, CensorFilters, ChannelFilter, GuildConfig, Punishment, RaidAction};
 pub use reactors::Reactor;
 
 mod bot_config;
@@ -9,12 +9,33 @@ mod cold_resume_data;
 mod bot_context;
 pub use bot_context::{status, BotContext, BotStats, ShardState};
 
+pub mod config_editing;
+pub mod config_migrations;
+pub mod config_transfer;
+pub mod config_validation;
+
 mod command_context;
-pub use command_context::{CommandContext, CommandMessage};
+pub use command_context::{CommandContext, CommandMessage, CommandSource};
+
+pub mod custom_commands;
 
 mod guild_config;
 
 pub mod logging;
 pub mod logpump;
 
+pub mod message_retention;
+
+pub mod moderation;
+
+pub mod punishments;
+
+pub mod raids;
+
 pub mod reactors;
+
+pub mod rename_limiter;
+
+pub mod spam;
+
+pub mod timed_actions;
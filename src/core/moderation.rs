@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use twilight_http::request::AuditLogReason;
+use twilight_model::id::{ChannelId, GuildId, RoleId, UserId};
+
+use crate::cache::{CachedGuild, CachedMember, CachedUser};
+use crate::core::logpump::{LogData, LogType, ModerationAction};
+use crate::core::BotContext;
+use crate::database::structures::{Infraction, InfractionType};
+use crate::error::OtherFailure;
+
+/// Discord refuses audit log reasons that are longer than this.
+const AUDIT_LOG_REASON_LENGTH: usize = 512;
+
+/// Carries out moderation actions in a guild on behalf of a moderator.
+///
+/// Both the moderation commands and the automatic moderation features go through this, so every action shows up the
+/// same way in the audit log, the infractions and the logs. Checking if the action is allowed is up to the caller.
+pub struct Moderator<'a> {
+    ctx: &'a Arc<BotContext>,
+    guild_id: GuildId,
+    name: String,
+    id: UserId,
+    source_channel: Option<ChannelId>,
+}
+
+impl<'a> Moderator<'a> {
+    pub fn new(
+        ctx: &'a Arc<BotContext>,
+        guild_id: GuildId,
+        user: &CachedUser,
+        source_channel: Option<ChannelId>,
+    ) -> Self {
+        Moderator {
+            ctx,
+            guild_id,
+            name: user.full_name(),
+            id: user.id,
+            source_channel,
+        }
+    }
+
+    /// For actions the bot takes by itself.
+    pub fn bot(ctx: &'a Arc<BotContext>, guild_id: GuildId, source_channel: Option<ChannelId>) -> Self {
+        Moderator {
+            ctx,
+            guild_id,
+            name: format!("{}#{}", ctx.bot_user.name, ctx.bot_user.discriminator),
+            id: ctx.bot_user.id,
+            source_channel,
+        }
+    }
+
+    /// Builds the reason that shows up in the discord audit log, so it's clear who was responsible for the action.
+    pub fn audit_log_reason(&self, reason: &str) -> String {
+        format!("Moderator: {} ({}) Reason: {}", self.name, self.id, reason)
+            .chars()
+            .take(AUDIT_LOG_REASON_LENGTH)
+            .collect()
+    }
+
+    pub async fn warn(&self, user_id: UserId, reason: &str) -> Result<Infraction, OtherFailure> {
+        self.record(user_id, InfractionType::Warning, reason, None, LogType::Warned)
+            .await
+    }
+
+    /// Mutes the user until `end`, `None` if they already have an active mute.
+    pub async fn mute(
+        &self,
+        user_id: UserId,
+        role: RoleId,
+        reason: &str,
+        end: DateTime<Utc>,
+    ) -> Result<Option<Infraction>, OtherFailure> {
+        if self
+            .ctx
+            .datastore
+            .get_active_mute(self.guild_id, user_id)
+            .await?
+            .is_some()
+        {
+            return Ok(None);
+        }
+
+        self.ctx
+            .http
+            .add_guild_member_role(self.guild_id, user_id, role)
+            .reason(self.audit_log_reason(reason))
+            .map_err(OtherFailure::AuditLogReason)?
+            .await?;
+
+        let infraction = self
+            .record(user_id, InfractionType::Mute, reason, Some(end), LogType::Muted)
+            .await?;
+        self.ctx.datastore.add_timed_action(infraction.id).await?;
+        self.ctx.reschedule_timed_actions();
+
+        Ok(Some(infraction))
+    }
+
+    pub async fn kick(&self, user_id: UserId, reason: &str) -> Result<Infraction, OtherFailure> {
+        self.ctx
+            .http
+            .remove_guild_member(self.guild_id, user_id)
+            .reason(self.audit_log_reason(reason))
+            .map_err(OtherFailure::AuditLogReason)?
+            .await?;
+
+        self.record(user_id, InfractionType::Kick, reason, None, LogType::Kicked)
+            .await
+    }
+
+    pub async fn ban(&self, user_id: UserId, reason: &str) -> Result<Infraction, OtherFailure> {
+        self.create_ban(user_id, reason).await?;
+        self.record(user_id, InfractionType::Ban, reason, None, LogType::Banned)
+            .await
+    }
+
+    /// Bans the user until `end`, the ban is lifted again once that has passed.
+    pub async fn tempban(&self, user_id: UserId, reason: &str, end: DateTime<Utc>) -> Result<Infraction, OtherFailure> {
+        self.create_ban(user_id, reason).await?;
+        let infraction = self
+            .record(user_id, InfractionType::Tempban, reason, Some(end), LogType::Tempbanned)
+            .await?;
+        self.ctx.datastore.add_timed_action(infraction.id).await?;
+        self.ctx.reschedule_timed_actions();
+
+        Ok(infraction)
+    }
+
+    /// Bans a user that isn't on the server.
+    pub async fn forceban(&self, user_id: UserId, reason: &str) -> Result<Infraction, OtherFailure> {
+        self.create_ban(user_id, reason).await?;
+        self.record(user_id, InfractionType::Forceban, reason, None, LogType::ForceBanned)
+            .await
+    }
+
+    /// Lifts a ban, a tempban that was still running won't be lifted by the scheduler anymore.
+    pub async fn unban(&self, user_id: UserId, reason: &str) -> Result<Infraction, OtherFailure> {
+        self.ctx
+            .http
+            .delete_ban(self.guild_id, user_id)
+            .reason(self.audit_log_reason(reason))
+            .map_err(OtherFailure::AuditLogReason)?
+            .await?;
+
+        let datastore = &self.ctx.datastore;
+        if let Some(tempban) = datastore.get_active_tempban(self.guild_id, user_id).await? {
+            datastore.complete_timed_action(tempban.id).await?;
+        }
+
+        self.record(user_id, InfractionType::Unban, reason, None, LogType::Unbanned)
+            .await
+    }
+
+    async fn create_ban(&self, user_id: UserId, reason: &str) -> Result<(), OtherFailure> {
+        self.ctx
+            .http
+            .create_ban(self.guild_id, user_id)
+            .reason(self.audit_log_reason(reason))
+            .map_err(OtherFailure::AuditLogReason)?
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stores the infraction and logs the action, once discord has carried it out.
+    async fn record(
+        &self,
+        user_id: UserId,
+        kind: InfractionType,
+        reason: &str,
+        end: Option<DateTime<Utc>>,
+        log: fn(ModerationAction) -> LogType,
+    ) -> Result<Infraction, OtherFailure> {
+        let infraction = self
+            .ctx
+            .datastore
+            .insert_infraction(self.guild_id, user_id, self.id, kind, reason, end)
+            .await?;
+
+        self.ctx.log(LogData {
+            log_type: log(ModerationAction {
+                moderator: self.name.clone(),
+                moderator_id: self.id,
+                reason: infraction.reason.clone(),
+                infraction_id: infraction.id,
+            }),
+            guild: self.guild_id,
+            source_channel: self.source_channel,
+            source_user: user_id,
+        });
+
+        Ok(infraction)
+    }
+}
+
+/// Gets the configured mute role, `None` if there is none or it no longer exists.
+pub async fn get_mute_role(ctx: &Arc<BotContext>, guild_id: GuildId) -> Result<Option<RoleId>, OtherFailure> {
+    let role = match ctx.get_config(guild_id).await?.mute.role {
+        Some(role) => role,
+        None => return Ok(None),
+    };
+
+    Ok(match ctx.cache.get_guild(&guild_id).await {
+        Some(guild) => guild.get_role(&role).await.map(|_| role),
+        None => None,
+    })
+}
+
+/// Checks if the member sits above the target in the role hierarchy.
+///
+/// The guild owner outranks everyone and can never be outranked themselves.
+pub async fn outranks(guild: &CachedGuild, member: &CachedMember, target: &CachedMember) -> bool {
+    if target.user_id == guild.owner_id {
+        return false;
+    }
+    if member.user_id == guild.owner_id {
+        return true;
+    }
+
+    guild.get_top_role_position(member).await > guild.get_top_role_position(target).await
+}
+
+/// Checks if the bot sits above the user in the role hierarchy. Users that are no member (anymore) have no roles
+/// to worry about.
+pub async fn bot_outranks(ctx: &Arc<BotContext>, guild_id: GuildId, user_id: UserId) -> bool {
+    let guild = match ctx.cache.get_guild(&guild_id).await {
+        Some(guild) => guild,
+        None => return false,
+    };
+
+    if user_id == guild.owner_id {
+        return false;
+    }
+
+    let target = match ctx.cache.get_member(&guild_id, &user_id).await {
+        Some(target) => target,
+        None => return true,
+    };
+
+    match ctx.cache.get_member(&guild_id, &ctx.bot_user.id).await {
+        Some(bot) => outranks(&guild, &bot, &target).await,
+        None => false,
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use twilight_model::id::{ChannelId, GuildId, UserId};
+
+use crate::core::guild_config::Punishment;
+use crate::core::moderation::{self, Moderator};
+use crate::core::BotContext;
+use crate::error::OtherFailure;
+use crate::gearbot_warn;
+
+/// Hands out a punishment on behalf of the bot itself, for the automatic moderation features.
+///
+/// This works just like the moderation commands: an infraction is recorded with the bot as moderator and the action
+/// gets logged. Members that sit above the bot are left alone. `Delete` doesn't do anything here, only the caller
+/// knows which messages need to go.
+pub async fn punish(
+    ctx: &Arc<BotContext>,
+    guild_id: GuildId,
+    user_id: UserId,
+    punishment: Punishment,
+    reason: &str,
+    source_channel: Option<ChannelId>,
+) -> Result<(), OtherFailure> {
+    if punishment == Punishment::Delete || !moderation::bot_outranks(ctx, guild_id, user_id).await {
+        return Ok(());
+    }
+
+    let moderator = Moderator::bot(ctx, guild_id, source_channel);
+    match punishment {
+        Punishment::Warn => {
+            moderator.warn(user_id, reason).await?;
+        }
+        Punishment::Mute(seconds) => {
+            let role = match moderation::get_mute_role(ctx, guild_id).await? {
+                Some(role) => role,
+                None => {
+                    gearbot_warn!("Can't mute {} in {} without a mute role configured", user_id, guild_id);
+                    return Ok(());
+                }
+            };
+
+            let end = Utc::now() + Duration::seconds(seconds as i64);
+            moderator.mute(user_id, role, reason, end).await?;
+        }
+        Punishment::Kick => {
+            moderator.kick(user_id, reason).await?;
+        }
+        Punishment::Ban => {
+            moderator.ban(user_id, reason).await?;
+        }
+        Punishment::Delete => {}
+    }
+
+    Ok(())
+}
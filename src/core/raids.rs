@@ -0,0 +1,156 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+use twilight_model::guild::VerificationLevel;
+use twilight_model::id::{GuildId, UserId};
+
+use crate::core::guild_config::RaidConfig;
+
+/// A raid that is still going on.
+#[derive(Debug)]
+pub struct ActiveRaid {
+    pub started: DateTime<Utc>,
+    /// What the verification level was before it got raised, if it was raised at all.
+    pub previous_verification: Option<VerificationLevel>,
+    /// Everyone that joined as part of the raid, in the order they joined.
+    pub caught: Vec<UserId>,
+}
+
+/// What a new join meant for the guild.
+#[derive(Debug, PartialEq)]
+pub enum JoinOutcome {
+    Nothing,
+    /// This join tipped the guild over the threshold, these are all the joins that made up the start of the raid.
+    RaidStarted(Vec<UserId>),
+    /// There already is a raid going on, this member is part of it.
+    Raider,
+}
+
+#[derive(Default)]
+struct GuildRaidState {
+    joins: VecDeque<(Instant, UserId)>,
+    raid: Option<ActiveRaid>,
+}
+
+/// Keeps track of recent joins to spot raids, and of the raids that are going on.
+///
+/// Just like the spam buckets this only lives in memory, a restart forgets about ongoing raids.
+#[derive(Default)]
+pub struct RaidTracker {
+    guilds: Mutex<HashMap<GuildId, GuildRaidState>>,
+}
+
+impl RaidTracker {
+    /// Registers a join. Joins that don't `count` (accounts that are too old) can still get caught in an ongoing raid,
+    /// but don't help to start one.
+    pub async fn track_join(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        counts: bool,
+        config: &RaidConfig,
+        now: Instant,
+    ) -> JoinOutcome {
+        let mut guilds = self.guilds.lock().await;
+        let state = guilds.entry(guild_id).or_default();
+
+        if let Some(raid) = &mut state.raid {
+            raid.caught.push(user_id);
+            return JoinOutcome::Raider;
+        }
+
+        let period = Duration::from_secs(config.period as u64);
+        while state
+            .joins
+            .front()
+            .map_or(false, |(joined, _)| now.duration_since(*joined) > period)
+        {
+            state.joins.pop_front();
+        }
+
+        if !counts {
+            return JoinOutcome::Nothing;
+        }
+
+        state.joins.push_back((now, user_id));
+        if state.joins.len() < config.threshold.max(1) as usize {
+            return JoinOutcome::Nothing;
+        }
+
+        let caught: Vec<UserId> = state.joins.drain(..).map(|(_, user_id)| user_id).collect();
+        state.raid = Some(ActiveRaid {
+            started: Utc::now(),
+            previous_verification: None,
+            caught: caught.clone(),
+        });
+
+        JoinOutcome::RaidStarted(caught)
+    }
+
+    /// Remembers what the verification level was before the raid raised it, so ending the raid can restore it.
+    pub async fn set_previous_verification(&self, guild_id: GuildId, level: VerificationLevel) {
+        if let Some(raid) = self
+            .guilds
+            .lock()
+            .await
+            .get_mut(&guild_id)
+            .and_then(|state| state.raid.as_mut())
+        {
+            raid.previous_verification = Some(level);
+        }
+    }
+
+    /// Ends the raid in a guild, returning it if there was one.
+    pub async fn end(&self, guild_id: GuildId) -> Option<ActiveRaid> {
+        self.guilds.lock().await.remove(&guild_id).and_then(|state| state.raid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::RaidAction;
+
+    #[tokio::test]
+    async fn raids_start_at_the_threshold() {
+        let config = RaidConfig {
+            enabled: true,
+            threshold: 3,
+            period: 10,
+            max_account_age_days: None,
+            raise_verification: false,
+            action: RaidAction::Nothing,
+        };
+        let tracker = RaidTracker::default();
+        let guild = GuildId(1);
+        let start = Instant::now();
+
+        let join = |user, counts, seconds| {
+            tracker.track_join(
+                guild,
+                UserId(user),
+                counts,
+                &config,
+                start + Duration::from_secs(seconds),
+            )
+        };
+
+        assert_eq!(join(1, true, 0).await, JoinOutcome::Nothing);
+        // too old to count
+        assert_eq!(join(2, false, 5).await, JoinOutcome::Nothing);
+        // the first join fell out of the window by now
+        assert_eq!(join(3, true, 11).await, JoinOutcome::Nothing);
+        assert_eq!(join(4, true, 12).await, JoinOutcome::Nothing);
+        assert_eq!(
+            join(5, true, 13).await,
+            JoinOutcome::RaidStarted(vec![UserId(3), UserId(4), UserId(5)])
+        );
+        assert_eq!(join(6, false, 14).await, JoinOutcome::Raider);
+
+        let raid = tracker.end(guild).await.unwrap();
+        assert_eq!(raid.caught.len(), 4);
+        assert!(tracker.end(guild).await.is_none());
+    }
+}
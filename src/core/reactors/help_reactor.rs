@@ -1 +1,153 @@
+use std::sync::Arc;
 
+use serde::{Deserialize, Serialize};
+use twilight_embed_builder::{EmbedAuthorBuilder, EmbedBuilder, EmbedFooterBuilder};
+use twilight_model::channel::embed::Embed;
+use twilight_model::channel::Reaction;
+use twilight_model::id::UserId;
+use unic_langid::LanguageIdentifier;
+
+use crate::commands::meta::nodes::{CommandGroup, CommandNode, GearBotPermissions};
+use crate::commands::ROOT_NODE;
+use crate::core::bot_context::BotContext;
+use crate::core::reactors::{get_emoji, scroll_page};
+use crate::core::GuildConfig;
+use crate::error::{MessageError, ReactorError};
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+/// Keeps big groups from turning into a wall of text.
+const COMMANDS_PER_PAGE: usize = 10;
+
+/// Pages through the commands someone can use, either of every group or of a single one.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct HelpReactor {
+    pub author: UserId,
+    /// What the author was allowed to use when asking for help, the pages are built from these again when scrolling.
+    pub permissions: GearBotPermissions,
+    pub group: Option<CommandGroup>,
+    pub page: u8,
+}
+
+/// A page of commands, all from the same group.
+pub struct HelpPage {
+    pub group: CommandGroup,
+    pub commands: Vec<Arc<CommandNode>>,
+}
+
+impl HelpReactor {
+    pub fn processes(&self, reaction: &Reaction) -> Option<Emoji> {
+        // the pages only list what the author can use
+        if reaction.user_id != self.author {
+            return None;
+        }
+
+        get_emoji(vec![Emoji::Left, Emoji::Right], reaction)
+    }
+
+    pub async fn do_the_thing(
+        &mut self,
+        emoji: &Emoji,
+        ctx: &Arc<BotContext>,
+        reaction: &Reaction,
+    ) -> Result<(), ReactorError> {
+        let guild_id = match reaction.guild_id {
+            Some(guild_id) => guild_id,
+            None => return Ok(()),
+        };
+
+        let pages = help_pages(self.permissions, self.group.as_ref());
+        if pages.is_empty() {
+            return Ok(());
+        }
+
+        // the commands might have changed since the reactor was saved
+        let count = pages.len() as u8;
+        self.page = scroll_page(count, self.page.min(count - 1), emoji);
+        let embed = gen_help_page(ctx, &ctx.get_config(guild_id).await?, &pages, self.page)?;
+        ctx.http
+            .update_message(reaction.channel_id, reaction.message_id)
+            .embed(embed)?
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Splits the commands someone with these permissions can use into pages, for the given group or all of them.
+pub fn help_pages(permissions: GearBotPermissions, group: Option<&CommandGroup>) -> Vec<HelpPage> {
+    let groups = match group {
+        Some(group) => vec![group],
+        None => ROOT_NODE.groups.iter().collect(),
+    };
+
+    let mut pages = vec![];
+    for group in groups {
+        let commands = ROOT_NODE.visible_commands(group, permissions);
+        for chunk in commands.chunks(COMMANDS_PER_PAGE) {
+            pages.push(HelpPage {
+                group: group.clone(),
+                commands: chunk.to_vec(),
+            });
+        }
+    }
+
+    pages
+}
+
+pub fn gen_help_page(
+    ctx: &BotContext,
+    guild_config: &GuildConfig,
+    pages: &[HelpPage],
+    page: u8,
+) -> Result<Embed, MessageError> {
+    let lang = &guild_config.language;
+    let current = &pages[page as usize];
+
+    let header_args = FluArgs::with_capacity(3)
+        .add("group", current.group.get_name())
+        .add("page", page + 1)
+        .add("pages", pages.len())
+        .generate();
+    let footer_args = FluArgs::with_capacity(1)
+        .add("prefix", guild_config.prefix.clone())
+        .generate();
+
+    let lines: Vec<String> = current
+        .commands
+        .iter()
+        .map(|command| command_line(ctx, lang, &guild_config.prefix, &command.name, command))
+        .collect();
+
+    Ok(EmbedBuilder::new()
+        .author(EmbedAuthorBuilder::new().name(ctx.translate_with_args(
+            lang,
+            GearBotString::HelpPageHeader,
+            &header_args,
+        ))?)
+        .description(lines.join("\n"))?
+        .footer(EmbedFooterBuilder::new(ctx.translate_with_args(
+            lang,
+            GearBotString::HelpFooter,
+            &footer_args,
+        ))?)
+        .build()?)
+}
+
+/// A line with the names a command can be invoked by and what it does. `invocation` is what comes before those names,
+/// `path` the names of the command and its parents joined by `__`.
+pub fn command_line(
+    ctx: &BotContext,
+    lang: &LanguageIdentifier,
+    invocation: &str,
+    path: &str,
+    command: &CommandNode,
+) -> String {
+    let names: Vec<String> = std::iter::once(&command.name)
+        .chain(&command.aliases)
+        .map(|name| format!("``{}{}``", invocation, name))
+        .collect();
+    let description = ctx.translations.get_command_description(lang, path).unwrap_or_default();
+
+    format!("{} - {}", names.join(", "), description)
+}
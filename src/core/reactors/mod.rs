@@ -2,23 +2,29 @@ use std::sync::Arc;
 
 use serde::{Deserialize, Serialize};
 use twilight_model::channel::Reaction;
-use twilight_model::id::MessageId;
+use twilight_model::id::{MessageId, UserId};
 
+use crate::commands::meta::nodes::{CommandGroup, GearBotPermissions};
 use crate::core::bot_context::BotContext;
 use crate::core::reactors::emoji_list_reactor::EmojiListReactor;
+use crate::core::reactors::help_reactor::HelpReactor;
+use crate::core::reactors::raid_ban_reactor::RaidBanReactor;
 use crate::error::{DatabaseError, ReactorError};
 use crate::utils::Emoji;
 
 mod emoji_list_reactor;
 mod help_reactor;
+mod raid_ban_reactor;
 pub mod reactor_controller;
 
 pub use emoji_list_reactor::gen_emoji_page;
+pub use help_reactor::{command_line, gen_help_page, help_pages};
 
 #[derive(Deserialize, Serialize, Debug)]
 pub enum Reactor {
-    Help,
+    Help(HelpReactor),
     EmojiList(EmojiListReactor),
+    RaidBan(RaidBanReactor),
 }
 
 impl Reactor {
@@ -28,10 +34,28 @@ impl Reactor {
         }
     }
 
+    pub fn new_help(author: UserId, permissions: GearBotPermissions, group: Option<CommandGroup>) -> Self {
+        Reactor::Help(HelpReactor {
+            author,
+            permissions,
+            group,
+            page: 0,
+        })
+    }
+
+    pub fn new_raid_ban(moderator: UserId, reason: String, users: Vec<UserId>) -> Self {
+        Reactor::RaidBan(RaidBanReactor {
+            moderator,
+            reason,
+            users,
+        })
+    }
+
     pub fn processes(&self, reaction: &Reaction) -> Option<Emoji> {
         match self {
-            Reactor::Help => None,
+            Reactor::Help(inner) => inner.processes(reaction),
             Reactor::EmojiList(inner) => inner.processes(reaction),
+            Reactor::RaidBan(inner) => inner.processes(reaction),
         }
     }
 
@@ -46,11 +70,18 @@ impl Reactor {
             None => None,
         };
         let new = match self {
-            Reactor::Help => self,
+            Reactor::Help(mut inner) => {
+                inner.do_the_thing(emoji, ctx, reaction).await?;
+                Reactor::Help(inner)
+            }
             Reactor::EmojiList(mut inner) => {
                 inner.do_the_thing(emoji, ctx, member, reaction).await?;
                 Reactor::EmojiList { 0: inner }
             }
+            Reactor::RaidBan(mut inner) => {
+                inner.do_the_thing(ctx, member, reaction).await?;
+                Reactor::RaidBan(inner)
+            }
         };
 
         new.save(ctx, reaction.message_id).await?;
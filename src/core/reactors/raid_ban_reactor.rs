@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use twilight_model::channel::Reaction;
+use twilight_model::guild::Permissions;
+use twilight_model::id::UserId;
+
+use crate::cache::CachedMember;
+use crate::commands::meta::nodes::GearBotPermissions;
+use crate::core::bot_context::BotContext;
+use crate::core::config_editing;
+use crate::core::moderation::{self, Moderator};
+use crate::core::reactors::get_emoji;
+use crate::error::{MessageError, ReactorError};
+use crate::gearbot_warn;
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils::Emoji;
+
+/// How much of the message can go to listing the accounts that were skipped.
+const SKIPPED_LIST_LENGTH: usize = 1500;
+
+/// Offers the moderator that ended a raid to ban everyone that was caught in it.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RaidBanReactor {
+    pub moderator: UserId,
+    pub reason: String,
+    /// Emptied once they are banned, so the ban can't be repeated.
+    pub users: Vec<UserId>,
+}
+
+impl RaidBanReactor {
+    pub fn processes(&self, reaction: &Reaction) -> Option<Emoji> {
+        // only the moderator that ended the raid gets to confirm
+        if reaction.user_id != self.moderator || self.users.is_empty() {
+            return None;
+        }
+
+        get_emoji(vec![Emoji::Yes], reaction)
+    }
+
+    pub async fn do_the_thing(
+        &mut self,
+        ctx: &Arc<BotContext>,
+        member: Option<Arc<CachedMember>>,
+        reaction: &Reaction,
+    ) -> Result<(), ReactorError> {
+        let (guild_id, member) = match (reaction.guild_id, member) {
+            (Some(guild_id), Some(member)) => (guild_id, member),
+            _ => return Ok(()),
+        };
+
+        let guild = match ctx.cache.get_guild(&guild_id).await {
+            Some(guild) => guild,
+            None => return Ok(()),
+        };
+        let config = ctx.get_config(guild_id).await?;
+
+        // permissions might have changed since the raid was ended, the same ones as for the ban command are needed
+        if !ctx
+            .get_permissions_for(&guild, &member, &config)
+            .await
+            .contains(GearBotPermissions::BAN_COMMAND)
+            || !ctx
+                .get_guild_permissions_for(&guild_id, &self.moderator)
+                .await
+                .contains(Permissions::BAN_MEMBERS)
+            || !ctx
+                .get_guild_permissions_for(&guild_id, &ctx.bot_user.id)
+                .await
+                .contains(Permissions::BAN_MEMBERS)
+        {
+            return Ok(());
+        }
+
+        let moderator = Moderator::new(ctx, guild_id, &member.user(&ctx.cache).await, None);
+        let users = std::mem::take(&mut self.users);
+        let mut banned = 0;
+        let mut skipped = vec![];
+        for user_id in &users {
+            // they might have been given roles since they joined
+            let moderator_outranks = match ctx.cache.get_member(&guild_id, user_id).await {
+                Some(target) => moderation::outranks(&guild, &member, &target).await,
+                None => true,
+            };
+            if !moderator_outranks || !moderation::bot_outranks(ctx, guild_id, *user_id).await {
+                skipped.push(format!("<@{}>", user_id));
+                continue;
+            }
+
+            match moderator.ban(*user_id, &self.reason).await {
+                Ok(_) => banned += 1,
+                Err(e) => gearbot_warn!("Failed to ban raider {} in {}: {}", user_id, guild_id, e),
+            }
+        }
+
+        let args = FluArgs::with_capacity(3)
+            .add("gearyes", Emoji::Yes.for_chat())
+            .add("banned", banned)
+            .add("count", users.len())
+            .generate();
+        let mut content = ctx.translate_with_args(&config.language, GearBotString::RaidBanDone, &args);
+        if !skipped.is_empty() {
+            let args = FluArgs::with_capacity(2)
+                .add("gearwarn", Emoji::Warn.for_chat())
+                .add("users", config_editing::shorten_diff(skipped, SKIPPED_LIST_LENGTH))
+                .generate();
+            content += "\n";
+            content += &ctx.translate_with_args(&config.language, GearBotString::RaidBanSkipped, &args);
+        }
+
+        ctx.http
+            .create_message(reaction.channel_id)
+            .content(content)
+            .map_err(MessageError::Create)?
+            .await?;
+
+        Ok(())
+    }
+}
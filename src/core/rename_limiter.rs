@@ -0,0 +1,66 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use twilight_model::id::GuildId;
+
+/// How many members can be renamed in a single guild within `RENAME_WINDOW`.
+const MAX_RENAMES: usize = 10;
+const RENAME_WINDOW: Duration = Duration::from_secs(60);
+
+/// Caps how many automatic renames a guild gets, so a mass join full of bad names can't use up the http bucket that
+/// is shared with everything else the bot does in that guild.
+///
+/// Names that didn't get fixed because of this just stay until the next time the member gets updated.
+#[derive(Default)]
+pub struct RenameLimiter {
+    guilds: Mutex<HashMap<GuildId, VecDeque<Instant>>>,
+}
+
+impl RenameLimiter {
+    /// Claims a rename for the guild, returns false when it already had its share for now.
+    pub async fn try_rename(&self, guild_id: GuildId, now: Instant) -> bool {
+        let mut guilds = self.guilds.lock().await;
+        // guilds with nothing recent can be forgotten about entirely
+        guilds.retain(|_, renames| {
+            renames
+                .back()
+                .map_or(false, |newest| now.duration_since(*newest) < RENAME_WINDOW)
+        });
+
+        let renames = guilds.entry(guild_id).or_default();
+        while renames
+            .front()
+            .map_or(false, |oldest| now.duration_since(*oldest) >= RENAME_WINDOW)
+        {
+            renames.pop_front();
+        }
+
+        if renames.len() >= MAX_RENAMES {
+            return false;
+        }
+
+        renames.push_back(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn renames_are_limited_per_guild() {
+        let limiter = RenameLimiter::default();
+        let start = Instant::now();
+
+        for _ in 0..MAX_RENAMES {
+            assert!(limiter.try_rename(GuildId(1), start).await);
+        }
+        assert!(!limiter.try_rename(GuildId(1), start).await);
+        // other guilds have their own limit
+        assert!(limiter.try_rename(GuildId(2), start).await);
+
+        assert!(limiter.try_rename(GuildId(1), start + RENAME_WINDOW).await);
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+use twilight_model::id::{ChannelId, GuildId, MessageId, UserId};
+
+use crate::core::guild_config::{SpamBucket, SpamRule};
+use crate::utils::matchers;
+
+/// Nothing is remembered for longer than this, no matter how long the period of a rule is.
+pub const MAX_SPAM_PERIOD: u32 = 60 * 60;
+/// How often we forget about the users that went quiet.
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// What a single message puts into the buckets.
+#[derive(Debug, Clone)]
+pub struct SpamEntry {
+    pub message_id: MessageId,
+    pub channel_id: ChannelId,
+    pub received: Instant,
+    /// Hash of the content, messages without any don't count as duplicates of each other.
+    content_hash: Option<u64>,
+    mentions: u32,
+    emoji: u32,
+    newlines: u32,
+    links: u32,
+    attachments: u32,
+}
+
+impl SpamEntry {
+    pub fn new(
+        message_id: MessageId,
+        channel_id: ChannelId,
+        content: &str,
+        attachments: usize,
+        received: Instant,
+    ) -> Self {
+        let content_hash = if content.is_empty() {
+            None
+        } else {
+            let mut hasher = DefaultHasher::new();
+            content.hash(&mut hasher);
+            Some(hasher.finish())
+        };
+
+        SpamEntry {
+            message_id,
+            channel_id,
+            received,
+            content_hash,
+            mentions: matchers::count_mentions(content) as u32,
+            emoji: matchers::get_emoji_parts(content).len() as u32,
+            newlines: content.matches('\n').count() as u32,
+            links: matchers::get_urls(content).len() as u32,
+            attachments: attachments as u32,
+        }
+    }
+
+    fn amount(&self, bucket: SpamBucket) -> u32 {
+        match bucket {
+            SpamBucket::Messages | SpamBucket::DuplicateMessages => 1,
+            SpamBucket::Mentions => self.mentions,
+            SpamBucket::Emoji => self.emoji,
+            SpamBucket::Newlines => self.newlines,
+            SpamBucket::Links => self.links,
+            SpamBucket::Attachments => self.attachments,
+        }
+    }
+}
+
+/// A rule that got broken, together with the messages that filled up its bucket.
+#[derive(Debug)]
+pub struct SpamViolation {
+    pub rule: SpamRule,
+    pub amount: u32,
+    pub messages: Vec<(ChannelId, MessageId)>,
+}
+
+struct BucketState {
+    users: HashMap<(GuildId, UserId), VecDeque<SpamEntry>>,
+    last_cleanup: Instant,
+}
+
+/// Keeps track of what everyone sent recently, so the spam rules can be checked against a sliding window.
+///
+/// This only lives in memory, after a restart everyone starts with empty buckets again.
+pub struct SpamBuckets {
+    state: Mutex<BucketState>,
+}
+
+impl Default for SpamBuckets {
+    fn default() -> Self {
+        SpamBuckets {
+            state: Mutex::new(BucketState {
+                users: HashMap::new(),
+                last_cleanup: Instant::now(),
+            }),
+        }
+    }
+}
+
+impl SpamBuckets {
+    /// Adds a new message to the buckets of the user and checks it against the rules.
+    ///
+    /// When a rule is broken the buckets of the user are emptied, so the same messages don't get punished twice.
+    pub async fn track(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        entry: SpamEntry,
+        rules: &[SpamRule],
+    ) -> Option<SpamViolation> {
+        let now = entry.received;
+        let mut state = self.state.lock().await;
+
+        if now.duration_since(state.last_cleanup) >= CLEANUP_INTERVAL {
+            let max_period = Duration::from_secs(MAX_SPAM_PERIOD as u64);
+            state.users.retain(|_, entries| {
+                entries
+                    .back()
+                    .map_or(false, |newest| now.duration_since(newest.received) < max_period)
+            });
+            state.last_cleanup = now;
+        }
+
+        let longest = rules
+            .iter()
+            .map(|rule| rule.period.min(MAX_SPAM_PERIOD))
+            .max()
+            .unwrap_or(0);
+        let longest = Duration::from_secs(longest as u64);
+
+        let key = (guild_id, user_id);
+        let entries = state.users.entry(key).or_default();
+        entries.push_back(entry);
+        while entries
+            .front()
+            .map_or(false, |oldest| now.duration_since(oldest.received) > longest)
+        {
+            entries.pop_front();
+        }
+
+        let violation = find_violation(entries, rules, now);
+        if violation.is_some() {
+            state.users.remove(&key);
+        }
+
+        violation
+    }
+}
+
+/// Finds the first rule that is broken by the newest entry.
+fn find_violation(entries: &VecDeque<SpamEntry>, rules: &[SpamRule], now: Instant) -> Option<SpamViolation> {
+    let newest = entries.back()?;
+
+    rules.iter().filter(|rule| rule.count > 0).find_map(|rule| {
+        let period = Duration::from_secs(rule.period.min(MAX_SPAM_PERIOD) as u64);
+        let counted = entries
+            .iter()
+            .filter(|entry| now.duration_since(entry.received) <= period)
+            .filter(|entry| match rule.bucket {
+                SpamBucket::DuplicateMessages => {
+                    entry.content_hash.is_some() && entry.content_hash == newest.content_hash
+                }
+                bucket => entry.amount(bucket) > 0,
+            })
+            .collect::<Vec<_>>();
+
+        let amount = counted.iter().map(|entry| entry.amount(rule.bucket)).sum();
+        if amount < rule.count {
+            return None;
+        }
+
+        Some(SpamViolation {
+            rule: rule.clone(),
+            amount,
+            messages: counted
+                .iter()
+                .map(|entry| (entry.channel_id, entry.message_id))
+                .collect(),
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::guild_config::Punishment;
+
+    fn rule(bucket: SpamBucket, count: u32, period: u32) -> SpamRule {
+        SpamRule {
+            bucket,
+            count,
+            period,
+            punishment: Punishment::Delete,
+        }
+    }
+
+    fn entry(id: u64, content: &str, received: Instant) -> SpamEntry {
+        SpamEntry::new(MessageId(id), ChannelId(1), content, 0, received)
+    }
+
+    #[test]
+    fn buckets_only_count_within_the_period() {
+        let start = Instant::now();
+        let rules = [rule(SpamBucket::Messages, 3, 5)];
+
+        let mut entries = VecDeque::new();
+        entries.push_back(entry(1, "a", start));
+        entries.push_back(entry(2, "b", start + Duration::from_secs(4)));
+        entries.push_back(entry(3, "c", start + Duration::from_secs(8)));
+        assert!(find_violation(&entries, &rules, start + Duration::from_secs(8)).is_none());
+
+        entries.push_back(entry(4, "d", start + Duration::from_secs(9)));
+        let violation = find_violation(&entries, &rules, start + Duration::from_secs(9)).unwrap();
+        assert_eq!(violation.amount, 3);
+        assert_eq!(violation.messages.len(), 3);
+    }
+
+    #[test]
+    fn duplicates_and_mentions_are_counted() {
+        let now = Instant::now();
+        let rules = [
+            rule(SpamBucket::Mentions, 5, 10),
+            rule(SpamBucket::DuplicateMessages, 3, 10),
+        ];
+
+        let mut entries = VecDeque::new();
+        entries.push_back(entry(1, "hello", now));
+        entries.push_back(entry(2, "something else", now));
+        entries.push_back(entry(3, "hello", now));
+        assert!(find_violation(&entries, &rules, now).is_none());
+
+        entries.push_back(entry(4, "hello", now));
+        let violation = find_violation(&entries, &rules, now).unwrap();
+        assert_eq!(violation.rule.bucket, SpamBucket::DuplicateMessages);
+        assert_eq!(violation.messages.len(), 3);
+
+        let mut entries = VecDeque::new();
+        entries.push_back(entry(1, "<@1> <@!2> <@3>", now));
+        entries.push_back(entry(2, "<@4> <@5>", now));
+        let violation = find_violation(&entries, &rules, now).unwrap();
+        assert_eq!(violation.rule.bucket, SpamBucket::Mentions);
+        assert_eq!(violation.amount, 5);
+    }
+}
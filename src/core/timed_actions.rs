@@ -0,0 +1,182 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use hyper::StatusCode;
+use twilight_http::request::AuditLogReason;
+
+use crate::core::logpump::{LogData, LogType};
+use crate::core::BotContext;
+use crate::database::structures::{Infraction, InfractionType};
+use crate::error::OtherFailure;
+use crate::{gearbot_error, gearbot_warn};
+
+/// How many expired actions are picked up at once.
+const BATCH_SIZE: i64 = 50;
+/// Upper limit on how long the scheduler sleeps, as safety net in case a wakeup gets missed. Postponed actions are
+/// also picked up again after this at the latest.
+const MAX_SLEEP: Duration = Duration::from_secs(10 * 60);
+/// How long to wait before trying again when the database is having issues.
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Lifts tempbans and mutes once they expire.
+///
+/// All state lives in the database so nothing gets lost on restarts, and each cluster only picks up the
+/// actions for guilds on its own shards.
+pub async fn run(ctx: Arc<BotContext>) {
+    log::info!("Timed action scheduler started!");
+    let first_shard = ctx.scheme_info.cluster_id * ctx.scheme_info.shards_per_cluster;
+    let shards = first_shard..first_shard + ctx.scheme_info.shards_per_cluster;
+
+    loop {
+        let pending = match ctx
+            .datastore
+            .get_pending_actions(shards.clone(), ctx.scheme_info.total_shards, BATCH_SIZE)
+            .await
+        {
+            Ok(pending) => pending,
+            Err(e) => {
+                gearbot_error!("Failed to load the pending timed actions: {}", e);
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+
+        let now = Utc::now();
+        let mut next_end = None;
+        let mut handled = 0;
+        let mut failed = false;
+        for action in pending {
+            if let Some(end) = action.end.filter(|end| *end > now) {
+                next_end = Some(end);
+                break;
+            }
+
+            // actions that can't be carried out right now are put off, so they don't keep coming back in the batch
+            let id = action.id;
+            if let Err(e) = complete(&ctx, action).await {
+                gearbot_warn!(
+                    "Failed to complete the timed action for infraction {}, trying again later: {}",
+                    id,
+                    e
+                );
+                if let Err(e) = ctx.datastore.postpone_timed_action(id).await {
+                    gearbot_error!("Failed to postpone the timed action for infraction {}: {}", id, e);
+                    failed = true;
+                    break;
+                }
+            }
+            handled += 1;
+        }
+
+        if failed {
+            tokio::time::sleep(RETRY_DELAY).await;
+            continue;
+        }
+
+        if handled > 0 {
+            // there might be more that expired in the meantime
+            continue;
+        }
+
+        let sleep_for = match next_end {
+            Some(end) => (end - now).to_std().unwrap_or_default().min(MAX_SLEEP),
+            None => MAX_SLEEP,
+        };
+
+        tokio::select! {
+            _ = tokio::time::sleep(sleep_for) => {},
+            _ = ctx.timed_actions_changed() => {},
+        }
+    }
+}
+
+/// How lifting a punishment went, when it didn't run into an error that might go away on its own.
+enum Lift {
+    /// The punishment is gone, or there was nothing left to lift.
+    Done,
+    /// Discord won't let us, trying again won't change that.
+    Forbidden,
+}
+
+/// Lifts the punishment and marks the action as completed. Errors that might go away leave the action pending so it
+/// gets retried, it only counts as expired once the user is actually unbanned or unmuted. When the bot isn't allowed
+/// to lift it the action is completed anyway, and the guild is told to do it by hand.
+async fn complete(ctx: &Arc<BotContext>, action: Infraction) -> Result<(), OtherFailure> {
+    let infraction_id = action.id;
+    let log_type = match action.kind {
+        InfractionType::Tempban => match lift_ban(ctx, &action).await? {
+            Lift::Done => LogType::TempbanExpired { infraction_id },
+            Lift::Forbidden => LogType::TempbanLiftFailed { infraction_id },
+        },
+        InfractionType::Mute => match lift_mute(ctx, &action).await? {
+            Lift::Done => LogType::MuteExpired { infraction_id },
+            Lift::Forbidden => LogType::MuteLiftFailed { infraction_id },
+        },
+        other => {
+            gearbot_warn!(
+                "Infraction {} has a timed action but {} infractions can't expire, skipping it",
+                action.id,
+                other.get_name()
+            );
+            ctx.datastore.complete_timed_action(action.id).await?;
+            return Ok(());
+        }
+    };
+
+    ctx.datastore.complete_timed_action(action.id).await?;
+
+    ctx.log(LogData {
+        log_type,
+        guild: action.guild_id,
+        source_channel: None,
+        source_user: action.user_id,
+    });
+
+    Ok(())
+}
+
+async fn lift_ban(ctx: &Arc<BotContext>, action: &Infraction) -> Result<Lift, OtherFailure> {
+    let result = ctx
+        .http
+        .delete_ban(action.guild_id, action.user_id)
+        .reason(format!("Tempban expired (infraction {})", action.id))
+        .map_err(OtherFailure::AuditLogReason)?
+        .await;
+
+    lift_result(result)
+}
+
+async fn lift_mute(ctx: &Arc<BotContext>, action: &Infraction) -> Result<Lift, OtherFailure> {
+    let config = ctx
+        .get_config(action.guild_id)
+        .await
+        .map_err(OtherFailure::DatabaseError)?;
+
+    // without a mute role there is nothing to remove anymore
+    let role = match config.mute.role {
+        Some(role) => role,
+        None => return Ok(Lift::Done),
+    };
+
+    let result = ctx
+        .http
+        .remove_guild_member_role(action.guild_id, action.user_id, role)
+        .reason(format!("Mute expired (infraction {})", action.id))
+        .map_err(OtherFailure::AuditLogReason)?
+        .await;
+
+    lift_result(result)
+}
+
+/// Discord answers with a 404 when the ban, member or mute role is already gone, in which case there is nothing to
+/// lift anymore, and a 403 when the bot lost its permissions or access to the guild. Anything else might work on the
+/// next attempt.
+fn lift_result<T>(result: Result<T, twilight_http::Error>) -> Result<Lift, OtherFailure> {
+    match result {
+        Ok(_) => Ok(Lift::Done),
+        Err(twilight_http::Error::Response { status, .. }) if status == StatusCode::NOT_FOUND => Ok(Lift::Done),
+        Err(twilight_http::Error::Response { status, .. }) if status == StatusCode::FORBIDDEN => Ok(Lift::Forbidden),
+        Err(e) => Err(OtherFailure::TwilightHttp(e)),
+    }
+}
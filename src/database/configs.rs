@@ -1,29 +1,56 @@
+use std::ops::Range;
+
 use log::info;
+use twilight_model::id::{GuildId, UserId};
 
+use super::structures::{ConfigRevision, ConfigSource, StoredConfigRevision};
 use super::{crypto, DataStorage};
-use crate::core::GuildConfig;
+use crate::core::{config_migrations, GuildConfig};
 use crate::error::DatabaseError;
 
+/// How many config revisions are returned per page when listing them.
+pub const CONFIG_REVISIONS_PER_PAGE: i64 = 10;
+
 impl DataStorage {
     /// Fetches a guild configuration from the database, returning it if it existed.
     ///
-    /// The permissions inside the config are guaranteed to be in the correct order.
-    pub async fn get_guild_config(&self, guild_id: u64) -> Result<Option<GuildConfig>, DatabaseError> {
+    /// Configs stored in an older format are upgraded and written back, the bot user is recorded as the author of
+    /// that. The permissions inside the config are guaranteed to be in the correct order.
+    pub async fn get_guild_config(&self, guild_id: u64, bot_id: UserId) -> Result<Option<GuildConfig>, DatabaseError> {
+        Ok(self
+            .load_guild_config(guild_id, bot_id)
+            .await?
+            .map(|(config, _)| config))
+    }
+
+    /// Does the work for `get_guild_config`, but also tells if the config had to be migrated.
+    pub async fn load_guild_config(
+        &self,
+        guild_id: u64,
+        bot_id: UserId,
+    ) -> Result<Option<(GuildConfig, bool)>, DatabaseError> {
         let row: Option<(serde_json::Value,)> = sqlx::query_as("SELECT config from guildconfig where id=$1")
             .bind(guild_id as i64)
             .fetch_optional(&self.persistent_pool)
             .await?;
 
-        let config = if let Some(c_val) = row {
-            let mut config: GuildConfig = serde_json::from_value(c_val.0).map_err(DatabaseError::Deserializing)?;
-            //CRITICAL: make sure permissions are propertly sorted
-            config.permission_groups.sort_by(|a, b| a.priority.cmp(&b.priority));
-            Some(config)
-        } else {
-            None
+        let mut c_val = match row {
+            Some((c_val,)) => c_val,
+            None => return Ok(None),
         };
 
-        Ok(config)
+        let migrated = config_migrations::migrate(&mut c_val)?;
+        let mut config: GuildConfig = serde_json::from_value(c_val).map_err(DatabaseError::Deserializing)?;
+        //CRITICAL: make sure permissions are propertly sorted
+        config.permission_groups.sort_by(|a, b| a.priority.cmp(&b.priority));
+
+        if migrated {
+            info!("Migrated the config of {} to version {}", guild_id, config.version);
+            self.set_guild_config(guild_id, &config, bot_id, ConfigSource::Migration)
+                .await?;
+        }
+
+        Ok(Some((config, migrated)))
     }
 
     /// Creates a new guild configuration for the specified guild and inserts it into the database.
@@ -44,16 +71,106 @@ impl DataStorage {
         Ok(new_config)
     }
 
-    /// Updates a guild config for the specified guild with the provided new value.
+    /// Updates a guild config for the specified guild with the provided new value. The config it replaces is kept in
+    /// the history, the revision it got there is returned.
     ///
     /// Errors if the guild doesn't exist already.
-    pub async fn set_guild_config(&self, guild_id: u64, config: &GuildConfig) -> Result<(), DatabaseError> {
+    pub async fn set_guild_config(
+        &self,
+        guild_id: u64,
+        config: &GuildConfig,
+        author: UserId,
+        source: ConfigSource,
+    ) -> Result<i32, DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        // Lock the row so two edits at the same time can't both claim the same old config
+        let (old,): (serde_json::Value,) = sqlx::query_as("SELECT config from guildconfig where id=$1 FOR UPDATE")
+            .bind(guild_id as i64)
+            .fetch_one(&mut transaction)
+            .await?;
+
+        let (revision,): (i32,) = sqlx::query_as(
+            "INSERT INTO guildconfig_history (guild_id, revision, config, author_id, source)
+            SELECT $1, coalesce(max(revision), 0) + 1, $2, $3, $4 from guildconfig_history where guild_id=$1
+            RETURNING revision",
+        )
+        .bind(guild_id as i64)
+        .bind(old)
+        .bind(author.0 as i64)
+        .bind(source)
+        .fetch_one(&mut transaction)
+        .await?;
+
         sqlx::query("UPDATE guildconfig set config=$1 WHERE id=$2")
             .bind(serde_json::to_value(config).map_err(DatabaseError::Serializing)?)
             .bind(guild_id as i64)
-            .execute(&self.persistent_pool)
+            .execute(&mut transaction)
             .await?;
 
-        Ok(())
+        transaction.commit().await?;
+
+        Ok(revision)
+    }
+
+    /// Lists the config revisions of a guild, newest first.
+    ///
+    /// Pages start at 0 and hold up to `CONFIG_REVISIONS_PER_PAGE` entries.
+    pub async fn get_config_revisions(
+        &self,
+        guild_id: GuildId,
+        page: u32,
+    ) -> Result<Vec<ConfigRevision>, DatabaseError> {
+        let stored: Vec<StoredConfigRevision> = sqlx::query_as(
+            "SELECT * from guildconfig_history where guild_id=$1 ORDER BY revision DESC LIMIT $2 OFFSET $3",
+        )
+        .bind(guild_id.0 as i64)
+        .bind(CONFIG_REVISIONS_PER_PAGE)
+        .bind(page as i64 * CONFIG_REVISIONS_PER_PAGE)
+        .fetch_all(&self.persistent_pool)
+        .await?;
+
+        Ok(stored.into_iter().map(ConfigRevision::from).collect())
+    }
+
+    /// Fetches a single config revision of a guild, if it exists.
+    pub async fn get_config_revision(
+        &self,
+        guild_id: GuildId,
+        revision: i32,
+    ) -> Result<Option<ConfigRevision>, DatabaseError> {
+        let stored: Option<StoredConfigRevision> =
+            sqlx::query_as("SELECT * from guildconfig_history where guild_id=$1 and revision=$2")
+                .bind(guild_id.0 as i64)
+                .bind(revision)
+                .fetch_optional(&self.persistent_pool)
+                .await?;
+
+        Ok(stored.map(ConfigRevision::from))
+    }
+
+    /// Reads how long a guild keeps its stored messages straight from its stored config, without loading (or migrating)
+    /// the whole thing. `None` if the guild has no config or it doesn't say, the default applies then.
+    pub async fn get_message_retention(&self, guild_id: GuildId) -> Result<Option<u32>, DatabaseError> {
+        let row: Option<(Option<i32>,)> =
+            sqlx::query_as("SELECT (config->'message_logs'->>'retention_days')::int from guildconfig where id=$1")
+                .bind(guild_id.0 as i64)
+                .fetch_optional(&self.persistent_pool)
+                .await?;
+
+        Ok(row.and_then(|(retention,)| retention).map(|retention| retention as u32))
+    }
+
+    /// Lists the guilds that have a config and live on one of the given shards.
+    pub async fn get_guild_ids(&self, shards: Range<u64>, total_shards: u64) -> Result<Vec<GuildId>, DatabaseError> {
+        let ids: Vec<(i64,)> =
+            sqlx::query_as("SELECT id from guildconfig where (id >> 22) % $1 >= $2 and (id >> 22) % $1 < $3")
+                .bind(total_shards as i64)
+                .bind(shards.start as i64)
+                .bind(shards.end as i64)
+                .fetch_all(&self.persistent_pool)
+                .await?;
+
+        Ok(ids.into_iter().map(|(id,)| GuildId(id as u64)).collect())
     }
 }
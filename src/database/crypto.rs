@@ -40,30 +40,41 @@ pub fn generate_guild_encryption_key(main_encryption_key: &EncryptionKey, guild_
 }
 
 pub fn encrypt_bytes(plaintext: &[u8], key: &EncryptionKey, msg_id: u64) -> Vec<u8> {
-    let aead = Aes256Gcm::new(&key.0);
+    encrypt_revision_bytes(plaintext, key, msg_id, 0)
+}
 
-    // Since nonce's only never need to be reused, and Discor's snowflakes for messages
-    // are unique, we can use the messasge id to construct the nonce with its 64 bits, and then
-    // pad the rest with zeros.
-    let mut nonce_bytes = [0u8; 12];
-    let msg_id_bytes = msg_id.to_le_bytes();
-    nonce_bytes[..8].copy_from_slice(&msg_id_bytes);
-    nonce_bytes[8..].copy_from_slice(&[0u8; 4]);
+pub fn decrypt_bytes(ciphertext: &[u8], key: &EncryptionKey, msg_id: u64) -> Vec<u8> {
+    decrypt_revision_bytes(ciphertext, key, msg_id, 0)
+}
+
+/// Encrypts a new revision of an object that was encrypted before, like an edited message.
+///
+/// Every revision needs its own nonce, reusing the one of the original would leak the key stream.
+pub fn encrypt_revision_bytes(plaintext: &[u8], key: &EncryptionKey, msg_id: u64, revision: u32) -> Vec<u8> {
+    let aead = Aes256Gcm::new(&key.0);
 
+    let nonce_bytes = construct_nonce(msg_id, revision);
     let nonce = GenericArray::from_slice(&nonce_bytes);
 
     aead.encrypt(&nonce, plaintext).expect("Failed to encrypt an object!")
 }
 
-pub fn decrypt_bytes(ciphertext: &[u8], key: &EncryptionKey, msg_id: u64) -> Vec<u8> {
+pub fn decrypt_revision_bytes(ciphertext: &[u8], key: &EncryptionKey, msg_id: u64, revision: u32) -> Vec<u8> {
     let aead = Aes256Gcm::new(&key.0);
 
-    let mut nonce_bytes = [0u8; 12];
-    let msg_id_bytes = msg_id.to_le_bytes();
-    nonce_bytes[..8].copy_from_slice(&msg_id_bytes);
-    nonce_bytes[8..].copy_from_slice(&[0u8; 4]);
-
+    let nonce_bytes = construct_nonce(msg_id, revision);
     let nonce = GenericArray::from_slice(&nonce_bytes);
 
     aead.decrypt(&nonce, ciphertext).expect("Failed to decrypt an object!")
 }
+
+fn construct_nonce(msg_id: u64, revision: u32) -> [u8; 12] {
+    // Since nonce's only never need to be reused, and Discor's snowflakes for messages
+    // are unique, we can use the messasge id to construct the nonce with its 64 bits, and then
+    // fill the rest with the revision, which is zero for anything that never gets re-encrypted.
+    let mut nonce_bytes = [0u8; 12];
+    nonce_bytes[..8].copy_from_slice(&msg_id.to_le_bytes());
+    nonce_bytes[8..].copy_from_slice(&revision.to_le_bytes());
+
+    nonce_bytes
+}
@@ -0,0 +1,70 @@
+use twilight_model::id::GuildId;
+
+use super::structures::CustomCommand;
+use super::DataStorage;
+use crate::error::DatabaseError;
+
+impl DataStorage {
+    /// Lists all custom commands of a guild, sorted by their trigger.
+    pub async fn get_custom_commands(&self, guild_id: GuildId) -> Result<Vec<CustomCommand>, DatabaseError> {
+        let commands = sqlx::query_as("SELECT trigger, response from customCommand where guild_id=$1 ORDER BY trigger")
+            .bind(guild_id.0 as i64)
+            .fetch_all(&self.persistent_pool)
+            .await?;
+
+        Ok(commands)
+    }
+
+    /// Adds a custom command to a guild.
+    ///
+    /// Returns `false` if the guild already has a command with that trigger.
+    pub async fn insert_custom_command(
+        &self,
+        guild_id: GuildId,
+        trigger: &str,
+        response: &str,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query(
+            "INSERT INTO customCommand (guild_id, trigger, response) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+        )
+        .bind(guild_id.0 as i64)
+        .bind(trigger)
+        .bind(response)
+        .execute(&self.persistent_pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Replaces the response of a custom command.
+    ///
+    /// Returns `false` if the guild has no command with that trigger.
+    pub async fn update_custom_command(
+        &self,
+        guild_id: GuildId,
+        trigger: &str,
+        response: &str,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("UPDATE customCommand set response=$1 WHERE guild_id=$2 and trigger=$3")
+            .bind(response)
+            .bind(guild_id.0 as i64)
+            .bind(trigger)
+            .execute(&self.persistent_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Removes a custom command.
+    ///
+    /// Returns `false` if the guild has no command with that trigger.
+    pub async fn delete_custom_command(&self, guild_id: GuildId, trigger: &str) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("DELETE FROM customCommand WHERE guild_id=$1 and trigger=$2")
+            .bind(guild_id.0 as i64)
+            .bind(trigger)
+            .execute(&self.persistent_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
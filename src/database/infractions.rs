@@ -0,0 +1,242 @@
+use std::ops::Range;
+
+use chrono::{DateTime, Utc};
+use twilight_model::id::{GuildId, UserId};
+
+use super::structures::{Infraction, InfractionType, StoredInfraction};
+use super::DataStorage;
+use crate::error::DatabaseError;
+
+/// How many infractions are returned per page when listing them.
+pub const INFRACTIONS_PER_PAGE: i64 = 10;
+
+impl DataStorage {
+    /// Records a new infraction in the history of a guild, returning the stored version of it.
+    pub async fn insert_infraction(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        mod_id: UserId,
+        kind: InfractionType,
+        reason: &str,
+        end: Option<DateTime<Utc>>,
+    ) -> Result<Infraction, DatabaseError> {
+        let stored: StoredInfraction = sqlx::query_as(
+            "INSERT INTO history (guild_id, user_id, mod_id, type, \"end\", reason)
+            VALUES ($1, $2, $3, $4, $5, $6) RETURNING *",
+        )
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .bind(mod_id.0 as i64)
+        .bind(kind)
+        .bind(end)
+        .bind(reason)
+        .fetch_one(&self.persistent_pool)
+        .await?;
+
+        Ok(stored.into())
+    }
+
+    /// Fetches a single infraction, if it exists and belongs to the specified guild.
+    pub async fn get_infraction(&self, guild_id: GuildId, id: i32) -> Result<Option<Infraction>, DatabaseError> {
+        let stored: Option<StoredInfraction> = sqlx::query_as("SELECT * from history where id=$1 and guild_id=$2")
+            .bind(id)
+            .bind(guild_id.0 as i64)
+            .fetch_optional(&self.persistent_pool)
+            .await?;
+
+        Ok(stored.map(Infraction::from))
+    }
+
+    /// Lists all infractions of a guild, newest first.
+    ///
+    /// Pages start at 0 and hold up to `INFRACTIONS_PER_PAGE` entries.
+    pub async fn get_infractions(&self, guild_id: GuildId, page: u32) -> Result<Vec<Infraction>, DatabaseError> {
+        let stored: Vec<StoredInfraction> =
+            sqlx::query_as("SELECT * from history where guild_id=$1 ORDER BY id DESC LIMIT $2 OFFSET $3")
+                .bind(guild_id.0 as i64)
+                .bind(INFRACTIONS_PER_PAGE)
+                .bind(page as i64 * INFRACTIONS_PER_PAGE)
+                .fetch_all(&self.persistent_pool)
+                .await?;
+
+        Ok(stored.into_iter().map(Infraction::from).collect())
+    }
+
+    /// Lists the infractions a user received in a guild, newest first.
+    pub async fn get_user_infractions(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        page: u32,
+    ) -> Result<Vec<Infraction>, DatabaseError> {
+        let stored: Vec<StoredInfraction> = sqlx::query_as(
+            "SELECT * from history where guild_id=$1 and user_id=$2 ORDER BY id DESC LIMIT $3 OFFSET $4",
+        )
+        .bind(guild_id.0 as i64)
+        .bind(user_id.0 as i64)
+        .bind(INFRACTIONS_PER_PAGE)
+        .bind(page as i64 * INFRACTIONS_PER_PAGE)
+        .fetch_all(&self.persistent_pool)
+        .await?;
+
+        Ok(stored.into_iter().map(Infraction::from).collect())
+    }
+
+    /// Lists the infractions a moderator handed out in a guild, newest first.
+    pub async fn get_mod_infractions(
+        &self,
+        guild_id: GuildId,
+        mod_id: UserId,
+        page: u32,
+    ) -> Result<Vec<Infraction>, DatabaseError> {
+        let stored: Vec<StoredInfraction> =
+            sqlx::query_as("SELECT * from history where guild_id=$1 and mod_id=$2 ORDER BY id DESC LIMIT $3 OFFSET $4")
+                .bind(guild_id.0 as i64)
+                .bind(mod_id.0 as i64)
+                .bind(INFRACTIONS_PER_PAGE)
+                .bind(page as i64 * INFRACTIONS_PER_PAGE)
+                .fetch_all(&self.persistent_pool)
+                .await?;
+
+        Ok(stored.into_iter().map(Infraction::from).collect())
+    }
+
+    /// Replaces the reason of an infraction.
+    ///
+    /// Returns `false` if there was no such infraction in this guild.
+    pub async fn update_infraction_reason(
+        &self,
+        guild_id: GuildId,
+        id: i32,
+        reason: &str,
+    ) -> Result<bool, DatabaseError> {
+        let result = sqlx::query("UPDATE history set reason=$1 WHERE id=$2 and guild_id=$3")
+            .bind(reason)
+            .bind(id)
+            .bind(guild_id.0 as i64)
+            .execute(&self.persistent_pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Removes an infraction, together with any timed action that was still attached to it.
+    ///
+    /// Returns `false` if there was no such infraction in this guild.
+    pub async fn delete_infraction(&self, guild_id: GuildId, id: i32) -> Result<bool, DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        sqlx::query("DELETE FROM timedAction where history_id in (SELECT id from history where id=$1 and guild_id=$2)")
+            .bind(id)
+            .bind(guild_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM history where id=$1 and guild_id=$2")
+            .bind(id)
+            .bind(guild_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Attaches a timed action to an infraction, so the scheduler lifts it again once its end has passed.
+    pub async fn add_timed_action(&self, infraction_id: i32) -> Result<(), DatabaseError> {
+        sqlx::query("INSERT INTO timedAction (history_id) VALUES ($1)")
+            .bind(infraction_id)
+            .execute(&self.persistent_pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Lists the infractions with timed actions that still need to be lifted, the ones ending first come first.
+    /// Actions that failed before are left out until their next attempt is due.
+    ///
+    /// Only guilds living on one of the given shards are included, so clusters never act on each others guilds.
+    pub async fn get_pending_actions(
+        &self,
+        shards: Range<u64>,
+        total_shards: u64,
+        limit: i64,
+    ) -> Result<Vec<Infraction>, DatabaseError> {
+        let stored: Vec<StoredInfraction> = sqlx::query_as(
+            "SELECT p.* from pendingActions p inner join timedAction t on t.history_id = p.id
+            where p.\"end\" is not null and (t.next_attempt is null or t.next_attempt <= now())
+            and (p.guild_id >> 22) % $1 >= $2 and (p.guild_id >> 22) % $1 < $3 ORDER BY p.\"end\" LIMIT $4",
+        )
+        .bind(total_shards as i64)
+        .bind(shards.start as i64)
+        .bind(shards.end as i64)
+        .bind(limit)
+        .fetch_all(&self.persistent_pool)
+        .await?;
+
+        Ok(stored.into_iter().map(Infraction::from).collect())
+    }
+
+    /// Fetches the mute of a user that has not been lifted yet, if they have one.
+    pub async fn get_active_mute(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<Infraction>, DatabaseError> {
+        self.get_pending_action(guild_id, user_id, InfractionType::Mute).await
+    }
+
+    /// Fetches the tempban of a user that has not been lifted yet, if they have one.
+    pub async fn get_active_tempban(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<Option<Infraction>, DatabaseError> {
+        self.get_pending_action(guild_id, user_id, InfractionType::Tempban)
+            .await
+    }
+
+    async fn get_pending_action(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        kind: InfractionType,
+    ) -> Result<Option<Infraction>, DatabaseError> {
+        let stored: Option<StoredInfraction> =
+            sqlx::query_as("SELECT * from pendingActions where guild_id=$1 and user_id=$2 and type=$3 LIMIT 1")
+                .bind(guild_id.0 as i64)
+                .bind(user_id.0 as i64)
+                .bind(kind)
+                .fetch_optional(&self.persistent_pool)
+                .await?;
+
+        Ok(stored.map(Infraction::from))
+    }
+
+    /// Puts off the next attempt at a timed action that couldn't be carried out, the wait doubles after every failure
+    /// up to a few hours.
+    pub async fn postpone_timed_action(&self, infraction_id: i32) -> Result<(), DatabaseError> {
+        sqlx::query(
+            "UPDATE timedAction set attempts=attempts + 1,
+            next_attempt=now() + least(interval '30 seconds' * power(2, least(attempts, 10)), interval '6 hours')
+            where history_id=$1",
+        )
+        .bind(infraction_id)
+        .execute(&self.persistent_pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Marks the timed action of an infraction as carried out.
+    pub async fn complete_timed_action(&self, infraction_id: i32) -> Result<(), DatabaseError> {
+        sqlx::query("UPDATE timedAction set completed=true where history_id=$1")
+            .bind(infraction_id)
+            .execute(&self.persistent_pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,9 @@
 pub mod configs;
 
+pub mod custom_commands;
+
 mod crypto;
+pub mod infractions;
 use crypto::EncryptionKey;
 
 pub mod redis;
@@ -8,6 +11,7 @@ pub mod redis;
 pub mod structures;
 use structures::{StoredUserMessage, UserMessage};
 
+use std::collections::HashMap;
 use twilight_model::channel::{Attachment, Message};
 use twilight_model::id::{ChannelId, GuildId, MessageId, UserId, WebhookId};
 
@@ -137,21 +141,19 @@ impl DataStorage {
 
         let user_msg = match stored_message {
             Some(sm) => {
-                let start = std::time::Instant::now();
-
                 let guild_key = self.get_guild_encryption_key(guild_id).await?;
-                let decrypted_content = crypto::decrypt_bytes(&sm.encrypted_content, &guild_key, message_id.0);
-
-                log::debug!("It took {}us to decrypt a user message!", start.elapsed().as_micros());
-
-                Some(UserMessage {
-                    content: String::from_utf8(decrypted_content).unwrap(),
-                    author: UserId(sm.author_id as u64),
-                    channel: ChannelId(sm.channel_id as u64),
-                    guild: GuildId(sm.guild_id as u64),
-                    kind: sm.kind(),
-                    pinned: sm.pinned,
-                })
+
+                let attachments: Vec<(String,)> =
+                    sqlx::query_as("SELECT name from attachment where message_id=$1 ORDER BY id")
+                        .bind(message_id.0 as i64)
+                        .fetch_all(&self.persistent_pool)
+                        .await?;
+
+                Some(decrypt_message(
+                    sm,
+                    &guild_key,
+                    attachments.into_iter().map(|(name,)| name).collect(),
+                ))
             }
             None => None,
         };
@@ -159,6 +161,160 @@ impl DataStorage {
         Ok(user_msg)
     }
 
+    /// Retrieves all messages out of a list that were stored, oldest first.
+    ///
+    /// Just like with `get_full_message`, they all *must* have been recieved in the provided guild.
+    pub async fn get_full_messages(
+        &self,
+        message_ids: &[MessageId],
+        guild_id: GuildId,
+    ) -> Result<Vec<UserMessage>, DatabaseError> {
+        let ids: Vec<i64> = message_ids.iter().map(|id| id.0 as i64).collect();
+
+        let stored_messages: Vec<StoredUserMessage> =
+            sqlx::query_as("SELECT * from message where id = ANY($1) ORDER BY id")
+                .bind(&ids)
+                .fetch_all(&self.persistent_pool)
+                .await?;
+
+        if stored_messages.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let stored_attachments: Vec<(i64, String)> =
+            sqlx::query_as("SELECT message_id, name from attachment where message_id = ANY($1) ORDER BY id")
+                .bind(&ids)
+                .fetch_all(&self.persistent_pool)
+                .await?;
+
+        let mut attachments: HashMap<i64, Vec<String>> = HashMap::new();
+        for (message_id, name) in stored_attachments {
+            attachments.entry(message_id).or_default().push(name);
+        }
+
+        let guild_key = self.get_guild_encryption_key(guild_id).await?;
+
+        Ok(stored_messages
+            .into_iter()
+            .map(|sm| {
+                let message_attachments = attachments.remove(&sm.id).unwrap_or_default();
+                decrypt_message(sm, &guild_key, message_attachments)
+            })
+            .collect())
+    }
+
+    /// Replaces the stored content of a message with its edited version.
+    ///
+    /// Every edit gets encrypted as a new revision, so the message never reuses a nonce.
+    pub async fn update_message_content(
+        &self,
+        message_id: MessageId,
+        guild_id: GuildId,
+        content: &str,
+    ) -> Result<(), DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        let edits: Option<(i32,)> = sqlx::query_as("SELECT edits from message where id=$1 FOR UPDATE")
+            .bind(message_id.0 as i64)
+            .fetch_optional(&mut transaction)
+            .await?;
+
+        let revision = match edits {
+            Some((edits,)) => edits + 1,
+            None => return Ok(()),
+        };
+
+        let guild_key = self.get_guild_encryption_key(guild_id).await?;
+        let ciphertext = crypto::encrypt_revision_bytes(content.as_bytes(), &guild_key, message_id.0, revision as u32);
+
+        sqlx::query("UPDATE message set encrypted_content=$1, edits=$2 where id=$3")
+            .bind(ciphertext)
+            .bind(revision)
+            .bind(message_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    /// Forgets about a single stored message and its attachments.
+    pub async fn delete_message(&self, message_id: MessageId) -> Result<(), DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        sqlx::query("DELETE FROM attachment where message_id=$1")
+            .bind(message_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        sqlx::query("DELETE FROM message where id=$1")
+            .bind(message_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
+    /// Removes up to `limit` messages of a guild that were sent before the given message id, together with their attachments.
+    ///
+    /// Returns how many messages were removed, the caller should keep going until this returns less then the limit.
+    pub async fn prune_messages(&self, guild_id: GuildId, before: MessageId, limit: i64) -> Result<u64, DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        let ids: Vec<(i64,)> = sqlx::query_as("SELECT id from message where guild_id=$1 and id < $2 LIMIT $3")
+            .bind(guild_id.0 as i64)
+            .bind(before.0 as i64)
+            .bind(limit)
+            .fetch_all(&mut transaction)
+            .await?;
+        let ids: Vec<i64> = ids.into_iter().map(|(id,)| id).collect();
+
+        sqlx::query("DELETE FROM attachment where message_id = ANY($1)")
+            .bind(&ids)
+            .execute(&mut transaction)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM message where id = ANY($1)")
+            .bind(&ids)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Removes all stored messages and attachments of a guild, for when the bot is no longer in it.
+    pub async fn purge_guild_messages(&self, guild_id: GuildId) -> Result<u64, DatabaseError> {
+        let mut transaction = self.persistent_pool.begin().await?;
+
+        sqlx::query("DELETE FROM attachment where message_id in (SELECT id from message where guild_id=$1)")
+            .bind(guild_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        let result = sqlx::query("DELETE FROM message where guild_id=$1")
+            .bind(guild_id.0 as i64)
+            .execute(&mut transaction)
+            .await?;
+
+        transaction.commit().await?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Gets the estimated amount of stored messages from the table statistics, counting them all would be way too slow.
+    pub async fn estimate_stored_messages(&self) -> Result<i64, DatabaseError> {
+        let estimate: (i64,) = sqlx::query_as("SELECT reltuples::bigint from pg_class where relname='message'")
+            .fetch_one(&self.persistent_pool)
+            .await?;
+
+        Ok(estimate.0)
+    }
+
     pub async fn get_webhook_parts(&self, channel_id: ChannelId) -> Result<Option<(WebhookId, String)>, DatabaseError> {
         let data: Option<WebhookInfo> = sqlx::query_as("SELECT * from webhook where channel_id=$1")
             .bind(channel_id.0 as i64)
@@ -211,3 +367,27 @@ impl DataStorage {
         Ok(guild_key)
     }
 }
+
+fn decrypt_message(stored: StoredUserMessage, guild_key: &EncryptionKey, attachments: Vec<String>) -> UserMessage {
+    let start = std::time::Instant::now();
+
+    let decrypted_content = crypto::decrypt_revision_bytes(
+        &stored.encrypted_content,
+        guild_key,
+        stored.id as u64,
+        stored.edits as u32,
+    );
+
+    log::debug!("It took {}us to decrypt a user message!", start.elapsed().as_micros());
+
+    UserMessage {
+        id: MessageId(stored.id as u64),
+        content: String::from_utf8(decrypted_content).unwrap(),
+        author: UserId(stored.author_id as u64),
+        channel: ChannelId(stored.channel_id as u64),
+        guild: GuildId(stored.guild_id as u64),
+        kind: stored.kind(),
+        pinned: stored.pinned,
+        attachments,
+    }
+}
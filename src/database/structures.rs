@@ -1,14 +1,18 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use twilight_model::channel::message::MessageType;
-use twilight_model::id::{ChannelId, GuildId, UserId};
+use twilight_model::id::{ChannelId, GuildId, MessageId, UserId};
 
 #[derive(Debug)]
 pub struct UserMessage {
+    pub id: MessageId,
     pub content: String,
     pub author: UserId,
     pub channel: ChannelId,
     pub guild: GuildId,
     pub kind: MessageType,
     pub pinned: bool,
+    pub attachments: Vec<String>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -20,6 +24,7 @@ pub(super) struct StoredUserMessage {
     pub guild_id: i64,
     pub kind: i16,
     pub pinned: bool,
+    pub edits: i32,
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -30,6 +35,139 @@ pub struct StoredAttachment {
     message_id: i64,
 }
 
+/// A trigger a guild set up, and what the bot answers with when a message starts with it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct CustomCommand {
+    pub trigger: String,
+    pub response: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type)]
+#[sqlx(type_name = "historytype", rename_all = "lowercase")]
+pub enum InfractionType {
+    Note,
+    Warning,
+    Censor,
+    Mute,
+    Kick,
+    Cleankick,
+    Tempban,
+    Ban,
+    Forceban,
+    Unban,
+}
+
+impl InfractionType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            InfractionType::Note => "note",
+            InfractionType::Warning => "warning",
+            InfractionType::Censor => "censor",
+            InfractionType::Mute => "mute",
+            InfractionType::Kick => "kick",
+            InfractionType::Cleankick => "cleankick",
+            InfractionType::Tempban => "tempban",
+            InfractionType::Ban => "ban",
+            InfractionType::Forceban => "forceban",
+            InfractionType::Unban => "unban",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Infraction {
+    pub id: i32,
+    pub guild_id: GuildId,
+    pub user_id: UserId,
+    pub mod_id: UserId,
+    pub kind: InfractionType,
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    pub reason: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub(super) struct StoredInfraction {
+    pub id: i32,
+    pub guild_id: i64,
+    pub user_id: i64,
+    pub mod_id: i64,
+    #[sqlx(rename = "type")]
+    pub kind: InfractionType,
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+    pub reason: String,
+}
+
+impl From<StoredInfraction> for Infraction {
+    fn from(stored: StoredInfraction) -> Self {
+        Infraction {
+            id: stored.id,
+            guild_id: GuildId(stored.guild_id as u64),
+            user_id: UserId(stored.user_id as u64),
+            mod_id: UserId(stored.mod_id as u64),
+            kind: stored.kind,
+            start: stored.start,
+            end: stored.end,
+            reason: stored.reason,
+        }
+    }
+}
+
+/// How a config change was made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, sqlx::Type)]
+#[sqlx(type_name = "configsource", rename_all = "lowercase")]
+pub enum ConfigSource {
+    Command,
+    Api,
+    Migration,
+}
+
+impl ConfigSource {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ConfigSource::Command => "command",
+            ConfigSource::Api => "api",
+            ConfigSource::Migration => "migration",
+        }
+    }
+}
+
+/// A config as it was before it got replaced by `author`, the config itself is kept as stored so older revisions
+/// can still be looked at after the config format changed.
+#[derive(Debug)]
+pub struct ConfigRevision {
+    pub revision: i32,
+    pub guild_id: GuildId,
+    pub config: serde_json::Value,
+    pub author: UserId,
+    pub source: ConfigSource,
+    pub replaced: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub(super) struct StoredConfigRevision {
+    pub guild_id: i64,
+    pub revision: i32,
+    pub config: serde_json::Value,
+    pub author_id: i64,
+    pub source: ConfigSource,
+    pub replaced: DateTime<Utc>,
+}
+
+impl From<StoredConfigRevision> for ConfigRevision {
+    fn from(stored: StoredConfigRevision) -> Self {
+        ConfigRevision {
+            revision: stored.revision,
+            guild_id: GuildId(stored.guild_id as u64),
+            config: stored.config,
+            author: UserId(stored.author_id as u64),
+            source: stored.source,
+            replaced: stored.replaced,
+        }
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct WebhookInfo {
     pub channel_id: i64,
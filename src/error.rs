@@ -7,10 +7,16 @@ use twilight_embed_builder::{
 };
 use twilight_gateway::cluster::{ClusterCommandError, ClusterStartError};
 use twilight_gateway::{cluster, shard};
+use twilight_http::request::applications::InteractionError;
 use twilight_http::request::channel::message::create_message::CreateMessageError;
+use twilight_http::request::channel::message::get_channel_messages::GetChannelMessagesError;
 use twilight_http::request::channel::message::update_message::UpdateMessageError;
+use twilight_http::request::guild::member::update_guild_member::UpdateGuildMemberError;
+use twilight_http::request::AuditLogReasonError;
 use twilight_model::id::{ChannelId, GuildId, UserId};
 
+use crate::core::config_validation::ConfigIssue;
+
 pub type CommandResult = Result<(), CommandError>;
 
 #[derive(Debug)]
@@ -23,6 +29,11 @@ pub enum StartupError {
     DarkRedis(darkredis::Error),
     ClusterStart(ClusterStartError),
     Io(io::Error),
+    Database(DatabaseError),
+    /// How many configs could not be migrated.
+    ConfigMigration(usize),
+    CommandRegistration(InteractionError),
+    NoApplicationId,
 }
 
 #[derive(Debug)]
@@ -72,6 +83,7 @@ pub enum EventHandlerError {
     Reactor(ReactorError),
     Database(DatabaseError),
     Twilight(twilight_http::Error),
+    Reply(CommandError),
 }
 impl error::Error for EventHandlerError {}
 
@@ -87,6 +99,7 @@ impl fmt::Display for EventHandlerError {
             EventHandlerError::Reactor(e) => write!(f, "Message reactor failure: {}", e),
             EventHandlerError::Database(e) => write!(f, "Database interaction failed: {}", e),
             EventHandlerError::Twilight(e) => write!(f, "Failed to interact with the discord api: {}", e),
+            EventHandlerError::Reply(e) => write!(f, "Failed to reply to a command: {}", e),
         }
     }
 }
@@ -147,6 +160,7 @@ pub enum DatabaseError {
     Deserializing(serde_json::Error),
     Serializing(serde_json::Error),
     Darkredis(darkredis::Error),
+    Migration(MigrationError),
 }
 
 impl error::Error for DatabaseError {}
@@ -158,6 +172,34 @@ impl fmt::Display for DatabaseError {
             DatabaseError::Deserializing(e) => write!(f, "Failed to deserialize: {}", e),
             DatabaseError::Serializing(e) => write!(f, "Failed to seralize: {}", e),
             DatabaseError::Darkredis(e) => write!(f, "Redis failure: {}", e),
+            DatabaseError::Migration(e) => write!(f, "Failed to migrate config: {}", e),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum MigrationError {
+    NotAnObject,
+    InvalidVersion(String),
+    /// Written by a newer version of the bot, that knows migrations this one doesn't.
+    TooNew(u64),
+    Failed {
+        version: u32,
+        reason: String,
+    },
+}
+
+impl error::Error for MigrationError {}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::NotAnObject => write!(f, "The config is not a json object"),
+            MigrationError::InvalidVersion(version) => write!(f, "{} is not a valid config version", version),
+            MigrationError::TooNew(version) => write!(f, "Config version {} is newer than this build knows", version),
+            MigrationError::Failed { version, reason } => {
+                write!(f, "Migrating from version {} failed: {}", version, reason)
+            }
         }
     }
 }
@@ -194,10 +236,29 @@ impl fmt::Display for ApiMessageError {
     }
 }
 
+#[derive(Debug)]
+pub enum ConfigError {
+    Invalid(Vec<ConfigIssue>),
+    Database(DatabaseError),
+}
+
+impl error::Error for ConfigError {}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Invalid(issues) => write!(f, "The config is invalid: {:?}", issues),
+            ConfigError::Database(e) => write!(f, "Database failure: {}", e),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum CommandError {
     NoDM,
     InvalidPermissions,
+    /// Already translated list of everything that is wrong with the config.
+    InvalidConfig(String),
     ParseError(ParseError),
     OtherFailure(OtherFailure),
 }
@@ -209,6 +270,7 @@ impl fmt::Display for CommandError {
         match self {
             CommandError::NoDM => write!(f, "You can not use this command in DMs"),
             CommandError::InvalidPermissions => write!(f, "You don't have the permissions to run this command!"),
+            CommandError::InvalidConfig(issues) => write!(f, "{}", issues),
             CommandError::ParseError(e) => write!(f, "Failed to parse the command arguments!\n``{}``", e),
             CommandError::OtherFailure(_) => write!(f, "Unexpected error while executing the command, please report this on the support server if it keeps happening"),
         }
@@ -222,6 +284,12 @@ pub enum OtherFailure {
     DatabaseError(DatabaseError),
     CorruptCache,
     Message(MessageError),
+    AuditLogReason(AuditLogReasonError),
+    ChannelMessages(GetChannelMessagesError),
+    Nickname(UpdateGuildMemberError),
+    Download(DownloadError),
+    /// Discord answered a follow up to an interaction without the message that was created.
+    MissingFollowup,
 }
 
 impl error::Error for OtherFailure {}
@@ -234,6 +302,30 @@ impl fmt::Display for OtherFailure {
             OtherFailure::ShardOrCluster(e) => write!(f, "Shard command failed: {}", e),
             OtherFailure::TwilightHttp(e) => write!(f, "Something when wrong interacting with the discord api: {}", e),
             OtherFailure::Message(e) => write!(f, "Failed to construct a message: {}", e),
+            OtherFailure::AuditLogReason(e) => write!(f, "Invalid audit log reason: {}", e),
+            OtherFailure::ChannelMessages(e) => write!(f, "Invalid channel history request: {}", e),
+            OtherFailure::Nickname(e) => write!(f, "Invalid nickname: {}", e),
+            OtherFailure::Download(e) => write!(f, "Failed to download a file: {}", e),
+            OtherFailure::MissingFollowup => write!(f, "The follow up message to the interaction was not returned"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum DownloadError {
+    InvalidUrl(String),
+    Hyper(hyper::Error),
+    Status(hyper::StatusCode),
+}
+
+impl error::Error for DownloadError {}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::InvalidUrl(url) => write!(f, "Invalid url: {}", url),
+            DownloadError::Hyper(e) => write!(f, "Request failed: {}", e),
+            DownloadError::Status(status) => write!(f, "Unexpected response status: {}", status),
         }
     }
 }
@@ -253,6 +345,23 @@ pub enum ParseError {
     NSFW,
     CorruptCache,
     NoDm,
+    UnknownConfigPath(String),
+    NotAConfigList(String),
+    AlreadyInConfigList(String),
+    NotInConfigList(String),
+    InvalidConfigValue(String),
+    InvalidConfigFile(String),
+    NotANumber(String),
+    NumberOutOfRange { input: String, min: String, max: String },
+    InvalidDuration(String),
+    InvalidMessage(String),
+    ForeignMessageLink,
+    RoleNotFoundById(u64),
+    RoleNotFoundByName(String),
+    MultipleRolesByName(String),
+    ChannelNotFoundByName(String),
+    MultipleChannelsByName(String),
+    InvalidChoice { input: String, choices: String },
     Other(OtherFailure),
 }
 
@@ -285,6 +394,43 @@ impl fmt::Display for ParseError {
             ),
             ParseError::CorruptCache => write!(f, "While processing this command cache corruption was detected, command execution was aborted and a cache reset is in progress, please try again in a few minutes"),
             ParseError::NoDm => write!(f, "This can not be used in DMs"),
+            ParseError::UnknownConfigPath(path) => write!(f, "There is nothing at ``{}`` in the config", path),
+            ParseError::NotAConfigList(path) => write!(f, "``{}`` is not a list", path),
+            ParseError::AlreadyInConfigList(path) => write!(f, "That is already in ``{}``", path),
+            ParseError::NotInConfigList(path) => write!(f, "That is not in ``{}``", path),
+            ParseError::InvalidConfigValue(e) => write!(f, "That is not a valid value there: {}", e),
+            ParseError::InvalidConfigFile(e) => write!(f, "That is not a valid config file: {}", e),
+            ParseError::NotANumber(input) => write!(f, "``{}`` is not a number", input),
+            ParseError::NumberOutOfRange { input, min, max } => {
+                write!(f, "``{}`` is out of range, it has to be between {} and {}", input, min, max)
+            }
+            ParseError::InvalidDuration(input) => write!(
+                f,
+                "``{}`` is not a valid duration, use something like ``30m`` or ``1d12h``",
+                input
+            ),
+            ParseError::InvalidMessage(input) => {
+                write!(f, "``{}`` is not a message id or a link to a message", input)
+            }
+            ParseError::ForeignMessageLink => write!(f, "That message is on another server"),
+            ParseError::RoleNotFoundById(id) => write!(f, "There is no role with id ``{}`` on this server", id),
+            ParseError::RoleNotFoundByName(name) => write!(f, "There is no role named ``{}`` on this server", name),
+            ParseError::MultipleRolesByName(name) => write!(
+                f,
+                "Multiple roles are named ``{}``, please use a mention or id instead",
+                name
+            ),
+            ParseError::ChannelNotFoundByName(name) => {
+                write!(f, "There is no channel named ``{}`` on this server", name)
+            }
+            ParseError::MultipleChannelsByName(name) => write!(
+                f,
+                "Multiple channels are named ``{}``, please use a mention or id instead",
+                name
+            ),
+            ParseError::InvalidChoice { input, choices } => {
+                write!(f, "``{}`` is not an option here, pick one of: {}", input, choices)
+            }
             ParseError::Other(_) => write!(f, "An unexpected error occurred trying to parse and retrieve this")
         }
     }
@@ -300,6 +446,10 @@ impl fmt::Display for StartupError {
             StartupError::DarkRedis(e) => write!(f, "Unable to create redis database pool: {}", e),
             StartupError::ClusterStart(e) => write!(f, "The cluster failed to start: {}", e),
             StartupError::Io(e) => write!(f, "IO error: {}", e),
+            StartupError::Database(e) => write!(f, "Database failure: {}", e),
+            StartupError::ConfigMigration(failed) => write!(f, "{} guild configs failed to migrate", failed),
+            StartupError::CommandRegistration(e) => write!(f, "Invalid slash commands: {}", e),
+            StartupError::NoApplicationId => write!(f, "The application id is needed to register the slash commands"),
         }
     }
 }
@@ -380,6 +530,18 @@ impl From<twilight_http::Error> for CommandError {
     }
 }
 
+impl From<AuditLogReasonError> for CommandError {
+    fn from(e: AuditLogReasonError) -> Self {
+        CommandError::OtherFailure(OtherFailure::AuditLogReason(e))
+    }
+}
+
+impl From<GetChannelMessagesError> for CommandError {
+    fn from(e: GetChannelMessagesError) -> Self {
+        CommandError::OtherFailure(OtherFailure::ChannelMessages(e))
+    }
+}
+
 impl From<OtherFailure> for CommandError {
     fn from(e: OtherFailure) -> Self {
         CommandError::OtherFailure(e)
@@ -403,6 +565,30 @@ impl From<darkredis::Error> for ApiCommunicaionError {
     }
 }
 
+impl From<hyper::Error> for DownloadError {
+    fn from(e: hyper::Error) -> Self {
+        DownloadError::Hyper(e)
+    }
+}
+
+impl From<DownloadError> for CommandError {
+    fn from(e: DownloadError) -> Self {
+        CommandError::OtherFailure(OtherFailure::Download(e))
+    }
+}
+
+impl From<DatabaseError> for StartupError {
+    fn from(e: DatabaseError) -> Self {
+        StartupError::Database(e)
+    }
+}
+
+impl From<MigrationError> for DatabaseError {
+    fn from(e: MigrationError) -> Self {
+        DatabaseError::Migration(e)
+    }
+}
+
 impl From<DatabaseError> for ParseError {
     fn from(e: DatabaseError) -> Self {
         ParseError::Other(OtherFailure::DatabaseError(e))
@@ -461,6 +647,12 @@ impl From<twilight_http::Error> for EventHandlerError {
     }
 }
 
+impl From<CommandError> for EventHandlerError {
+    fn from(e: CommandError) -> Self {
+        EventHandlerError::Reply(e)
+    }
+}
+
 impl From<twilight_http::Error> for ReactorError {
     fn from(e: twilight_http::Error) -> Self {
         ReactorError::TwilightHttp(e)
@@ -481,6 +673,12 @@ impl From<ParseError> for CommandError {
     }
 }
 
+impl From<DatabaseError> for ConfigError {
+    fn from(e: DatabaseError) -> Self {
+        ConfigError::Database(e)
+    }
+}
+
 impl From<DatabaseError> for CommandError {
     fn from(e: DatabaseError) -> Self {
         CommandError::OtherFailure(OtherFailure::DatabaseError(e))
@@ -564,6 +762,12 @@ impl From<io::Error> for StartupError {
     }
 }
 
+impl From<InteractionError> for StartupError {
+    fn from(e: InteractionError) -> Self {
+        StartupError::CommandRegistration(e)
+    }
+}
+
 impl From<EmbedBuildError> for MessageError {
     fn from(e: EmbedBuildError) -> Self {
         MessageError::EmbedBuild(e)
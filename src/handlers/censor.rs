@@ -0,0 +1,313 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use twilight_gateway::Event;
+use twilight_model::channel::Attachment;
+use twilight_model::guild::Permissions;
+use twilight_model::id::{ChannelId, GuildId, MessageId, UserId};
+
+use crate::core::logpump::{LogData, LogType};
+use crate::core::{BotContext, CensorConfig, ChannelFilter, GuildConfig};
+use crate::database::structures::InfractionType;
+use crate::error::EventHandlerError;
+use crate::translation::{FluArgs, GearBotString};
+use crate::utils;
+use crate::utils::matchers::{self, InviteLink};
+
+/// Invites rarely change where they point to, but they can be deleted and replaced with one to somewhere else.
+const INVITE_CACHE_DURATION: u32 = 3600;
+
+/// Where an invite leads to, as stored in redis.
+#[derive(Serialize, Deserialize)]
+enum InviteTarget {
+    Guild { id: GuildId, name: String },
+    GroupDm { name: String },
+    Invalid,
+}
+
+/// Why a message got censored: what to log and what to put in the infraction, if the guild wants one.
+struct Censored {
+    log_type: LogType,
+    reason: String,
+}
+
+/// The parts of a new or edited message the censor needs to look at.
+struct ToCensor<'a> {
+    id: MessageId,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    author_id: UserId,
+    content: &'a str,
+    attachments: &'a [Attachment],
+}
+
+pub async fn handle_event(_shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
+    let message = match event {
+        Event::MessageCreate(msg) => match msg.guild_id {
+            Some(guild_id) => ToCensor {
+                id: msg.id,
+                guild_id,
+                channel_id: msg.channel_id,
+                author_id: msg.author.id,
+                content: &msg.content,
+                attachments: &msg.attachments,
+            },
+            None => return Ok(()),
+        },
+        Event::MessageUpdate(update) => match (update.guild_id, &update.author, &update.content) {
+            (Some(guild_id), Some(author), Some(content)) => ToCensor {
+                id: update.id,
+                guild_id,
+                channel_id: update.channel_id,
+                author_id: author.id,
+                content,
+                attachments: update.attachments.as_deref().unwrap_or_default(),
+            },
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    if message.author_id == ctx.bot_user.id {
+        return Ok(());
+    }
+
+    let config = ctx.get_config(message.guild_id).await?;
+    if is_exempt(&ctx, &config.censoring, &message).await {
+        return Ok(());
+    }
+
+    let censored = match check_invites(&ctx, &config, &message).await? {
+        Some(censored) => Some(censored),
+        None => match check_channel_filter(&ctx, &config, &message).await {
+            Some(censored) => Some(censored),
+            None => check_filters(&ctx, &config, &message).await?,
+        },
+    };
+
+    if let Some(censored) = censored {
+        censor(&ctx, &config, &message, censored).await?;
+    }
+
+    Ok(())
+}
+
+async fn is_exempt(ctx: &Arc<BotContext>, config: &CensorConfig, message: &ToCensor<'_>) -> bool {
+    if config.exempt_channels.contains(&message.channel_id) {
+        return true;
+    }
+
+    match ctx.cache.get_member(&message.guild_id, &message.author_id).await {
+        Some(member) => member.roles.iter().any(|role| config.exempt_roles.contains(role)),
+        None => false,
+    }
+}
+
+/// Looks for invites that lead anywhere other then this guild or one of the whitelisted ones.
+async fn check_invites(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    message: &ToCensor<'_>,
+) -> Result<Option<Censored>, EventHandlerError> {
+    if !config.censoring.censor_invites {
+        return Ok(None);
+    }
+
+    for link in matchers::get_invite_links(message.content) {
+        let (code, guild_name) = match link {
+            InviteLink::Discord(code) => match get_invite_target(ctx, code).await? {
+                InviteTarget::Guild { id, .. }
+                    if id == message.guild_id || config.censoring.invite_whitelist.contains(&id) =>
+                {
+                    continue
+                }
+                InviteTarget::Guild { name, .. } | InviteTarget::GroupDm { name } => (code.to_string(), name),
+                // Dead invites don't lead anywhere
+                InviteTarget::Invalid => continue,
+            },
+            // There is no way to tell where these lead, so they can't be whitelisted either
+            InviteLink::ThirdParty(link) => {
+                let site = link
+                    .trim_start_matches("https://")
+                    .trim_start_matches("http://")
+                    .split('/')
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+                (link.to_string(), site)
+            }
+        };
+
+        let args = FluArgs::with_capacity(1).add("guild", guild_name.clone()).generate();
+        return Ok(Some(Censored {
+            reason: ctx.translate_with_args(&config.language, GearBotString::CensorReasonInvite, &args),
+            log_type: LogType::InviteCensored {
+                code,
+                guild_name,
+                content: message.content.to_string(),
+            },
+        }));
+    }
+
+    Ok(None)
+}
+
+/// Checks the attachments and links against the rules for the channel, or the ones for its category.
+async fn check_channel_filter(ctx: &Arc<BotContext>, config: &GuildConfig, message: &ToCensor<'_>) -> Option<Censored> {
+    let filters = &config.censoring.channel_filters;
+    if filters.is_empty() {
+        return None;
+    }
+
+    let filter = match filters.get(&message.channel_id) {
+        Some(filter) => filter,
+        None => {
+            let parent = ctx.cache.get_channel(message.channel_id).await?.get_parent_id()?;
+            filters.get(&parent)?
+        }
+    };
+
+    check_attachments(ctx, config, filter, message).or_else(|| check_links(ctx, config, filter, message))
+}
+
+fn check_attachments(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    filter: &ChannelFilter,
+    message: &ToCensor<'_>,
+) -> Option<Censored> {
+    let attachment = message
+        .attachments
+        .iter()
+        .find(|attachment| !filter.allows_file(&attachment.filename))?;
+
+    let args = FluArgs::with_capacity(1)
+        .add("filename", attachment.filename.clone())
+        .generate();
+    Some(Censored {
+        reason: ctx.translate_with_args(&config.language, GearBotString::CensorReasonAttachment, &args),
+        log_type: LogType::AttachmentCensored {
+            filename: attachment.filename.clone(),
+            content: message.content.to_string(),
+        },
+    })
+}
+
+fn check_links(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    filter: &ChannelFilter,
+    message: &ToCensor<'_>,
+) -> Option<Censored> {
+    let domain = matchers::get_url_domains(message.content)
+        .into_iter()
+        .find(|domain| !filter.allows_domain(domain))?;
+
+    let args = FluArgs::with_capacity(1).add("domain", domain.clone()).generate();
+    Some(Censored {
+        reason: ctx.translate_with_args(&config.language, GearBotString::CensorReasonLink, &args),
+        log_type: LogType::LinkCensored {
+            domain,
+            content: message.content.to_string(),
+        },
+    })
+}
+
+/// Runs the word and regex lists over the message, after undoing any lookalike or zalgo tricks.
+async fn check_filters(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    message: &ToCensor<'_>,
+) -> Result<Option<Censored>, EventHandlerError> {
+    let filters = ctx.get_censor_filters(message.guild_id).await?;
+    if filters.is_empty() {
+        return Ok(None);
+    }
+
+    let cleaned = utils::clean(message.content, false, false, false, true);
+    let (normalized, origins) = utils::normalize_lookalikes(&cleaned);
+
+    Ok(filters.find(&normalized).map(|found| {
+        let term = found.as_str().to_string();
+        let args = FluArgs::with_capacity(1).add("term", term.clone()).generate();
+        Censored {
+            reason: ctx.translate_with_args(&config.language, GearBotString::CensorReasonWord, &args),
+            log_type: LogType::WordCensored {
+                term,
+                start: origins[found.start()],
+                end: origins[found.end()],
+                content: cleaned,
+            },
+        }
+    }))
+}
+
+async fn get_invite_target(ctx: &Arc<BotContext>, code: &str) -> Result<InviteTarget, EventHandlerError> {
+    let redis_key = format!("invite:{}", code);
+    let redis_cache = &ctx.datastore.cache_pool;
+    if let Some(target) = redis_cache.get::<InviteTarget>(&redis_key).await? {
+        return Ok(target);
+    }
+
+    let target = match ctx.http.invite(code).await? {
+        Some(invite) => match invite.guild {
+            Some(guild) => InviteTarget::Guild {
+                id: guild.id,
+                name: guild.name,
+            },
+            None => InviteTarget::GroupDm {
+                name: invite.channel.name.unwrap_or_default(),
+            },
+        },
+        None => InviteTarget::Invalid,
+    };
+
+    redis_cache
+        .set(&redis_key, &target, Some(INVITE_CACHE_DURATION))
+        .await?;
+
+    Ok(target)
+}
+
+/// Removes the message, logs why and adds an infraction if the guild wants that.
+///
+/// The stored copy goes first, the log already holds the content so there is no need for a second deletion log.
+async fn censor(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    message: &ToCensor<'_>,
+    censored: Censored,
+) -> Result<(), EventHandlerError> {
+    if !ctx
+        .get_channel_permissions_for(ctx.bot_user.id, message.channel_id)
+        .await
+        .contains(Permissions::MANAGE_MESSAGES)
+    {
+        return Ok(());
+    }
+
+    ctx.datastore.delete_message(message.id).await?;
+    ctx.http.delete_message(message.channel_id, message.id).await?;
+
+    if config.censoring.add_infraction {
+        ctx.datastore
+            .insert_infraction(
+                message.guild_id,
+                message.author_id,
+                ctx.bot_user.id,
+                InfractionType::Censor,
+                &censored.reason,
+                None,
+            )
+            .await?;
+    }
+
+    ctx.log(LogData {
+        log_type: censored.log_type,
+        guild: message.guild_id,
+        source_channel: Some(message.channel_id),
+        source_user: message.author_id,
+    });
+
+    Ok(())
+}
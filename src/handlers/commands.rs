@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use log::{debug, trace};
 use twilight_gateway::Event;
+use twilight_model::applications::interaction::Interaction;
 
 use crate::core::BotContext;
 use crate::error::EventHandlerError;
@@ -50,6 +51,12 @@ pub async fn handle_event<'a>(shard_id: u64, event: Event, ctx: Arc<BotContext>)
                 Parser::figure_it_out(&prefix, msg, ctx, shard_id).await?;
             }
         }
+        Event::InteractionCreate(interaction) => {
+            if let Interaction::ApplicationCommand(command) = interaction.0 {
+                trace!("Received the {} slash command", command.data.name);
+                Parser::handle_interaction(command, ctx, shard_id).await?;
+            }
+        }
         Event::MessageUpdate(update) => {
             trace!("Message updated to {:?}", update.content);
         }
@@ -1,3 +1,8 @@
+pub mod censor;
 pub mod commands;
 pub mod general;
+pub mod moderation;
 pub mod modlog;
+pub mod names;
+pub mod raids;
+pub mod spam;
@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use twilight_gateway::Event;
+use twilight_model::guild::Member;
+
+use crate::core::BotContext;
+use crate::error::EventHandlerError;
+
+pub async fn handle_event(_shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
+    if let Event::MemberAdd(member) = &event {
+        reapply_mute(&ctx, member).await?;
+    }
+
+    Ok(())
+}
+
+/// Leaving and joining again should not be a way to get rid of a mute that hasn't expired yet.
+async fn reapply_mute(ctx: &Arc<BotContext>, member: &Member) -> Result<(), EventHandlerError> {
+    let role = match ctx.get_config(member.guild_id).await?.mute.role {
+        Some(role) => role,
+        None => return Ok(()),
+    };
+
+    if ctx
+        .datastore
+        .get_active_mute(member.guild_id, member.user.id)
+        .await?
+        .is_some()
+    {
+        ctx.http
+            .add_guild_member_role(member.guild_id, member.user.id, role)
+            .await?;
+    }
+
+    Ok(())
+}
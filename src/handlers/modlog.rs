@@ -2,92 +2,35 @@ use std::sync::Arc;
 
 use log::debug;
 use twilight_gateway::Event;
-use twilight_model::gateway::payload::RequestGuildMembers;
+use twilight_model::gateway::payload::{MemberUpdate, MessageDeleteBulk, RequestGuildMembers};
+use twilight_model::id::RoleId;
 
+use crate::cache::CachedGuild;
+use crate::core::logpump::{LogData, LogFile, LogType};
 use crate::core::BotContext;
+use crate::database::structures::UserMessage;
 use crate::error::EventHandlerError;
+use crate::utils;
+
+/// Discord doesn't accept files over 8MB, stay a bit below that so there is room for the rest of the request.
+const ARCHIVE_SIZE_LIMIT: usize = 8_000_000;
 
 pub async fn handle_event(shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
     match &event {
         Event::MemberChunk(_chunk) => {}
         Event::UserUpdate(_) => {}
-        Event::MemberUpdate(_) => {
-            // According to the docs, cache commands can never error, but just to be safe and
-            // not spam unwraps everywhere, wrap it.
-            // let old_member = ctx.cache.member(update.guild_id, update.user.id).await?;
-            //
-            // let old_member = match old_member {
-            //     Some(om) => om,
-            //     None => return Ok(()),
-            // };
-            //
-            // // These cover the possible modlog options
-            // // that a member_update could trigger.
-            // let old_roles = &old_member.roles;
-            // let old_nickname = old_member.nick.as_ref();
-            // let old_member = &old_member.user;
-            //
-            // let new_roles = &update.roles;
-            // let new_nickname = update.nick.as_ref();
-            // let new_member = &update.user;
-            //
-            // let mut roles_lost = Vec::new();
-            // let mut roles_gained = Vec::new();
-            // if new_roles != old_roles {
-            //     for old_role in old_roles {
-            //         if !new_roles.contains(old_role) {
-            //             roles_lost.push(*old_role);
-            //         }
-            //     }
-            //
-            //     for new_role in new_roles {
-            //         if !old_roles.contains(new_role) {
-            //             roles_gained.push(*new_role)
-            //         }
-            //     }
-            // }
-            //
-            // let username_change = if new_member.name != old_member.name {
-            //     Some((&new_member.name, &old_member.name))
-            // } else {
-            //     None
-            // };
-            //
-            // let nickname_change = match (old_nickname, new_nickname) {
-            //     (Some(old_nick), Some(new_nick)) => Some((old_nick, new_nick)),
-            //     _ => None,
-            // };
-            //
-            // debug!("A member update occured: ");
-            //
-            // if let Some((old_alias, new_alias)) = nickname_change {
-            //     debug!(
-            //         "User {} changed their nickname from '{}' to '{}'",
-            //         new_member.name, old_alias, new_alias
-            //     );
-            // }
-            //
-            // if let Some((new_name, past_name)) = username_change {
-            //     debug!("User '{}' changed their name to '{}'", past_name, new_name);
-            // }
-            //
-            // if !roles_lost.is_empty() {
-            //     let roles_lost_display = generate_role_display(&roles_lost, &ctx).await?;
-            //     debug!(
-            //         "User '{}' lost the following roles: {}",
-            //         new_member.name, roles_lost_display
-            //     );
-            // }
-            //
-            // if !roles_gained.is_empty() {
-            //     let roles_gained_display = generate_role_display(&roles_lost, &ctx).await?;
-            //     debug!(
-            //         "User '{}' gained the following roles: {}",
-            //         new_member.name, roles_gained_display
-            //     );
-            // }
-        }
-
+        Event::MemberAdd(member) => ctx.log(LogData {
+            log_type: LogType::MemberJoin,
+            guild: member.guild_id,
+            source_channel: None,
+            source_user: member.user.id,
+        }),
+        Event::MemberRemove(removal) => ctx.log(LogData {
+            log_type: LogType::MemberLeave,
+            guild: removal.guild_id,
+            source_channel: None,
+            source_user: removal.user.id,
+        }),
         Event::MessageCreate(msg) => {
             if let Some(guild_id) = msg.guild_id {
                 let config = &ctx.get_config(guild_id).await?.message_logs;
@@ -101,6 +44,52 @@ pub async fn handle_event(shard_id: u64, event: &Event, ctx: Arc<BotContext>) ->
                 }
             }
         }
+        Event::MessageUpdate(update) => {
+            // Embeds loading in also count as an update, only edits that touched the content matter
+            if let (Some(guild_id), Some(content)) = (update.guild_id, &update.content) {
+                if let Some(old) = ctx.fetch_user_message(update.id, guild_id).await? {
+                    if &old.content != content {
+                        ctx.datastore
+                            .update_message_content(update.id, guild_id, content)
+                            .await?;
+                        ctx.log(LogData {
+                            log_type: LogType::MessageEdited {
+                                before: old.content,
+                                after: content.clone(),
+                                attachments: old.attachments,
+                            },
+                            guild: guild_id,
+                            source_channel: Some(update.channel_id),
+                            source_user: old.author,
+                        });
+                    }
+                }
+            }
+        }
+        Event::MessageDelete(delete) => {
+            if let Some(guild_id) = delete.guild_id {
+                if let Some(old) = ctx.fetch_user_message(delete.id, guild_id).await? {
+                    ctx.log(LogData {
+                        log_type: LogType::MessageDeleted {
+                            content: old.content,
+                            attachments: old.attachments,
+                        },
+                        guild: guild_id,
+                        source_channel: Some(delete.channel_id),
+                        source_user: old.author,
+                    });
+                }
+            }
+        }
+        Event::MessageDeleteBulk(delete) => log_bulk_delete(delete, &ctx).await?,
+        Event::GuildDelete(guild) if !guild.unavailable => {
+            // We got removed from the guild, there is no reason to hold on to its messages any longer
+            let purged = ctx.datastore.purge_guild_messages(guild.id).await?;
+            debug!(
+                "Purged {} stored messages of guild {} after leaving it",
+                purged, guild.id
+            );
+        }
         Event::GuildCreate(guild) => {
             let c = ctx.cluster.clone();
             let data = RequestGuildMembers::builder(guild.id).query("", None);
@@ -111,3 +100,171 @@ pub async fn handle_event(shard_id: u64, event: &Event, ctx: Arc<BotContext>) ->
     }
     Ok(())
 }
+
+/// Handles the events that need to be compared against what the cache knew before it gets updated with them.
+///
+/// This runs before the cache update and blocks the event loop, so it should never do more then read the cache.
+pub async fn handle_pre_cache_event(event: &Event, ctx: &Arc<BotContext>) {
+    if let Event::MemberUpdate(update) = event {
+        log_member_changes(update, ctx).await;
+    }
+}
+
+async fn log_member_changes(update: &MemberUpdate, ctx: &Arc<BotContext>) {
+    let guild = match ctx.cache.get_guild(&update.guild_id).await {
+        Some(guild) => guild,
+        None => return,
+    };
+    let old_member = match guild.get_member(&update.user.id).await {
+        Some(member) => member,
+        None => return,
+    };
+    let log = |log_type| {
+        ctx.log(LogData {
+            log_type,
+            guild: update.guild_id,
+            source_channel: None,
+            source_user: update.user.id,
+        })
+    };
+
+    if old_member.nickname != update.nick {
+        log(LogType::NicknameChanged {
+            before: old_member.nickname.clone(),
+            after: update.nick.clone(),
+        });
+    }
+
+    let added: Vec<RoleId> = update
+        .roles
+        .iter()
+        .filter(|role| !old_member.roles.contains(role))
+        .copied()
+        .collect();
+    if !added.is_empty() {
+        log(LogType::RolesAdded {
+            roles: role_names(&guild, &added).await,
+        });
+    }
+
+    let removed: Vec<RoleId> = old_member
+        .roles
+        .iter()
+        .filter(|role| !update.roles.contains(role))
+        .copied()
+        .collect();
+    if !removed.is_empty() {
+        log(LogType::RolesRemoved {
+            roles: role_names(&guild, &removed).await,
+        });
+    }
+
+    // The user itself is shared between all guilds, so the cache only sees this change once
+    if let Some(old_user) = ctx.cache.get_user(update.user.id).await {
+        if old_user.username != update.user.name || old_user.discriminator != update.user.discriminator {
+            let before = old_user.full_name();
+            let after = format!("{}#{}", update.user.name, update.user.discriminator);
+            for guild in ctx.cache.get_mutual_guilds(&update.user.id).await {
+                ctx.log(LogData {
+                    log_type: LogType::UsernameChanged {
+                        before: before.clone(),
+                        after: after.clone(),
+                    },
+                    guild: guild.id,
+                    source_channel: None,
+                    source_user: update.user.id,
+                });
+            }
+        }
+    }
+}
+
+async fn role_names(guild: &CachedGuild, roles: &[RoleId]) -> Vec<String> {
+    let mut names = vec![];
+    for role_id in roles {
+        match guild.get_role(role_id).await {
+            Some(role) => names.push(role.name.clone()),
+            None => names.push(role_id.to_string()),
+        }
+    }
+    names
+}
+
+/// Archives all messages that were stored out of a bulk delete, rather then logging each of them on their own.
+async fn log_bulk_delete(delete: &MessageDeleteBulk, ctx: &Arc<BotContext>) -> Result<(), EventHandlerError> {
+    let guild_id = match delete.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    let messages = ctx.datastore.get_full_messages(&delete.ids, guild_id).await?;
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    let mut lines = Vec::with_capacity(messages.len());
+    for message in &messages {
+        lines.push(archive_line(message, ctx).await);
+    }
+
+    let archives = split_archive(lines);
+    let parts = archives.len();
+    for (index, content) in archives.into_iter().enumerate() {
+        ctx.log(LogData {
+            log_type: LogType::BulkDelete {
+                count: delete.ids.len(),
+                part: index + 1,
+                parts,
+                archive: LogFile {
+                    name: format!("deleted_messages_{}_{}.txt", delete.channel_id, index + 1),
+                    content,
+                },
+            },
+            guild: guild_id,
+            source_channel: Some(delete.channel_id),
+            source_user: ctx.bot_user.id,
+        });
+    }
+
+    Ok(())
+}
+
+async fn archive_line(message: &UserMessage, ctx: &Arc<BotContext>) -> String {
+    let author = match ctx.cache.get_user(message.author).await {
+        Some(user) => user.full_name_with_id(),
+        None => message.author.to_string(),
+    };
+
+    let mut line = format!(
+        "[{}] {} [{}]: {}",
+        utils::snowflake_timestamp(message.id.0).format("%F %T"),
+        author,
+        message.id,
+        message.content
+    );
+    if !message.attachments.is_empty() {
+        line += &format!(" (attachments: {})", message.attachments.join(", "));
+    }
+
+    line
+}
+
+/// Packs the archive lines into as few files as possible without any of them going over the size limit.
+fn split_archive(lines: Vec<String>) -> Vec<String> {
+    let mut archives = vec![];
+    let mut current = String::new();
+
+    for line in lines {
+        if !current.is_empty() && current.len() + line.len() + 1 > ARCHIVE_SIZE_LIMIT {
+            archives.push(std::mem::take(&mut current));
+        }
+        current += &line;
+        current.push('\n');
+    }
+
+    if !current.is_empty() {
+        archives.push(current);
+    }
+
+    archives
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use log::debug;
+use twilight_gateway::Event;
+use twilight_http::request::AuditLogReason;
+use twilight_model::guild::Permissions;
+use twilight_model::id::{GuildId, UserId};
+
+use crate::core::config_validation::MAX_NICKNAME_LENGTH;
+use crate::core::logpump::{LogData, LogType};
+use crate::core::{moderation, BotContext, GuildConfig};
+use crate::error::{EventHandlerError, OtherFailure};
+use crate::translation::{FluArgs, GearBotString};
+use crate::{gearbot_warn, utils};
+
+/// Checks the names of members as they join or get updated.
+///
+/// Username changes also reach us as member updates for every guild we share with them, `UserUpdate` is only ever
+/// about the bot itself so there is nothing to check there.
+pub async fn handle_event(_shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
+    let (guild_id, user, nick, roles) = match event {
+        Event::MemberAdd(member) => (member.guild_id, &member.user, &member.nick, &member.roles),
+        Event::MemberUpdate(update) => (update.guild_id, &update.user, &update.nick, &update.roles),
+        _ => return Ok(()),
+    };
+
+    if user.id == ctx.bot_user.id {
+        return Ok(());
+    }
+
+    let config = ctx.get_config(guild_id).await?;
+    let censoring = &config.censoring;
+    if !censoring.censor_names && !censoring.dehoist {
+        return Ok(());
+    }
+
+    // Our own rename comes back as a member update as well
+    let fallback: String = censoring.name_fallback.chars().take(MAX_NICKNAME_LENGTH).collect();
+    let name = nick.as_deref().unwrap_or(&user.name);
+    if name == fallback || roles.iter().any(|role| censoring.exempt_roles.contains(role)) {
+        return Ok(());
+    }
+
+    let reason = match check_name(&ctx, &config, guild_id, name).await? {
+        Some(reason) => reason,
+        None => return Ok(()),
+    };
+
+    // only renames that can actually happen count towards the limit
+    if !can_rename(&ctx, guild_id, user.id).await {
+        return Ok(());
+    }
+
+    if !ctx.rename_limiter.try_rename(guild_id, Instant::now()).await {
+        debug!("Rename limit reached in {}, not renaming {}", guild_id, user.id);
+        return Ok(());
+    }
+
+    match rename(&ctx, guild_id, user.id, &fallback, &reason).await {
+        Ok(()) => ctx.log(LogData {
+            log_type: LogType::NameCensored {
+                before: name.to_string(),
+                after: fallback,
+                reason,
+            },
+            guild: guild_id,
+            source_channel: None,
+            source_user: user.id,
+        }),
+        Err(e) => gearbot_warn!("Failed to rename {} in {}: {}", user.id, guild_id, e),
+    }
+
+    Ok(())
+}
+
+/// Returns why the name is not allowed, if it isn't.
+async fn check_name(
+    ctx: &Arc<BotContext>,
+    config: &GuildConfig,
+    guild_id: GuildId,
+    name: &str,
+) -> Result<Option<String>, EventHandlerError> {
+    if config.censoring.censor_names {
+        let filters = ctx.get_censor_filters(guild_id).await?;
+        let (normalized, _) = utils::normalize_lookalikes(name);
+        if let Some(found) = filters.find(&normalized) {
+            let args = FluArgs::with_capacity(1)
+                .add("term", found.as_str().to_string())
+                .generate();
+            return Ok(Some(ctx.translate_with_args(
+                &config.language,
+                GearBotString::CensorReasonWord,
+                &args,
+            )));
+        }
+    }
+
+    if config.censoring.dehoist && utils::is_hoisted(name) {
+        return Ok(Some(
+            ctx.translate(&config.language, GearBotString::CensorReasonHoisted),
+        ));
+    }
+
+    Ok(None)
+}
+
+/// Checks if the bot is allowed to change the nickname of the member.
+async fn can_rename(ctx: &Arc<BotContext>, guild_id: GuildId, user_id: UserId) -> bool {
+    ctx.get_guild_permissions_for(&guild_id, &ctx.bot_user.id)
+        .await
+        .contains(Permissions::MANAGE_NICKNAMES)
+        && moderation::bot_outranks(ctx, guild_id, user_id).await
+}
+
+/// Gives the member the fallback nickname.
+async fn rename(
+    ctx: &Arc<BotContext>,
+    guild_id: GuildId,
+    user_id: UserId,
+    nickname: &str,
+    reason: &str,
+) -> Result<(), OtherFailure> {
+    ctx.http
+        .update_guild_member(guild_id, user_id)
+        .nick(Some(nickname.to_string()))
+        .map_err(OtherFailure::Nickname)?
+        .reason(reason)
+        .map_err(OtherFailure::AuditLogReason)?
+        .await?;
+
+    Ok(())
+}
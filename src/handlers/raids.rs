@@ -0,0 +1,124 @@
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::{Duration, Utc};
+use twilight_gateway::Event;
+use twilight_http::request::AuditLogReason;
+use twilight_model::guild::VerificationLevel;
+use twilight_model::id::{GuildId, RoleId, UserId};
+
+use crate::core::logpump::{LogData, LogType};
+use crate::core::raids::JoinOutcome;
+use crate::core::{punishments, BotContext, GuildConfig, Punishment, RaidAction};
+use crate::error::{EventHandlerError, OtherFailure};
+use crate::translation::GearBotString;
+use crate::{gearbot_warn, utils};
+
+pub async fn handle_event(_shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
+    let member = match event {
+        Event::MemberAdd(member) => member,
+        _ => return Ok(()),
+    };
+
+    let guild_id = member.guild_id;
+    let user_id = member.user.id;
+    let config = ctx.get_config(guild_id).await?;
+    let raids = &config.raids;
+    if !raids.enabled || raids.threshold == 0 {
+        return Ok(());
+    }
+
+    let counts = match raids.max_account_age_days {
+        Some(days) => utils::snowflake_timestamp(user_id.0) > Utc::now() - Duration::days(days as i64),
+        None => true,
+    };
+
+    match ctx
+        .raid_tracker
+        .track_join(guild_id, user_id, counts, raids, Instant::now())
+        .await
+    {
+        JoinOutcome::Nothing => {}
+        JoinOutcome::RaidStarted(caught) => {
+            ctx.log(LogData {
+                log_type: LogType::RaidStarted {
+                    joins: caught.len() as u32,
+                    period: raids.period,
+                },
+                guild: guild_id,
+                source_channel: None,
+                source_user: user_id,
+            });
+
+            if raids.raise_verification {
+                raise_verification(&ctx, guild_id).await;
+            }
+
+            for raider in caught {
+                handle_raider(&ctx, &config, guild_id, raider).await;
+            }
+        }
+        JoinOutcome::Raider => handle_raider(&ctx, &config, guild_id, user_id).await,
+    }
+
+    Ok(())
+}
+
+/// Locks the guild down to the highest verification level, remembering what it was so it can be restored afterwards.
+async fn raise_verification(ctx: &Arc<BotContext>, guild_id: GuildId) {
+    let previous = match ctx.cache.get_guild(&guild_id).await {
+        Some(guild) => guild.verification_level,
+        None => return,
+    };
+
+    if previous == VerificationLevel::VeryHigh {
+        return;
+    }
+
+    match ctx
+        .http
+        .update_guild(guild_id)
+        .verification_level(Some(VerificationLevel::VeryHigh))
+        .reason("Raid detected")
+    {
+        Ok(request) => match request.await {
+            Ok(_) => ctx.raid_tracker.set_previous_verification(guild_id, previous).await,
+            Err(e) => gearbot_warn!(
+                "Failed to raise the verification level of {} during a raid: {}",
+                guild_id,
+                e
+            ),
+        },
+        Err(e) => gearbot_warn!("Invalid audit log reason for raising the verification level: {}", e),
+    }
+}
+
+/// Applies the configured raid action to someone that joined as part of a raid.
+async fn handle_raider(ctx: &Arc<BotContext>, config: &GuildConfig, guild_id: GuildId, user_id: UserId) {
+    let reason = ctx.translate(&config.language, GearBotString::RaidJoinReason);
+    let result = match config.raids.action {
+        RaidAction::Nothing => Ok(()),
+        RaidAction::Quarantine(role) => quarantine(ctx, guild_id, user_id, role, &reason).await,
+        RaidAction::Kick => punishments::punish(ctx, guild_id, user_id, Punishment::Kick, &reason, None).await,
+    };
+
+    if let Err(e) = result {
+        gearbot_warn!("Failed to act on raider {} in {}: {}", user_id, guild_id, e);
+    }
+}
+
+async fn quarantine(
+    ctx: &Arc<BotContext>,
+    guild_id: GuildId,
+    user_id: UserId,
+    role: RoleId,
+    reason: &str,
+) -> Result<(), OtherFailure> {
+    ctx.http
+        .add_guild_member_role(guild_id, user_id, role)
+        .reason(reason)
+        .map_err(OtherFailure::AuditLogReason)?
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use twilight_gateway::Event;
+use twilight_model::guild::Permissions;
+use twilight_model::id::{ChannelId, GuildId, MessageId, UserId};
+
+use crate::core::logpump::{LogData, LogType};
+use crate::core::spam::{SpamEntry, SpamViolation};
+use crate::core::{punishments, BotContext, Punishment};
+use crate::error::EventHandlerError;
+use crate::gearbot_warn;
+use crate::translation::{FluArgs, GearBotString};
+
+/// Discord only bulk deletes up to 100 messages per request.
+const CHUNK_SIZE: usize = 100;
+
+pub async fn handle_event(_shard_id: u64, event: &Event, ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
+    let msg = match event {
+        Event::MessageCreate(msg) => msg,
+        _ => return Ok(()),
+    };
+
+    let guild_id = match msg.guild_id {
+        Some(guild_id) if !msg.author.bot => guild_id,
+        _ => return Ok(()),
+    };
+
+    let config = ctx.get_config(guild_id).await?;
+    let spam = &config.spam;
+    if !spam.enabled || spam.rules.is_empty() || spam.exempt_channels.contains(&msg.channel_id) {
+        return Ok(());
+    }
+
+    if let Some(member) = ctx.cache.get_member(&guild_id, &msg.author.id).await {
+        if member.roles.iter().any(|role| spam.exempt_roles.contains(role)) {
+            return Ok(());
+        }
+    }
+
+    let entry = SpamEntry::new(
+        msg.id,
+        msg.channel_id,
+        &msg.content,
+        msg.attachments.len(),
+        Instant::now(),
+    );
+    if let Some(violation) = ctx
+        .spam_buckets
+        .track(guild_id, msg.author.id, entry, &spam.rules)
+        .await
+    {
+        let args = FluArgs::with_capacity(3)
+            .add("amount", violation.amount)
+            .add("bucket", violation.rule.bucket.to_string())
+            .add("period", violation.rule.period)
+            .generate();
+        let reason = ctx.translate_with_args(&config.language, GearBotString::SpamInfractionReason, &args);
+
+        punish_spammer(&ctx, guild_id, msg.author.id, msg.channel_id, violation, &reason).await?;
+    }
+
+    Ok(())
+}
+
+async fn punish_spammer(
+    ctx: &Arc<BotContext>,
+    guild_id: GuildId,
+    user_id: UserId,
+    channel_id: ChannelId,
+    violation: SpamViolation,
+    reason: &str,
+) -> Result<(), EventHandlerError> {
+    let punishment = violation.rule.punishment;
+
+    ctx.log(LogData {
+        log_type: LogType::SpamDetected {
+            bucket: violation.rule.bucket,
+            amount: violation.amount,
+            period: violation.rule.period,
+            punishment,
+        },
+        guild: guild_id,
+        source_channel: Some(channel_id),
+        source_user: user_id,
+    });
+
+    if punishment == Punishment::Delete {
+        return delete_messages(ctx, violation.messages).await;
+    }
+
+    if let Err(e) = punishments::punish(ctx, guild_id, user_id, punishment, reason, Some(channel_id)).await {
+        gearbot_warn!(
+            "Failed to {} {} for spamming in {}: {}",
+            punishment,
+            user_id,
+            guild_id,
+            e
+        );
+    }
+
+    Ok(())
+}
+
+/// Removes the messages that filled up the bucket, they can be spread out over multiple channels.
+async fn delete_messages(
+    ctx: &Arc<BotContext>,
+    messages: Vec<(ChannelId, MessageId)>,
+) -> Result<(), EventHandlerError> {
+    let mut per_channel: HashMap<ChannelId, Vec<MessageId>> = HashMap::new();
+    for (channel_id, message_id) in messages {
+        per_channel.entry(channel_id).or_default().push(message_id);
+    }
+
+    for (channel_id, messages) in per_channel {
+        if !ctx
+            .get_channel_permissions_for(ctx.bot_user.id, channel_id)
+            .await
+            .contains(Permissions::MANAGE_MESSAGES)
+        {
+            continue;
+        }
+
+        for chunk in messages.chunks(CHUNK_SIZE) {
+            if chunk.len() == 1 {
+                ctx.http.delete_message(channel_id, chunk[0]).await?;
+            } else {
+                ctx.http.delete_messages(channel_id, chunk.to_vec()).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
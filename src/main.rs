@@ -19,14 +19,18 @@ use twilight_model::{
         presence::{ActivityType, Status},
         Intents,
     },
+    id::UserId,
     user::CurrentUser,
 };
 
 use prometheus::{Encoder, TextEncoder};
 
-use crate::core::{logging, logpump, status as bot_status, BotConfig, BotContext, BotStats, ColdRebootData};
+use crate::core::{
+    logging, logpump, message_retention, status as bot_status, timed_actions, BotConfig, BotContext, BotStats,
+    ColdRebootData,
+};
 use crate::error::{EventHandlerError, StartupError};
-use commands::ROOT_NODE;
+use commands::{meta::slash_commands, ROOT_NODE};
 use translation::Translations;
 
 mod commands;
@@ -113,6 +117,12 @@ async fn real_main() -> Result<(), StartupError> {
 
     // Parse CLI arguments for sharding and cluster info
     let args = std::env::args().skip(1).collect::<Vec<String>>();
+    match args.get(0).map(String::as_str) {
+        Some("migrate-configs") => return migrate_configs(&datastore, bot_user.id).await,
+        Some("register-commands") => return register_commands(&http, &config, &translations).await,
+        _ => {}
+    }
+
     let cluster_id = args
         .get(0)
         .map(|cs| cs.parse::<u64>().unwrap_or_default())
@@ -133,6 +143,57 @@ async fn real_main() -> Result<(), StartupError> {
     Ok(())
 }
 
+/// Brings every stored guild config up to the current version in one go, instead of as the guilds get loaded. Configs
+/// that fail to migrate are reported and left as they are.
+async fn migrate_configs(datastore: &DataStorage, bot_id: UserId) -> Result<(), StartupError> {
+    let guild_ids = datastore.get_guild_ids(0..1, 1).await?;
+
+    gearbot_info!("Migrating {} guild configs", guild_ids.len());
+    let mut migrated = 0;
+    let mut failed = 0;
+    for guild_id in &guild_ids {
+        match datastore.load_guild_config(guild_id.0, bot_id).await {
+            Ok(Some((_, true))) => migrated += 1,
+            Ok(_) => {}
+            Err(e) => {
+                gearbot_error!("Failed to migrate the config of {}: {}", guild_id, e);
+                failed += 1;
+            }
+        }
+    }
+
+    gearbot_important!(
+        "Config migration done: {} migrated, {} already up to date, {} failed",
+        migrated,
+        guild_ids.len() - migrated - failed,
+        failed
+    );
+
+    if failed > 0 {
+        Err(StartupError::ConfigMigration(failed))
+    } else {
+        Ok(())
+    }
+}
+
+/// Replaces the slash commands registered with discord by the ones of the current command tree. Registering them takes a
+/// while to reach every server, so this is done once per release instead of every time a cluster starts.
+async fn register_commands(
+    http: &HttpClient,
+    config: &BotConfig,
+    translations: &Translations,
+) -> Result<(), StartupError> {
+    let application_id = config.application_id.ok_or(StartupError::NoApplicationId)?;
+    http.set_application_id(application_id);
+
+    let commands = slash_commands::application_commands(translations);
+    let count = commands.len();
+    http.set_global_commands(commands)?.await?;
+
+    gearbot_important!("Registered {} slash commands", count);
+    Ok(())
+}
+
 async fn run(
     scheme_info: SchemeInfo,
     config: BotConfig,
@@ -241,6 +302,8 @@ async fn run(
     );
     let ctx = context.clone();
     let mut _logpump_task = tokio::spawn(logpump::run(ctx, receiver));
+    tokio::spawn(timed_actions::run(context.clone()));
+    tokio::spawn(message_retention::run(context.clone()));
 
     //establish api connection
     let c = context.clone();
@@ -270,6 +333,7 @@ async fn run(
     while let Some(event) = bot_events.next().await {
         let c = context.clone();
         context.update_stats(event.0, &event.1).await; //this is fine to await, only async for updating shard states, gona be extremely rare something else also has a lock on that
+        handlers::modlog::handle_pre_cache_event(&event.1, &context).await;
         context.cache.update(event.0, &event.1, context.clone()).await; //we are awaiting this because cache needs ot be updated before it's safe to spawn off the handling, to avoid working with stale data
         tokio::spawn(async {
             if let Err(e) = handle_event(event, c).await {
@@ -288,6 +352,11 @@ async fn run(
 async fn handle_event(event: (u64, Event), ctx: Arc<BotContext>) -> Result<(), EventHandlerError> {
     handlers::modlog::handle_event(event.0, &event.1, ctx.clone()).await?;
     handlers::general::handle_event(event.0, &event.1, ctx.clone()).await?;
+    handlers::moderation::handle_event(event.0, &event.1, ctx.clone()).await?;
+    handlers::censor::handle_event(event.0, &event.1, ctx.clone()).await?;
+    handlers::names::handle_event(event.0, &event.1, ctx.clone()).await?;
+    handlers::spam::handle_event(event.0, &event.1, ctx.clone()).await?;
+    handlers::raids::handle_event(event.0, &event.1, ctx.clone()).await?;
 
     // Bot stat handling "hooks". This can be converted into a match if we have more stats to register here.
     if let Event::MessageCreate(msg) = &event.1 {
@@ -1,19 +1,29 @@
 use std::cmp;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
+use chrono::Duration;
 use lazy_static::lazy_static;
 use log::{debug, info, trace};
+use twilight_model::applications::callback::{CallbackData, InteractionResponse};
+use twilight_model::applications::interaction::application_command::ApplicationCommand;
+use twilight_model::channel::message::MessageType;
+use twilight_model::channel::Message;
 use twilight_model::gateway::payload::MessageCreate;
 use twilight_model::guild::Permissions;
-use twilight_model::id::{GuildId, UserId};
+use twilight_model::id::{ChannelId, GuildId, MessageId, RoleId, UserId};
 
-use crate::cache::{CachedGuild, CachedMember, CachedUser};
+use crate::cache::{CachedChannel, CachedGuild, CachedMember, CachedRole, CachedUser};
 use crate::commands::{
+    meta::arguments::{self, ArgumentChoice},
     meta::nodes::{CommandNode, GearBotPermissions},
+    meta::slash_commands,
     ROOT_NODE,
 };
-use crate::core::{BotContext, CommandContext, CommandMessage, GuildConfig};
+use crate::core::{custom_commands, BotContext, CommandContext, CommandMessage, CommandSource, GuildConfig};
 use crate::error::{CommandError, EventHandlerError, ParseError};
 use crate::gearbot_error;
 use crate::translation::{FluArgs, GearBotString};
@@ -23,6 +33,26 @@ lazy_static! {
     static ref BLANK_CONFIG: Arc<GuildConfig> = Arc::new(GuildConfig::default());
 }
 
+/// Where a command was used and by who, looked up from the cache.
+struct CommandEnvironment {
+    channel: Arc<CachedChannel>,
+    author: Arc<CachedUser>,
+    guild: Option<Arc<CachedGuild>>,
+    member: Option<Arc<CachedMember>>,
+    config: Arc<GuildConfig>,
+    permissions: GearBotPermissions,
+}
+
+/// The name the usage of a command is counted under.
+fn command_name(command_nodes: &[Arc<CommandNode>]) -> String {
+    let mut name = String::new();
+    for node in command_nodes.iter().skip(1) {
+        name += "__";
+        name += &node.name
+    }
+    name
+}
+
 pub struct Parser {
     pub parts: Vec<String>,
     index: usize,
@@ -108,57 +138,22 @@ impl Parser {
         let command_nodes = parser.get_command();
 
         // Is there a valid node to execute?
-        let node = match command_nodes.last() {
-            Some(node) => node,
-            None => return Ok(()),
-        };
-
-        // Assemble the command's name
-        let mut name = String::new();
-        for node in command_nodes.iter().skip(1) {
-            name += "__";
-            name += &node.name
+        if command_nodes.is_empty() {
+            // If not, it might still be one of the custom commands of the guild
+            if let Some(guild_id) = message.guild_id {
+                Parser::run_custom_command(&parser, &message, guild_id).await?;
+            }
+            return Ok(());
         }
 
         let ctx = Arc::clone(&parser.ctx);
 
         let channel_id = message.channel_id;
-        let channel = match ctx.cache.get_channel(channel_id).await {
-            Some(channel) => channel,
-            None => return Err(EventHandlerError::UnknownChannel(channel_id)),
-        };
-
-        let author = match ctx.cache.get_user(message.author.id).await {
-            Some(author) => author,
-            None => return Err(EventHandlerError::UnknownUser(message.author.id)),
-        };
-
-        //get optional guild and member, as well as a config and calculate user permissions
-        let (guild, member, config, permissions) = if !channel.is_dm() {
-            let guild = match ctx.cache.get_guild(&message.guild_id.unwrap()).await {
-                Some(guild) => guild,
-                None => return Err(EventHandlerError::UnknownGuild(message.guild_id.unwrap())),
-            };
-
-            let member = match ctx.cache.get_member(&guild.id, &message.author.id).await {
-                Some(member) => member,
-                None => return Err(EventHandlerError::UnknownUser(message.author.id)),
-            };
-
-            let config = ctx.get_config(guild.id).await?;
-
-            let permissions = ctx.get_permissions_for(&guild, &member, &config).await;
-
-            (Some(guild), Some(member), config, permissions)
-        } else {
-            let mut perms = GearBotPermissions::empty() | BLANK_CONFIG.permission_groups[0].granted_perms;
-            ctx.apply_admin_perms(&message.author.id, &mut perms);
-            (None, None, Arc::clone(&BLANK_CONFIG), perms)
-        };
+        let environment = Parser::load_environment(&ctx, channel_id, message.guild_id, message.author.id).await?;
 
         // Silently ignore any DMs
         // TODO: Maybe return an error?
-        let guild = match guild {
+        let guild = match environment.guild {
             Some(g) => g,
             None => return Ok(()),
         };
@@ -166,24 +161,27 @@ impl Parser {
         let cmdm = CommandMessage {
             id: message.id,
             content: message.content,
-            author,
-            author_as_member: member,
-            channel,
+            author: environment.author,
+            author_as_member: environment.member,
+            channel: environment.channel,
             attachments: message.attachments,
             embeds: message.embeds,
             flags: message.flags,
             kind: message.kind,
             mention_everyone: message.mention_everyone,
             tts: message.tts,
+            source: CommandSource::Message(channel_id),
         };
 
-        let context = CommandContext::new(Arc::clone(&ctx), config, cmdm, guild, shard_id, parser, permissions);
-
-        if !permissions.contains(node.command_permission) {
-            let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
-            let _ = context.reply(GearBotString::MissingPermissions, args).await; //ignore result as there is nothing we can do if this fails
-            return Ok(());
-        }
+        let context = CommandContext::new(
+            Arc::clone(&ctx),
+            environment.config,
+            cmdm,
+            guild,
+            shard_id,
+            parser,
+            environment.permissions,
+        );
 
         //check if we can send a reply
         if !context.bot_has_channel_permissions(Permissions::SEND_MESSAGES).await {
@@ -193,7 +191,7 @@ impl Parser {
                 msg.author.username,
                 msg.author.discriminator,
                 msg.author.id,
-                name,
+                command_name(&command_nodes),
                 msg.channel.get_name(),
                 msg.channel.get_id()
             );
@@ -225,32 +223,244 @@ impl Parser {
             return Ok(());
         }
 
+        Parser::run(ctx, context, &command_nodes).await
+    }
+
+    /// Answers with the response of a custom command if the message starts with one of the triggers of the guild.
+    async fn run_custom_command(
+        parser: &Parser,
+        message: &Message,
+        guild_id: GuildId,
+    ) -> Result<(), EventHandlerError> {
+        let trigger = match parser.parts.first() {
+            Some(trigger) => trigger.to_lowercase(),
+            None => return Ok(()),
+        };
+
+        let ctx = &parser.ctx;
+        let commands = ctx.get_custom_commands(guild_id).await?;
+        let response = match commands.get(&trigger) {
+            Some(response) => response,
+            None => return Ok(()),
+        };
+
+        // Not being able to answer is no different from there not being a command
+        let permissions = ctx
+            .get_channel_permissions_for(ctx.bot_user.id, message.channel_id)
+            .await;
+        if !permissions.contains(Permissions::SEND_MESSAGES) {
+            return Ok(());
+        }
+
+        let response =
+            custom_commands::fill_placeholders(response, message.author.id, message.channel_id, &parser.parts[1..]);
+        if let Ok(request) = ctx.http.create_message(message.channel_id).content(response) {
+            request.await?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs a slash command. Discord only waits a few seconds for the interaction to be answered, so it gets
+    /// acknowledged right away and the replies of the command are sent as follow ups.
+    pub async fn handle_interaction(
+        command: Box<ApplicationCommand>,
+        ctx: Arc<BotContext>,
+        shard_id: u64,
+    ) -> Result<(), EventHandlerError> {
+        trace!("Parser processing slash command: {:?}", command.data);
+
+        // the commands registered with discord might be outdated
+        let (command_nodes, values) = match slash_commands::resolve(&command.data) {
+            Some(resolved) => resolved,
+            None => return Ok(()),
+        };
+
+        let (guild_id, author_id) = match (command.guild_id, command.member.as_ref().and_then(|m| m.user.as_ref())) {
+            (Some(guild_id), Some(user)) => (guild_id, user.id),
+            _ => {
+                let message = format!("{} {}", Emoji::No.for_chat(), CommandError::NoDM);
+                return Parser::answer_interaction(&ctx, &command, Some(message)).await;
+            }
+        };
+
+        // this has to happen before deferring, once deferred the author is left waiting if nothing follows
+        let environment = match Parser::load_environment(&ctx, command.channel_id, Some(guild_id), author_id).await {
+            Ok(environment) => environment,
+            Err(e) => {
+                let message = format!(
+                    "{} Something went very wrong trying to execute that command, please try again later or report this on the support server {}",
+                    Emoji::Bug.for_chat(),
+                    Emoji::Bug.for_chat()
+                );
+                Parser::answer_interaction(&ctx, &command, Some(message)).await?;
+                return Err(e);
+            }
+        };
+        let guild = match environment.guild {
+            Some(guild) => guild,
+            None => {
+                let message = format!("{} {}", Emoji::No.for_chat(), CommandError::NoDM);
+                return Parser::answer_interaction(&ctx, &command, Some(message)).await;
+            }
+        };
+
+        Parser::answer_interaction(&ctx, &command, None).await?;
+
+        let parser = Parser {
+            parts: values,
+            index: 0,
+            ctx: Arc::clone(&ctx),
+            shard_id,
+            guild_id: Some(guild_id),
+        };
+
+        let cmdm = CommandMessage {
+            // There is no message, but the id of the interaction is just as much a timestamp
+            id: MessageId(command.id.0),
+            content: String::new(),
+            author: environment.author,
+            author_as_member: environment.member,
+            channel: environment.channel,
+            attachments: vec![],
+            embeds: vec![],
+            flags: None,
+            kind: MessageType::Regular,
+            mention_everyone: false,
+            tts: false,
+            source: CommandSource::Interaction {
+                application_id: command.application_id,
+                token: command.token.clone(),
+            },
+        };
+
+        let context = CommandContext::new(
+            Arc::clone(&ctx),
+            environment.config,
+            cmdm,
+            guild,
+            shard_id,
+            parser,
+            environment.permissions,
+        );
+
+        Parser::run(ctx, context, &command_nodes).await
+    }
+
+    /// Answers the interaction with a message, or defers it when there is no content yet so the replies can be sent as
+    /// follow ups. It can only be answered once.
+    async fn answer_interaction(
+        ctx: &BotContext,
+        command: &ApplicationCommand,
+        content: Option<String>,
+    ) -> Result<(), EventHandlerError> {
+        let data = CallbackData {
+            allowed_mentions: None,
+            content,
+            embeds: vec![],
+            flags: None,
+            tts: None,
+        };
+        let response = if data.content.is_some() {
+            InteractionResponse::ChannelMessageWithSource(data)
+        } else {
+            InteractionResponse::DeferredChannelMessageWithSource(data)
+        };
+
+        ctx.http
+            .interaction_callback(command.id, &command.token, response)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Looks up where a command was used and by who. The permissions are those of the author in the guild, or the ones
+    /// everyone gets in DMs.
+    async fn load_environment(
+        ctx: &BotContext,
+        channel_id: ChannelId,
+        guild_id: Option<GuildId>,
+        author_id: UserId,
+    ) -> Result<CommandEnvironment, EventHandlerError> {
+        let channel = match ctx.cache.get_channel(channel_id).await {
+            Some(channel) => channel,
+            None => return Err(EventHandlerError::UnknownChannel(channel_id)),
+        };
+
+        let author = match ctx.cache.get_user(author_id).await {
+            Some(author) => author,
+            None => return Err(EventHandlerError::UnknownUser(author_id)),
+        };
+
+        //get optional guild and member, as well as a config and calculate user permissions
+        let (guild, member, config, permissions) = if !channel.is_dm() {
+            let guild = match ctx.cache.get_guild(&guild_id.unwrap()).await {
+                Some(guild) => guild,
+                None => return Err(EventHandlerError::UnknownGuild(guild_id.unwrap())),
+            };
+
+            let member = match ctx.cache.get_member(&guild.id, &author_id).await {
+                Some(member) => member,
+                None => return Err(EventHandlerError::UnknownUser(author_id)),
+            };
+
+            let config = ctx.get_config(guild.id).await?;
+
+            let permissions = ctx.get_permissions_for(&guild, &member, &config).await;
+
+            (Some(guild), Some(member), config, permissions)
+        } else {
+            let mut perms = GearBotPermissions::empty() | BLANK_CONFIG.permission_groups[0].granted_perms;
+            ctx.apply_admin_perms(&author_id, &mut perms);
+            (None, None, Arc::clone(&BLANK_CONFIG), perms)
+        };
+
+        Ok(CommandEnvironment {
+            channel,
+            author,
+            guild,
+            member,
+            config,
+            permissions,
+        })
+    }
+
+    /// Runs the last of the nodes if the author is allowed to, telling them when something goes wrong.
+    async fn run(
+        ctx: Arc<BotContext>,
+        context: CommandContext,
+        command_nodes: &[Arc<CommandNode>],
+    ) -> Result<(), EventHandlerError> {
+        let node = command_nodes.last().unwrap();
+        let name = command_name(command_nodes);
+
+        if !context.permissions.contains(node.command_permission) {
+            let args = FluArgs::with_capacity(1).add("gearno", Emoji::No.for_chat()).generate();
+            let _ = context.reply(GearBotString::MissingPermissions, args).await; //ignore result as there is nothing we can do if this fails
+            return Ok(());
+        }
+
         match &node.handler {
             Some(handler) => {
+                // the context goes to the handler, errors are reported the same way it would have replied
+                let source = context.message.source.clone();
                 if let Err(e) = handler(context).await {
                     match e {
                         CommandError::ParseError(e) => {
-                            ctx.http
-                                .create_message(channel_id)
-                                .content(format!(
-                                    "{} Something went wrong trying to parse that: {}",
-                                    Emoji::No.for_chat(),
-                                    e
-                                ))
-                                .unwrap()
-                                .await?;
+                            let message = format!(
+                                "{} Something went wrong trying to parse that: {}",
+                                Emoji::No.for_chat(),
+                                e
+                            );
+                            source.respond(&ctx.http, Some(message), None, None).await?;
                         }
-                        CommandError::NoDM | CommandError::InvalidPermissions => {
-                            ctx.http
-                                .create_message(channel_id)
-                                .content(format!("{} {}", Emoji::No.for_chat(), e))
-                                .unwrap()
-                                .await?;
+                        CommandError::NoDM | CommandError::InvalidPermissions | CommandError::InvalidConfig(_) => {
+                            let message = format!("{} {}", Emoji::No.for_chat(), e);
+                            source.respond(&ctx.http, Some(message), None, None).await?;
                         }
                         CommandError::OtherFailure(e) => {
-                            ctx.http.create_message(channel_id)
-                                .content(format!("{} Something went very wrong trying to execute that command, please try again later or report this on the support server {}", Emoji::Bug.for_chat(), Emoji::Bug.for_chat())).unwrap()
-                                .await?;
+                            let message = format!("{} Something went very wrong trying to execute that command, please try again later or report this on the support server {}", Emoji::Bug.for_chat(), Emoji::Bug.for_chat());
+                            source.respond(&ctx.http, Some(message), None, None).await?;
 
                             //TODO: better logging
                             gearbot_error!("Command error: {}", e);
@@ -289,7 +499,93 @@ impl Parser {
         self.index < self.parts.len()
     }
 
-    async fn get_member(&mut self) -> Result<Arc<CachedMember>, ParseError> {
+    /// Takes everything that is left as text, which can't be empty.
+    pub fn get_text(&mut self) -> Result<String, ParseError> {
+        let text = self.get_remaining();
+        if text.is_empty() {
+            return Err(ParseError::MissingArgument);
+        }
+
+        self.index = self.parts.len();
+        Ok(text)
+    }
+
+    /// Parses what comes next as a whole number within the range
+    pub fn get_number<T>(&mut self, range: RangeInclusive<T>) -> Result<T, ParseError>
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        arguments::parse_number(self.get_next()?, range)
+    }
+
+    /// Parses what comes next as a duration like `30m` or `1d12h`
+    pub fn get_duration(&mut self) -> Result<Duration, ParseError> {
+        arguments::parse_duration(self.get_next()?)
+    }
+
+    /// Parses what comes next as one of the choices of an enum
+    pub fn get_choice<T: ArgumentChoice>(&mut self) -> Result<T, ParseError> {
+        arguments::parse_choice(self.get_next()?)
+    }
+
+    /// Parses what comes next as a message id or a jump link to a message on this server
+    pub fn get_message_id(&mut self) -> Result<MessageId, ParseError> {
+        let guild_id = self.get_guild_id()?;
+        arguments::parse_message_id(self.get_next()?, guild_id)
+    }
+
+    /// Parses what comes next as a role on this server, by mention, id or name
+    pub async fn get_role(&mut self) -> Result<Arc<CachedRole>, ParseError> {
+        let guild = self.get_guild().await?;
+        let input = self.get_next()?.to_string();
+
+        if let Some(id) = matchers::get_role_mention(&input).or_else(|| input.parse().ok()) {
+            return guild
+                .get_role(&RoleId(id))
+                .await
+                .ok_or(ParseError::RoleNotFoundById(id));
+        }
+
+        let roles = guild.roles.read().await;
+        let matches: Vec<&Arc<CachedRole>> = roles
+            .values()
+            .filter(|role| role.name.eq_ignore_ascii_case(&input))
+            .collect();
+
+        match matches.len().cmp(&1) {
+            cmp::Ordering::Equal => Ok(Arc::clone(matches[0])),
+            cmp::Ordering::Greater => Err(ParseError::MultipleRolesByName(input)),
+            cmp::Ordering::Less => Err(ParseError::RoleNotFoundByName(input)),
+        }
+    }
+
+    /// Parses what comes next as a channel on this server, by mention, id or name
+    pub async fn get_channel(&mut self) -> Result<Arc<CachedChannel>, ParseError> {
+        let guild = self.get_guild().await?;
+        let input = self.get_next()?.to_string();
+        let channels = guild.channels.read().await;
+
+        if let Some(id) = matchers::get_channel_mention(&input).or_else(|| input.parse().ok()) {
+            return channels
+                .get(&ChannelId(id))
+                .cloned()
+                .ok_or(ParseError::UnknownChannel(id));
+        }
+
+        let name = input.trim_start_matches('#');
+        let matches: Vec<&Arc<CachedChannel>> = channels
+            .values()
+            .filter(|channel| channel.get_name().eq_ignore_ascii_case(name))
+            .collect();
+
+        match matches.len().cmp(&1) {
+            cmp::Ordering::Equal => Ok(Arc::clone(matches[0])),
+            cmp::Ordering::Greater => Err(ParseError::MultipleChannelsByName(input)),
+            cmp::Ordering::Less => Err(ParseError::ChannelNotFoundByName(input)),
+        }
+    }
+
+    pub async fn get_member(&mut self) -> Result<Arc<CachedMember>, ParseError> {
         let cache = &Arc::clone(&self.ctx).cache;
         let guild = self.get_guild().await?;
 
@@ -377,7 +673,7 @@ impl Parser {
     }
 
     /// Parses what comes next as discord user
-    async fn get_user(&mut self) -> Result<Arc<CachedUser>, ParseError> {
+    pub async fn get_user(&mut self) -> Result<Arc<CachedUser>, ParseError> {
         match self.get_affected_user()? {
             Some(id) => Ok(self.ctx.get_user(UserId(id)).await?),
             None => {
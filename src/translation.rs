@@ -12,6 +12,9 @@ type FluentBundle = RawBundle<FluentResource, Memoizer>;
 use crate::gearbot_warn;
 
 const TRANSLATION_DIR: &str = "./lang";
+/// Descriptions of commands (and the names of their arguments) are looked up by the path of the command instead of
+/// through a [`GearBotString`], their keys all start with this.
+const COMMAND_DESCRIPTION_PREFIX: &str = "command__";
 const FAILED_TRANSLATE_FALLBACK_MSG: &str =
     "A translation error occured and no fallback could be found! Something may be wrong with the guild configuration!";
 
@@ -42,7 +45,26 @@ impl<'a> FluArgs<'a> {
     }
 }
 
+/// The key of the description of a command, `command` being the names of the command and its parents joined by `__`.
+fn command_description_key(command: &str) -> String {
+    format!("{}{}__description", COMMAND_DESCRIPTION_PREFIX, command)
+}
+
+/// The key of the name of an argument of a command, spaces in the name become underscores.
+fn command_argument_key(command: &str, argument: &str) -> String {
+    format!(
+        "{}{}__arg__{}",
+        COMMAND_DESCRIPTION_PREFIX,
+        command,
+        argument.replace(' ', "_")
+    )
+}
+
 impl Translations {
+    pub fn supports(&self, language: &LanguageIdentifier) -> bool {
+        self.0.contains_key(language)
+    }
+
     /// Retreives a string key to use when sending a message to chat that *does not* require arguments and can be sent as fetched with no
     /// further modifications.
     pub fn get_text_plain(&self, lang_key: &LanguageIdentifier, string_key: GearBotString) -> Cow<str> {
@@ -56,7 +78,7 @@ impl Translations {
 
             let value = lang_bundle.format_pattern(pattern, None, &mut errors);
 
-            handle_translation_error(&errors, string_key, false);
+            handle_translation_error(&errors, string_key.as_str(), false);
 
             value
         } else {
@@ -70,7 +92,7 @@ impl Translations {
 
                 let value = lang_bundle.format_pattern(pattern, None, &mut errors);
 
-                handle_translation_error(&errors, string_key, true);
+                handle_translation_error(&errors, string_key.as_str(), true);
 
                 value
             } else {
@@ -100,7 +122,7 @@ impl Translations {
 
             let value = lang_bundle.format_pattern(pattern, Some(args), &mut errors);
 
-            handle_translation_error(&errors, string_key, false);
+            handle_translation_error(&errors, string_key.as_str(), false);
 
             value
         } else {
@@ -114,7 +136,7 @@ impl Translations {
 
                 let value = lang_bundle.format_pattern(pattern, Some(args), &mut errors);
 
-                handle_translation_error(&errors, string_key, true);
+                handle_translation_error(&errors, string_key.as_str(), true);
 
                 value
             } else {
@@ -125,24 +147,52 @@ impl Translations {
         }
     }
 
+    /// Retreives the description of a command, `None` if there is none in either the language or the fallback.
+    pub fn get_command_description(&self, lang_key: &LanguageIdentifier, command: &str) -> Option<String> {
+        self.get_command_string(lang_key, command_description_key(command))
+    }
+
+    /// Retreives the name of an argument of a command, as it is shown in the usage.
+    pub fn get_command_argument(&self, lang_key: &LanguageIdentifier, command: &str, argument: &str) -> Option<String> {
+        self.get_command_string(lang_key, command_argument_key(command, argument))
+    }
+
+    fn get_command_string(&self, lang_key: &LanguageIdentifier, key: String) -> Option<String> {
+        let lang_bundle = self.0.get(lang_key).unwrap();
+
+        let (bundle, message, is_fallback) = match lang_bundle.get_message(&key) {
+            Some(message) => (lang_bundle, message, false),
+            None => {
+                let fallback_bundle = self.0.get(&DEFAULT_LANG).unwrap();
+                (fallback_bundle, fallback_bundle.get_message(&key)?, true)
+            }
+        };
+
+        let mut errors = Vec::new();
+        let value = bundle.format_pattern(message.value()?, None, &mut errors).to_string();
+        handle_translation_error(&errors, &key, is_fallback);
+
+        Some(value)
+    }
+
     pub fn get_translator(&self, lang: &LanguageIdentifier) -> Arc<FluentBundle> {
         Arc::clone(self.0.get(lang).unwrap())
     }
 }
 
-fn handle_translation_error(errors: &[FluentError], key: GearBotString, is_fallback: bool) {
+fn handle_translation_error(errors: &[FluentError], key: &str, is_fallback: bool) {
     for error in errors {
         if is_fallback {
             gearbot_warn!(
                 "A translation error occured and had to fallback to '{}' while trying to translate the **``{}``** key: ``{:?}``",
-                key.as_str(),
+                key,
                 DEFAULT_LANG,
                 error
             );
         } else {
             gearbot_warn!(
                 "A translation error occured while trying to translate the **``{}``** key: ``{:?}``",
-                key.as_str(),
+                key,
                 error
             );
         }
@@ -167,6 +217,89 @@ pub enum GearBotString {
     EmojiOverviewHeader,
     EmojiInfo,
 
+    // Moderation commands
+    InfSearchHeader,
+    InfSearchEmpty,
+    InfInfoHeader,
+    InfNotFound,
+    InfUpdated,
+    WarnConfirmation,
+    WarnConfirmationNoDm,
+    WarnDm,
+    ModerationHierarchyAuthor,
+    ModerationHierarchyBot,
+    ModerationBotMissingPermission,
+    KickConfirmation,
+    BanConfirmation,
+    TempbanConfirmation,
+    ForcebanConfirmation,
+    ForcebanAlreadyBanned,
+    UnbanConfirmation,
+    UnbanNotBanned,
+    MuteConfirmation,
+    MuteAlreadyMuted,
+    MuteNotConfigured,
+    MuteSetupDone,
+    MuteSetupFailed,
+    UnmuteConfirmation,
+    UnmuteNotMuted,
+    CleanDone,
+    CleanDoneTooOld,
+    CleanNothingFound,
+    RaidNotActive,
+    RaidEnded,
+    RaidEndedMore,
+    RaidBanDone,
+    RaidBanSkipped,
+
+    // Config commands
+    ConfigUpdated,
+    ConfigUnchanged,
+    ConfigInvalid,
+    ConfigWarnings,
+    ConfigIssueWrongVersion,
+    ConfigIssueEmptyPrefix,
+    ConfigIssuePrefixTooLong,
+    ConfigIssueUnsupportedLanguage,
+    ConfigIssueDuplicatePriority,
+    ConfigIssueUnknownLogChannel,
+    ConfigIssueUnknownChannel,
+    ConfigIssueUnknownRole,
+    ConfigIssueMissingLogPermissions,
+    ConfigIssueInvalidNameFallback,
+    ConfigIssueCensoredNameFallback,
+    ConfigHistoryHeader,
+    ConfigHistoryEmpty,
+    ConfigRevisionNotFound,
+    ConfigRevisionDiff,
+    ConfigRevisionSame,
+    ConfigExported,
+    ConfigImportNoFile,
+    ConfigImportTooBig,
+    ConfigImportMatched,
+    ConfigImportDropped,
+    ConfigImportDroppedGroups,
+    HelpPageHeader,
+    HelpFooter,
+    HelpCommandHeader,
+    HelpUsage,
+    HelpAliases,
+    HelpSubcommands,
+    HelpNotFound,
+
+    // Custom commands
+    CustomCommandCreated,
+    CustomCommandEdited,
+    CustomCommandRemoved,
+    CustomCommandExists,
+    CustomCommandNotFound,
+    CustomCommandBuiltIn,
+    CustomCommandInvalidTrigger,
+    CustomCommandResponseTooLong,
+    CustomCommandLimit,
+    CustomCommandList,
+    CustomCommandListEmpty,
+
     //General logs (Text)
     CommandUsedText,
 
@@ -174,6 +307,130 @@ pub enum GearBotString {
     CommandUsedEmbed,
     CommandUsedFooter,
 
+    //Moderation logs (Text)
+    WarnedText,
+    KickedText,
+    BannedText,
+    TempbannedText,
+    ForceBannedText,
+    UnbannedText,
+    TempbanExpiredText,
+    MuteExpiredText,
+    MutedText,
+    UnmutedText,
+
+    //Moderation logs (embed)
+    WarnedEmbed,
+    WarnedFooter,
+    KickedEmbed,
+    KickedFooter,
+    BannedEmbed,
+    BannedFooter,
+    TempbannedEmbed,
+    TempbannedFooter,
+    ForceBannedEmbed,
+    ForceBannedFooter,
+    UnbannedEmbed,
+    UnbannedFooter,
+    TempbanExpiredEmbed,
+    TempbanExpiredFooter,
+    MuteExpiredEmbed,
+    MuteExpiredFooter,
+    TempbanLiftFailedText,
+    MuteLiftFailedText,
+    TempbanLiftFailedEmbed,
+    TempbanLiftFailedFooter,
+    MuteLiftFailedEmbed,
+    MuteLiftFailedFooter,
+    MutedEmbed,
+    MutedFooter,
+    UnmutedEmbed,
+    UnmutedFooter,
+
+    //Member logs (Text)
+    MemberJoinText,
+    MemberLeaveText,
+    NicknameChangedText,
+    UsernameChangedText,
+    RolesAddedText,
+    RolesRemovedText,
+
+    //Member logs (embed)
+    MemberJoinEmbed,
+    MemberJoinFooter,
+    MemberLeaveEmbed,
+    MemberLeaveFooter,
+    NicknameChangedEmbed,
+    NicknameChangedFooter,
+    UsernameChangedEmbed,
+    UsernameChangedFooter,
+    RolesAddedEmbed,
+    RolesAddedFooter,
+    RolesRemovedEmbed,
+    RolesRemovedFooter,
+
+    //Message logs (Text)
+    MessageEditedText,
+    MessageDeletedText,
+    BulkDeleteText,
+
+    //Message logs (embed)
+    MessageEditedEmbed,
+    MessageEditedFooter,
+    MessageDeletedEmbed,
+    MessageDeletedFooter,
+    BulkDeleteEmbed,
+    BulkDeleteFooter,
+
+    //Censor logs (Text)
+    InviteCensoredText,
+    WordCensoredText,
+    CensorReasonInvite,
+    CensorReasonWord,
+    NameCensoredText,
+    CensorReasonHoisted,
+    AttachmentCensoredText,
+    LinkCensoredText,
+    CensorReasonAttachment,
+    CensorReasonLink,
+
+    //Censor logs (embed)
+    InviteCensoredEmbed,
+    InviteCensoredFooter,
+    WordCensoredEmbed,
+    WordCensoredFooter,
+    NameCensoredEmbed,
+    NameCensoredFooter,
+    AttachmentCensoredEmbed,
+    AttachmentCensoredFooter,
+    LinkCensoredEmbed,
+    LinkCensoredFooter,
+
+    //Spam logs (Text)
+    SpamDetectedText,
+    SpamInfractionReason,
+
+    //Spam logs (embed)
+    SpamDetectedEmbed,
+    SpamDetectedFooter,
+
+    //Raid logs (Text)
+    RaidStartedText,
+    RaidEndedText,
+    RaidJoinReason,
+    RaidBanReason,
+
+    //Raid logs (embed)
+    RaidStartedEmbed,
+    RaidStartedFooter,
+    RaidEndedEmbed,
+    RaidEndedFooter,
+
+    //Config logs
+    ConfigChangedText,
+    ConfigChangedEmbed,
+    ConfigChangedFooter,
+
     //Errors
     MissingPermissions,
 
@@ -202,6 +459,181 @@ impl GearBotString {
             GearBotString::CommandUsedText => "command_used_text",
             GearBotString::CommandUsedEmbed => "command_used_embed",
             GearBotString::CommandUsedFooter => "command_used_footer",
+            GearBotString::InfSearchHeader => "moderation__inf_search_header",
+            GearBotString::InfSearchEmpty => "moderation__inf_search_empty",
+            GearBotString::InfInfoHeader => "moderation__inf_info_header",
+            GearBotString::InfNotFound => "moderation__inf_not_found",
+            GearBotString::InfUpdated => "moderation__inf_updated",
+            GearBotString::WarnedText => "warned_text",
+            GearBotString::WarnedEmbed => "warned_embed",
+            GearBotString::WarnedFooter => "warned_footer",
+            GearBotString::WarnConfirmation => "moderation__warn_confirmation",
+            GearBotString::WarnConfirmationNoDm => "moderation__warn_confirmation_no_dm",
+            GearBotString::WarnDm => "moderation__warn_dm",
+            GearBotString::ModerationHierarchyAuthor => "moderation__hierarchy_author",
+            GearBotString::KickedText => "kicked_text",
+            GearBotString::BannedText => "banned_text",
+            GearBotString::TempbannedText => "tempbanned_text",
+            GearBotString::ForceBannedText => "force_banned_text",
+            GearBotString::UnbannedText => "unbanned_text",
+            GearBotString::KickedEmbed => "kicked_embed",
+            GearBotString::KickedFooter => "kicked_footer",
+            GearBotString::BannedEmbed => "banned_embed",
+            GearBotString::BannedFooter => "banned_footer",
+            GearBotString::TempbannedEmbed => "tempbanned_embed",
+            GearBotString::TempbannedFooter => "tempbanned_footer",
+            GearBotString::ForceBannedEmbed => "force_banned_embed",
+            GearBotString::ForceBannedFooter => "force_banned_footer",
+            GearBotString::UnbannedEmbed => "unbanned_embed",
+            GearBotString::UnbannedFooter => "unbanned_footer",
+            GearBotString::ModerationHierarchyBot => "moderation__hierarchy_bot",
+            GearBotString::ModerationBotMissingPermission => "moderation__bot_missing_permission",
+            GearBotString::KickConfirmation => "moderation__kick_confirmation",
+            GearBotString::BanConfirmation => "moderation__ban_confirmation",
+            GearBotString::TempbanConfirmation => "moderation__tempban_confirmation",
+            GearBotString::ForcebanConfirmation => "moderation__forceban_confirmation",
+            GearBotString::ForcebanAlreadyBanned => "moderation__forceban_already_banned",
+            GearBotString::UnbanConfirmation => "moderation__unban_confirmation",
+            GearBotString::UnbanNotBanned => "moderation__unban_not_banned",
+            GearBotString::TempbanExpiredText => "tempban_expired_text",
+            GearBotString::MuteExpiredText => "mute_expired_text",
+            GearBotString::TempbanExpiredEmbed => "tempban_expired_embed",
+            GearBotString::TempbanExpiredFooter => "tempban_expired_footer",
+            GearBotString::MuteExpiredEmbed => "mute_expired_embed",
+            GearBotString::MuteExpiredFooter => "mute_expired_footer",
+            GearBotString::TempbanLiftFailedText => "tempban_lift_failed_text",
+            GearBotString::MuteLiftFailedText => "mute_lift_failed_text",
+            GearBotString::TempbanLiftFailedEmbed => "tempban_lift_failed_embed",
+            GearBotString::TempbanLiftFailedFooter => "tempban_lift_failed_footer",
+            GearBotString::MuteLiftFailedEmbed => "mute_lift_failed_embed",
+            GearBotString::MuteLiftFailedFooter => "mute_lift_failed_footer",
+            GearBotString::MutedText => "muted_text",
+            GearBotString::UnmutedText => "unmuted_text",
+            GearBotString::MutedEmbed => "muted_embed",
+            GearBotString::MutedFooter => "muted_footer",
+            GearBotString::UnmutedEmbed => "unmuted_embed",
+            GearBotString::UnmutedFooter => "unmuted_footer",
+            GearBotString::MuteConfirmation => "moderation__mute_confirmation",
+            GearBotString::MuteAlreadyMuted => "moderation__mute_already_muted",
+            GearBotString::MuteNotConfigured => "moderation__mute_not_configured",
+            GearBotString::MuteSetupDone => "moderation__mute_setup_done",
+            GearBotString::MuteSetupFailed => "moderation__mute_setup_failed",
+            GearBotString::UnmuteConfirmation => "moderation__unmute_confirmation",
+            GearBotString::UnmuteNotMuted => "moderation__unmute_not_muted",
+            GearBotString::MemberJoinText => "member_join_text",
+            GearBotString::MemberLeaveText => "member_leave_text",
+            GearBotString::NicknameChangedText => "nickname_changed_text",
+            GearBotString::UsernameChangedText => "username_changed_text",
+            GearBotString::RolesAddedText => "roles_added_text",
+            GearBotString::RolesRemovedText => "roles_removed_text",
+            GearBotString::MemberJoinEmbed => "member_join_embed",
+            GearBotString::MemberJoinFooter => "member_join_footer",
+            GearBotString::MemberLeaveEmbed => "member_leave_embed",
+            GearBotString::MemberLeaveFooter => "member_leave_footer",
+            GearBotString::NicknameChangedEmbed => "nickname_changed_embed",
+            GearBotString::NicknameChangedFooter => "nickname_changed_footer",
+            GearBotString::UsernameChangedEmbed => "username_changed_embed",
+            GearBotString::UsernameChangedFooter => "username_changed_footer",
+            GearBotString::RolesAddedEmbed => "roles_added_embed",
+            GearBotString::RolesAddedFooter => "roles_added_footer",
+            GearBotString::RolesRemovedEmbed => "roles_removed_embed",
+            GearBotString::RolesRemovedFooter => "roles_removed_footer",
+            GearBotString::MessageEditedText => "message_edited_text",
+            GearBotString::MessageDeletedText => "message_deleted_text",
+            GearBotString::MessageEditedEmbed => "message_edited_embed",
+            GearBotString::MessageEditedFooter => "message_edited_footer",
+            GearBotString::MessageDeletedEmbed => "message_deleted_embed",
+            GearBotString::MessageDeletedFooter => "message_deleted_footer",
+            GearBotString::BulkDeleteText => "bulk_delete_text",
+            GearBotString::BulkDeleteEmbed => "bulk_delete_embed",
+            GearBotString::BulkDeleteFooter => "bulk_delete_footer",
+            GearBotString::CleanDone => "moderation__clean_done",
+            GearBotString::CleanDoneTooOld => "moderation__clean_done_too_old",
+            GearBotString::CleanNothingFound => "moderation__clean_nothing_found",
+            GearBotString::InviteCensoredText => "invite_censored_text",
+            GearBotString::InviteCensoredEmbed => "invite_censored_embed",
+            GearBotString::InviteCensoredFooter => "invite_censored_footer",
+            GearBotString::WordCensoredText => "word_censored_text",
+            GearBotString::CensorReasonInvite => "censor_reason_invite",
+            GearBotString::CensorReasonWord => "censor_reason_word",
+            GearBotString::WordCensoredEmbed => "word_censored_embed",
+            GearBotString::WordCensoredFooter => "word_censored_footer",
+            GearBotString::SpamDetectedText => "spam_detected_text",
+            GearBotString::SpamInfractionReason => "spam_infraction_reason",
+            GearBotString::SpamDetectedEmbed => "spam_detected_embed",
+            GearBotString::SpamDetectedFooter => "spam_detected_footer",
+            GearBotString::RaidStartedText => "raid_started_text",
+            GearBotString::RaidEndedText => "raid_ended_text",
+            GearBotString::RaidStartedEmbed => "raid_started_embed",
+            GearBotString::RaidStartedFooter => "raid_started_footer",
+            GearBotString::RaidEndedEmbed => "raid_ended_embed",
+            GearBotString::RaidEndedFooter => "raid_ended_footer",
+            GearBotString::RaidJoinReason => "raid_join_reason",
+            GearBotString::RaidBanReason => "raid_ban_reason",
+            GearBotString::RaidNotActive => "moderation__raid_not_active",
+            GearBotString::RaidEnded => "moderation__raid_ended",
+            GearBotString::RaidEndedMore => "moderation__raid_ended_more",
+            GearBotString::RaidBanDone => "moderation__raid_ban_done",
+            GearBotString::RaidBanSkipped => "moderation__raid_ban_skipped",
+            GearBotString::NameCensoredText => "name_censored_text",
+            GearBotString::CensorReasonHoisted => "censor_reason_hoisted",
+            GearBotString::NameCensoredEmbed => "name_censored_embed",
+            GearBotString::NameCensoredFooter => "name_censored_footer",
+            GearBotString::AttachmentCensoredText => "attachment_censored_text",
+            GearBotString::LinkCensoredText => "link_censored_text",
+            GearBotString::CensorReasonAttachment => "censor_reason_attachment",
+            GearBotString::CensorReasonLink => "censor_reason_link",
+            GearBotString::AttachmentCensoredEmbed => "attachment_censored_embed",
+            GearBotString::AttachmentCensoredFooter => "attachment_censored_footer",
+            GearBotString::LinkCensoredEmbed => "link_censored_embed",
+            GearBotString::LinkCensoredFooter => "link_censored_footer",
+            GearBotString::ConfigUpdated => "config__updated",
+            GearBotString::ConfigUnchanged => "config__unchanged",
+            GearBotString::ConfigInvalid => "config__invalid",
+            GearBotString::ConfigWarnings => "config__warnings",
+            GearBotString::ConfigIssueEmptyPrefix => "config__issue_empty_prefix",
+            GearBotString::ConfigIssuePrefixTooLong => "config__issue_prefix_too_long",
+            GearBotString::ConfigIssueUnsupportedLanguage => "config__issue_unsupported_language",
+            GearBotString::ConfigIssueDuplicatePriority => "config__issue_duplicate_priority",
+            GearBotString::ConfigIssueUnknownLogChannel => "config__issue_unknown_log_channel",
+            GearBotString::ConfigIssueUnknownChannel => "config__issue_unknown_channel",
+            GearBotString::ConfigIssueUnknownRole => "config__issue_unknown_role",
+            GearBotString::ConfigIssueMissingLogPermissions => "config__issue_missing_log_permissions",
+            GearBotString::ConfigIssueInvalidNameFallback => "config__issue_invalid_name_fallback",
+            GearBotString::ConfigIssueCensoredNameFallback => "config__issue_censored_name_fallback",
+            GearBotString::ConfigChangedText => "config_changed_text",
+            GearBotString::ConfigChangedEmbed => "config_changed_embed",
+            GearBotString::ConfigChangedFooter => "config_changed_footer",
+            GearBotString::ConfigHistoryHeader => "config__history_header",
+            GearBotString::ConfigHistoryEmpty => "config__history_empty",
+            GearBotString::ConfigRevisionNotFound => "config__revision_not_found",
+            GearBotString::ConfigRevisionDiff => "config__revision_diff",
+            GearBotString::ConfigRevisionSame => "config__revision_same",
+            GearBotString::ConfigIssueWrongVersion => "config__issue_wrong_version",
+            GearBotString::ConfigExported => "config__exported",
+            GearBotString::ConfigImportNoFile => "config__import_no_file",
+            GearBotString::ConfigImportTooBig => "config__import_too_big",
+            GearBotString::ConfigImportMatched => "config__import_matched",
+            GearBotString::ConfigImportDropped => "config__import_dropped",
+            GearBotString::ConfigImportDroppedGroups => "config__import_dropped_groups",
+            GearBotString::HelpPageHeader => "help__page_header",
+            GearBotString::HelpFooter => "help__footer",
+            GearBotString::HelpCommandHeader => "help__command_header",
+            GearBotString::HelpUsage => "help__usage",
+            GearBotString::HelpAliases => "help__aliases",
+            GearBotString::HelpSubcommands => "help__subcommands",
+            GearBotString::HelpNotFound => "help__not_found",
+            GearBotString::CustomCommandCreated => "custom_commands__created",
+            GearBotString::CustomCommandEdited => "custom_commands__edited",
+            GearBotString::CustomCommandRemoved => "custom_commands__removed",
+            GearBotString::CustomCommandExists => "custom_commands__exists",
+            GearBotString::CustomCommandNotFound => "custom_commands__not_found",
+            GearBotString::CustomCommandBuiltIn => "custom_commands__built_in",
+            GearBotString::CustomCommandInvalidTrigger => "custom_commands__invalid_trigger",
+            GearBotString::CustomCommandResponseTooLong => "custom_commands__response_too_long",
+            GearBotString::CustomCommandLimit => "custom_commands__limit",
+            GearBotString::CustomCommandList => "custom_commands__list",
+            GearBotString::CustomCommandListEmpty => "custom_commands__list_empty",
         }
     }
 
@@ -259,13 +691,17 @@ pub fn load_translations() -> Translations {
 
 #[cfg(test)]
 mod tests {
-    use super::{GearBotString, TRANSLATION_DIR};
+    use super::{
+        command_argument_key, command_description_key, GearBotString, COMMAND_DESCRIPTION_PREFIX, TRANSLATION_DIR,
+    };
+    use crate::commands::meta::nodes::CommandNode;
+    use crate::commands::ROOT_NODE;
     use lazy_static::lazy_static;
     use std::collections::HashMap;
     use std::fs;
 
     lazy_static! {
-        static ref ALL_TRANSLATION_STR_KEYS: [&'static str; 17] = [
+        static ref ALL_TRANSLATION_STR_KEYS: [&'static str; 192] = [
             GearBotString::PingPong.as_str(),
             GearBotString::CoinflipDefault.as_str(),
             GearBotString::CoinflipYes.as_str(),
@@ -283,10 +719,194 @@ mod tests {
             GearBotString::CommandUsedText.as_str(),
             GearBotString::CommandUsedEmbed.as_str(),
             GearBotString::CommandUsedFooter.as_str(),
+            GearBotString::InfSearchHeader.as_str(),
+            GearBotString::InfSearchEmpty.as_str(),
+            GearBotString::InfInfoHeader.as_str(),
+            GearBotString::InfNotFound.as_str(),
+            GearBotString::InfUpdated.as_str(),
+            GearBotString::WarnedText.as_str(),
+            GearBotString::WarnedEmbed.as_str(),
+            GearBotString::WarnedFooter.as_str(),
+            GearBotString::WarnConfirmation.as_str(),
+            GearBotString::WarnConfirmationNoDm.as_str(),
+            GearBotString::WarnDm.as_str(),
+            GearBotString::ModerationHierarchyAuthor.as_str(),
+            GearBotString::KickedText.as_str(),
+            GearBotString::BannedText.as_str(),
+            GearBotString::TempbannedText.as_str(),
+            GearBotString::ForceBannedText.as_str(),
+            GearBotString::UnbannedText.as_str(),
+            GearBotString::KickedEmbed.as_str(),
+            GearBotString::KickedFooter.as_str(),
+            GearBotString::BannedEmbed.as_str(),
+            GearBotString::BannedFooter.as_str(),
+            GearBotString::TempbannedEmbed.as_str(),
+            GearBotString::TempbannedFooter.as_str(),
+            GearBotString::ForceBannedEmbed.as_str(),
+            GearBotString::ForceBannedFooter.as_str(),
+            GearBotString::UnbannedEmbed.as_str(),
+            GearBotString::UnbannedFooter.as_str(),
+            GearBotString::ModerationHierarchyBot.as_str(),
+            GearBotString::ModerationBotMissingPermission.as_str(),
+            GearBotString::KickConfirmation.as_str(),
+            GearBotString::BanConfirmation.as_str(),
+            GearBotString::TempbanConfirmation.as_str(),
+            GearBotString::ForcebanConfirmation.as_str(),
+            GearBotString::ForcebanAlreadyBanned.as_str(),
+            GearBotString::UnbanConfirmation.as_str(),
+            GearBotString::UnbanNotBanned.as_str(),
+            GearBotString::TempbanExpiredText.as_str(),
+            GearBotString::MuteExpiredText.as_str(),
+            GearBotString::TempbanExpiredEmbed.as_str(),
+            GearBotString::TempbanExpiredFooter.as_str(),
+            GearBotString::MuteExpiredEmbed.as_str(),
+            GearBotString::MuteExpiredFooter.as_str(),
+            GearBotString::TempbanLiftFailedText.as_str(),
+            GearBotString::MuteLiftFailedText.as_str(),
+            GearBotString::TempbanLiftFailedEmbed.as_str(),
+            GearBotString::TempbanLiftFailedFooter.as_str(),
+            GearBotString::MuteLiftFailedEmbed.as_str(),
+            GearBotString::MuteLiftFailedFooter.as_str(),
+            GearBotString::MutedText.as_str(),
+            GearBotString::UnmutedText.as_str(),
+            GearBotString::MutedEmbed.as_str(),
+            GearBotString::MutedFooter.as_str(),
+            GearBotString::UnmutedEmbed.as_str(),
+            GearBotString::UnmutedFooter.as_str(),
+            GearBotString::MuteConfirmation.as_str(),
+            GearBotString::MuteAlreadyMuted.as_str(),
+            GearBotString::MuteNotConfigured.as_str(),
+            GearBotString::MuteSetupDone.as_str(),
+            GearBotString::MuteSetupFailed.as_str(),
+            GearBotString::UnmuteConfirmation.as_str(),
+            GearBotString::UnmuteNotMuted.as_str(),
+            GearBotString::MemberJoinText.as_str(),
+            GearBotString::MemberLeaveText.as_str(),
+            GearBotString::NicknameChangedText.as_str(),
+            GearBotString::UsernameChangedText.as_str(),
+            GearBotString::RolesAddedText.as_str(),
+            GearBotString::RolesRemovedText.as_str(),
+            GearBotString::MemberJoinEmbed.as_str(),
+            GearBotString::MemberJoinFooter.as_str(),
+            GearBotString::MemberLeaveEmbed.as_str(),
+            GearBotString::MemberLeaveFooter.as_str(),
+            GearBotString::NicknameChangedEmbed.as_str(),
+            GearBotString::NicknameChangedFooter.as_str(),
+            GearBotString::UsernameChangedEmbed.as_str(),
+            GearBotString::UsernameChangedFooter.as_str(),
+            GearBotString::RolesAddedEmbed.as_str(),
+            GearBotString::RolesAddedFooter.as_str(),
+            GearBotString::RolesRemovedEmbed.as_str(),
+            GearBotString::RolesRemovedFooter.as_str(),
+            GearBotString::MessageEditedText.as_str(),
+            GearBotString::MessageDeletedText.as_str(),
+            GearBotString::MessageEditedEmbed.as_str(),
+            GearBotString::MessageEditedFooter.as_str(),
+            GearBotString::MessageDeletedEmbed.as_str(),
+            GearBotString::MessageDeletedFooter.as_str(),
+            GearBotString::BulkDeleteText.as_str(),
+            GearBotString::BulkDeleteEmbed.as_str(),
+            GearBotString::BulkDeleteFooter.as_str(),
+            GearBotString::CleanDone.as_str(),
+            GearBotString::CleanDoneTooOld.as_str(),
+            GearBotString::CleanNothingFound.as_str(),
+            GearBotString::InviteCensoredText.as_str(),
+            GearBotString::InviteCensoredEmbed.as_str(),
+            GearBotString::InviteCensoredFooter.as_str(),
+            GearBotString::WordCensoredText.as_str(),
+            GearBotString::CensorReasonInvite.as_str(),
+            GearBotString::CensorReasonWord.as_str(),
+            GearBotString::WordCensoredEmbed.as_str(),
+            GearBotString::WordCensoredFooter.as_str(),
+            GearBotString::SpamDetectedText.as_str(),
+            GearBotString::SpamInfractionReason.as_str(),
+            GearBotString::SpamDetectedEmbed.as_str(),
+            GearBotString::SpamDetectedFooter.as_str(),
+            GearBotString::RaidStartedText.as_str(),
+            GearBotString::RaidEndedText.as_str(),
+            GearBotString::RaidStartedEmbed.as_str(),
+            GearBotString::RaidStartedFooter.as_str(),
+            GearBotString::RaidEndedEmbed.as_str(),
+            GearBotString::RaidEndedFooter.as_str(),
+            GearBotString::RaidJoinReason.as_str(),
+            GearBotString::RaidBanReason.as_str(),
+            GearBotString::RaidNotActive.as_str(),
+            GearBotString::RaidEnded.as_str(),
+            GearBotString::RaidEndedMore.as_str(),
+            GearBotString::RaidBanDone.as_str(),
+            GearBotString::RaidBanSkipped.as_str(),
+            GearBotString::NameCensoredText.as_str(),
+            GearBotString::CensorReasonHoisted.as_str(),
+            GearBotString::NameCensoredEmbed.as_str(),
+            GearBotString::NameCensoredFooter.as_str(),
+            GearBotString::AttachmentCensoredText.as_str(),
+            GearBotString::LinkCensoredText.as_str(),
+            GearBotString::CensorReasonAttachment.as_str(),
+            GearBotString::CensorReasonLink.as_str(),
+            GearBotString::AttachmentCensoredEmbed.as_str(),
+            GearBotString::AttachmentCensoredFooter.as_str(),
+            GearBotString::LinkCensoredEmbed.as_str(),
+            GearBotString::LinkCensoredFooter.as_str(),
+            GearBotString::ConfigUpdated.as_str(),
+            GearBotString::ConfigUnchanged.as_str(),
+            GearBotString::ConfigInvalid.as_str(),
+            GearBotString::ConfigWarnings.as_str(),
+            GearBotString::ConfigIssueEmptyPrefix.as_str(),
+            GearBotString::ConfigIssuePrefixTooLong.as_str(),
+            GearBotString::ConfigIssueUnsupportedLanguage.as_str(),
+            GearBotString::ConfigIssueDuplicatePriority.as_str(),
+            GearBotString::ConfigIssueUnknownLogChannel.as_str(),
+            GearBotString::ConfigIssueUnknownChannel.as_str(),
+            GearBotString::ConfigIssueUnknownRole.as_str(),
+            GearBotString::ConfigIssueMissingLogPermissions.as_str(),
+            GearBotString::ConfigIssueInvalidNameFallback.as_str(),
+            GearBotString::ConfigIssueCensoredNameFallback.as_str(),
+            GearBotString::ConfigChangedText.as_str(),
+            GearBotString::ConfigChangedEmbed.as_str(),
+            GearBotString::ConfigChangedFooter.as_str(),
+            GearBotString::ConfigHistoryHeader.as_str(),
+            GearBotString::ConfigHistoryEmpty.as_str(),
+            GearBotString::ConfigRevisionNotFound.as_str(),
+            GearBotString::ConfigRevisionDiff.as_str(),
+            GearBotString::ConfigRevisionSame.as_str(),
+            GearBotString::ConfigIssueWrongVersion.as_str(),
+            GearBotString::ConfigExported.as_str(),
+            GearBotString::ConfigImportNoFile.as_str(),
+            GearBotString::ConfigImportTooBig.as_str(),
+            GearBotString::ConfigImportMatched.as_str(),
+            GearBotString::ConfigImportDropped.as_str(),
+            GearBotString::ConfigImportDroppedGroups.as_str(),
+            GearBotString::HelpPageHeader.as_str(),
+            GearBotString::HelpFooter.as_str(),
+            GearBotString::HelpCommandHeader.as_str(),
+            GearBotString::HelpUsage.as_str(),
+            GearBotString::HelpAliases.as_str(),
+            GearBotString::HelpSubcommands.as_str(),
+            GearBotString::HelpNotFound.as_str(),
+            GearBotString::CustomCommandCreated.as_str(),
+            GearBotString::CustomCommandEdited.as_str(),
+            GearBotString::CustomCommandRemoved.as_str(),
+            GearBotString::CustomCommandExists.as_str(),
+            GearBotString::CustomCommandNotFound.as_str(),
+            GearBotString::CustomCommandBuiltIn.as_str(),
+            GearBotString::CustomCommandInvalidTrigger.as_str(),
+            GearBotString::CustomCommandResponseTooLong.as_str(),
+            GearBotString::CustomCommandLimit.as_str(),
+            GearBotString::CustomCommandList.as_str(),
+            GearBotString::CustomCommandListEmpty.as_str(),
         ];
     }
 
     fn load_translations(lang: &str) -> HashMap<String, String> {
+        let mut t_data = load_all_translations(lang);
+        // These are looked up by command instead, `commands_are_described` covers them
+        t_data.retain(|key, _| !key.starts_with(COMMAND_DESCRIPTION_PREFIX));
+
+        assert_eq!(t_data.len(), ALL_TRANSLATION_STR_KEYS.len());
+        t_data
+    }
+
+    fn load_all_translations(lang: &str) -> HashMap<String, String> {
         let mut t_data = HashMap::new();
         let path = format!("{}/{}", TRANSLATION_DIR, lang);
         for t_file in fs::read_dir(path).unwrap() {
@@ -301,10 +921,24 @@ mod tests {
             t_data.extend(t_part)
         }
 
-        assert_eq!(t_data.len(), ALL_TRANSLATION_STR_KEYS.len());
         t_data
     }
 
+    fn command_keys(node: &CommandNode, parent: Option<&str>, keys: &mut Vec<String>) {
+        let path = match parent {
+            Some(parent) => format!("{}__{}", parent, node.name),
+            None => node.name.clone(),
+        };
+        keys.push(command_description_key(&path));
+        for argument in &node.arguments {
+            keys.push(command_argument_key(&path, argument.name));
+        }
+
+        for sub_node in &node.node_list {
+            command_keys(sub_node, Some(&path), keys);
+        }
+    }
+
     #[test]
     fn enum_variants_translation_coverage() {
         let translation_data = load_translations("en_US");
@@ -334,4 +968,28 @@ mod tests {
         // Make sure we exhausted everything
         assert_eq!(covered, ALL_TRANSLATION_STR_KEYS.len())
     }
+    #[test]
+    fn commands_are_described() {
+        let translation_data = load_all_translations("en_US");
+
+        let mut keys = vec![];
+        for command in &ROOT_NODE.command_list {
+            command_keys(command, None, &mut keys);
+        }
+
+        for key in &keys {
+            if !translation_data.contains_key(key) {
+                panic!("missing command translation: {}", key)
+            }
+        }
+
+        for key in translation_data
+            .keys()
+            .filter(|key| key.starts_with(COMMAND_DESCRIPTION_PREFIX))
+        {
+            if !keys.contains(key) {
+                panic!("translation for an unknown command or argument: {}", key)
+            }
+        }
+    }
 }
@@ -27,6 +27,21 @@ define_emoji!(
     Left => "⬅️",
     Right => "➡️",
     Online => "🟢",
+    Kick => "👢",
+    Ban => "🚪",
+    Unban => "🔓",
+    Mute => "🔇",
+    Unmute => "🔊",
+    Join => "📥",
+    Leave => "📤",
+    NameChange => "🏷️",
+    RoleAdd => "➕",
+    RoleRemove => "➖",
+    MessageEdit => "📝",
+    MessageDelete => "🗑️",
+    Censor => "🤐",
+    Spam => "🛑",
+    Raid => "🚨",
 
     StaffBadge => "",
     PartnerBadge => "",
@@ -1,4 +1,5 @@
 use super::emoji::{EmojiInfo, ANIMATED_EMOTE_KEY};
+use chrono::Duration;
 use regex::{Match, Regex, RegexBuilder};
 use url::{Host, Url};
 
@@ -29,6 +30,14 @@ pub fn contains_mention(msg: &str) -> bool {
     MENTION_MATCHER.is_match(msg)
 }
 
+pub fn count_mentions(msg: &str) -> usize {
+    if !contains_mention(msg) {
+        return 0;
+    }
+
+    MENTION_MATCHER.find_iter(msg).count()
+}
+
 pub fn get_mention(msg: &str) -> Option<u64> {
     MENTION_MATCHER_SOLO
         .captures(msg)
@@ -44,6 +53,33 @@ pub fn get_urls<'a>(msg: &'a str) -> Vec<Match<'a>> {
     URL_MATCHER.find_iter(msg).collect()
 }
 
+/// The (lowercase) domains of all links in a message.
+pub fn get_url_domains(msg: &str) -> Vec<String> {
+    get_urls(msg)
+        .iter()
+        .filter_map(|url| Url::parse(url.as_str()).ok())
+        .filter_map(|url| match url.host() {
+            Some(Host::Domain(domain)) => Some(domain.to_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Checks if a domain is the given one, or one of its subdomains.
+pub fn is_on_domain(domain: &str, parent: &str) -> bool {
+    let domain = domain.to_lowercase();
+    let parent = parent.trim_start_matches('.').to_lowercase();
+    domain == parent || domain.ends_with(&format!(".{}", parent))
+}
+
+/// The lowercase extension of a filename, without the dot. Files without one have an empty extension.
+pub fn file_extension(filename: &str) -> String {
+    match filename.rfind('.') {
+        Some(dot) => filename[dot + 1..].to_lowercase(),
+        None => String::new(),
+    }
+}
+
 pub fn contains_emote(msg: &str) -> bool {
     EMOJI_MATCHER.is_match(msg)
 }
@@ -52,10 +88,34 @@ pub fn get_emotes<'a>(msg: &'a str) -> Vec<Match<'a>> {
     EMOJI_MATCHER.find_iter(msg).collect()
 }
 
+pub fn get_role_mention(msg: &str) -> Option<u64> {
+    ROLE_MENTION_MATCHER_SOLO
+        .captures(msg)
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
+pub fn get_channel_mention(msg: &str) -> Option<u64> {
+    CHANNEL_MENTION_MATCHER_SOLO
+        .captures(msg)
+        .and_then(|c| c.get(1))
+        .and_then(|m| m.as_str().parse().ok())
+}
+
 pub fn contains_jump_link(msg: &str) -> bool {
     JUMP_LINK_MATCHER.is_match(msg)
 }
 
+/// Takes the first jump link in a message and returns `(guild id, channel id, message id)`
+pub fn get_jump_link(msg: &str) -> Option<(u64, u64, u64)> {
+    let captures = JUMP_LINK_MATCHER.captures(msg)?;
+    Some((
+        captures[1].parse().ok()?,
+        captures[2].parse().ok()?,
+        captures[3].parse().ok()?,
+    ))
+}
+
 pub fn starts_with_number(msg: &str) -> bool {
     msg.chars().next().map(|c| c.is_numeric()).unwrap_or(false)
 }
@@ -91,6 +151,55 @@ pub fn contains_invite_link(msg: &str) -> bool {
     false
 }
 
+/// An invite link that was found in a message.
+#[derive(Debug, PartialEq)]
+pub enum InviteLink<'a> {
+    /// A discord invite, the code can be looked up to see where it leads.
+    Discord(&'a str),
+    /// A link from one of the invite listing sites, there is no way to tell where these lead.
+    ThirdParty(&'a str),
+}
+
+/// Finds all invite links in a message, including the ones that were posted without `https://` in front.
+pub fn get_invite_links(msg: &str) -> Vec<InviteLink<'_>> {
+    INVITE_MATCHER
+        .captures_iter(msg)
+        .map(|caps| {
+            if caps.get(1).is_some() {
+                InviteLink::Discord(caps.get(2).unwrap().as_str())
+            } else {
+                InviteLink::ThirdParty(caps.get(0).unwrap().as_str())
+            }
+        })
+        .collect()
+}
+
+/// Parses durations like `30m` or `1d12h`, supporting weeks, days, hours, minutes and seconds.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    if !DURATION_MATCHER.is_match(input) {
+        return None;
+    }
+
+    let mut seconds: i64 = 0;
+    for part in DURATION_PART_MATCHER.captures_iter(input) {
+        let amount: i64 = part[1].parse().ok()?;
+        let unit = match part[2].to_ascii_lowercase().as_str() {
+            "w" => 7 * 24 * 60 * 60,
+            "d" => 24 * 60 * 60,
+            "h" => 60 * 60,
+            "m" => 60,
+            _ => 1,
+        };
+        seconds = seconds.checked_add(amount.checked_mul(unit)?)?;
+    }
+
+    if seconds == 0 || seconds > Duration::max_value().num_seconds() {
+        None
+    } else {
+        Some(Duration::seconds(seconds))
+    }
+}
+
 pub fn get_emoji_parts(msg: &str) -> Vec<EmojiInfo> {
     if !contains_emote(msg) {
         return vec![];
@@ -113,10 +222,26 @@ lazy_static! {
     static ref CHANNEL_ID_MATCHER: Regex = Regex::new(r"<#([0-9]+)>").unwrap();
     static ref MENTION_MATCHER: Regex = Regex::new(r"<@!?\d+>").unwrap();
     static ref MENTION_MATCHER_SOLO: Regex = Regex::new(r"^<@!?(\d+)>$").unwrap();
+    static ref ROLE_MENTION_MATCHER_SOLO: Regex = Regex::new(r"^<@&(\d+)>$").unwrap();
+    static ref CHANNEL_MENTION_MATCHER_SOLO: Regex = Regex::new(r"^<#(\d+)>$").unwrap();
     static ref EMOJI_MATCHER: Regex = Regex::new(r"<(a?):([^:\n]+):([0-9]+)>").unwrap();
     static ref USERNAME_WITH_DISCRIMINATOR: Regex = Regex::new(r"([!#]*)#(\d{4})").unwrap();
+    static ref DURATION_MATCHER: Regex = RegexBuilder::new(r"^(?:\d+[wdhms])+$")
+        .case_insensitive(true)
+        .build()
+        .unwrap();
+    static ref DURATION_PART_MATCHER: Regex = RegexBuilder::new(r"(\d+)([wdhms])")
+        .case_insensitive(true)
+        .build()
+        .unwrap();
     static ref JUMP_LINK_MATCHER: Regex =
-        Regex::new(r"https://(?:canary|ptb)?\.?discordapp.com/channels/\d*/(\d*)/(\d*)").unwrap();
+        Regex::new(r"https://(?:canary\.|ptb\.)?discord(?:app)?\.com/channels/(\d+)/(\d+)/(\d+)").unwrap();
+    static ref INVITE_MATCHER: Regex = RegexBuilder::new(
+        r"(?:https?://)?(?:www\.)?(?:(discord(?:app)?\.com/invite|discord\.gg)|discord\.(?:me|io|li))/([a-z0-9-]+)"
+    )
+    .case_insensitive(true)
+    .build()
+    .unwrap();
     static ref URL_MATCHER: Regex = {
         RegexBuilder::new(r"((?:https?://)[a-z0-9]+(?:[-._][a-z0-9]+)*\.[a-z]{2,5}(?::[0-9]{1,5})?(?:/[^ \n<>]*)?)")
             .case_insensitive(true)
@@ -155,6 +280,8 @@ mod tests {
 
         assert_eq!(contains_role_id(msg), true);
         assert_eq!(contains_role_id(control), false);
+        assert_eq!(get_role_mention(msg), Some(3892320392392));
+        assert_eq!(get_role_mention(control), None);
     }
 
     #[test]
@@ -164,6 +291,8 @@ mod tests {
 
         assert_eq!(contains_channel_id(msg), true);
         assert_eq!(contains_channel_id(control), false);
+        assert_eq!(get_channel_mention(msg), Some(7012116760323232));
+        assert_eq!(get_channel_mention(control), None);
     }
 
     #[test]
@@ -191,6 +320,18 @@ mod tests {
         assert_eq!(contains_url(control), false);
     }
 
+    #[test]
+    fn url_domains_work() {
+        let msg = "Look at https://Bit.ly/abc and https://cdn.example.com:8080/image.png";
+
+        assert_eq!(get_url_domains(msg), vec!["bit.ly", "cdn.example.com"]);
+        assert_eq!(is_on_domain("cdn.example.com", "example.com"), true);
+        assert_eq!(is_on_domain("example.com", ".example.com"), true);
+        assert_eq!(is_on_domain("notexample.com", "example.com"), false);
+        assert_eq!(file_extension("art.final.PNG"), "png");
+        assert_eq!(file_extension("README"), "");
+    }
+
     #[test]
     fn emote_matcher_works() {
         let msg = ":computer:";
@@ -213,6 +354,14 @@ mod tests {
         assert_eq!(contains_jump_link(msg2), true);
         assert_eq!(contains_jump_link(msg3), true);
         assert_eq!(contains_jump_link(control), false);
+
+        let msg4 = "https://discord.com/channels/365498559174410241/365498559723732993/606145193766551552";
+        assert_eq!(
+            get_jump_link(msg4),
+            Some((365498559174410241, 365498559723732993, 606145193766551552))
+        );
+        assert_eq!(get_jump_link(msg), get_jump_link(msg4));
+        assert_eq!(get_jump_link(control), None);
     }
 
     #[test]
@@ -244,4 +393,34 @@ mod tests {
 
         assert_eq!(contains_invite_link(control), false);
     }
+
+    #[test]
+    fn invite_links_are_found() {
+        let msg = "join discord.gg/abc-123 or https://discord.com/invite/XyZ and https://discord.me/server";
+        let control = "https://discord.com/channels/1/2/3 is not an invite";
+
+        assert_eq!(
+            get_invite_links(msg),
+            vec![
+                InviteLink::Discord("abc-123"),
+                InviteLink::Discord("XyZ"),
+                InviteLink::ThirdParty("https://discord.me/server")
+            ]
+        );
+        assert_eq!(get_invite_links(control), vec![]);
+    }
+
+    #[test]
+    fn duration_parsing_works() {
+        assert_eq!(parse_duration("30m"), Some(Duration::minutes(30)));
+        assert_eq!(parse_duration("1d12h"), Some(Duration::hours(36)));
+        assert_eq!(parse_duration("1W"), Some(Duration::weeks(1)));
+        assert_eq!(parse_duration("90s"), Some(Duration::seconds(90)));
+
+        assert_eq!(parse_duration("0m"), None);
+        assert_eq!(parse_duration("10"), None);
+        assert_eq!(parse_duration("m"), None);
+        assert_eq!(parse_duration("5 minutes"), None);
+        assert_eq!(parse_duration("99999999999999999999w"), None);
+    }
 }
@@ -1,4 +1,8 @@
 use chrono::{DateTime, NaiveDateTime, Utc};
+use hyper::{Body, Client, Uri};
+use hyper_rustls::HttpsConnector;
+
+use crate::error::DownloadError;
 
 pub use emoji::*;
 pub mod emoji;
@@ -62,6 +66,78 @@ pub fn clean(msg: &str, markdown: bool, links: bool, emotes: bool, lookalikes: b
     msg
 }
 
+/// Combining characters that get stacked on top of letters to turn them into zalgo text.
+fn is_zalgo(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036F | 0x0483..=0x0489 | 0x1AB0..=0x1AFF | 0x1DC0..=0x1DFF | 0x20D0..=0x20FF | 0xFE20..=0xFE2F
+    )
+}
+
+/// Maps characters that only look like a latin letter to the letter they are pretending to be.
+fn unconfuse(c: char) -> char {
+    match c {
+        // Fullwidth forms are just the ascii range shifted up
+        '\u{FF01}'..='\u{FF5E}' => std::char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        'а' | 'α' | 'А' | 'Α' => 'a',
+        'в' | 'β' | 'В' | 'Β' => 'b',
+        'с' | 'ϲ' | 'С' => 'c',
+        'ԁ' => 'd',
+        'е' | 'ε' | 'Е' | 'Ε' => 'e',
+        'һ' | 'Н' | 'Η' => 'h',
+        'і' | 'ι' | 'І' | 'Ι' => 'i',
+        'ј' | 'Ј' => 'j',
+        'к' | 'κ' | 'К' | 'Κ' => 'k',
+        'ⅼ' => 'l',
+        'м' | 'М' | 'Μ' => 'm',
+        'η' | 'Ν' => 'n',
+        'о' | 'ο' | 'О' | 'Ο' => 'o',
+        'р' | 'ρ' | 'Р' | 'Ρ' => 'p',
+        'ԛ' => 'q',
+        'ѕ' | 'Ѕ' => 's',
+        'т' | 'τ' | 'Т' | 'Τ' => 't',
+        'υ' => 'u',
+        'ν' => 'v',
+        'ԝ' => 'w',
+        'х' | 'χ' | 'Х' | 'Χ' => 'x',
+        'у' | 'γ' | 'У' | 'Υ' => 'y',
+        'Ζ' => 'z',
+        _ => c,
+    }
+}
+
+/// Undoes the usual tricks to sneak past filters: lookalike characters are swapped for the letter they imitate and
+/// zalgo is stripped off.
+///
+/// Besides the normalized text, this returns where every byte of it came from in the original, with one extra entry
+/// for the end. That way a match in the normalized text can be pointed out in the original message.
+pub fn normalize_lookalikes(msg: &str) -> (String, Vec<usize>) {
+    let mut normalized = String::with_capacity(msg.len());
+    let mut origins = Vec::with_capacity(msg.len() + 1);
+
+    for (position, c) in msg.char_indices() {
+        if is_zalgo(c) {
+            continue;
+        }
+
+        let c = unconfuse(c);
+        normalized.push(c);
+        origins.extend(std::iter::repeat(position).take(c.len_utf8()));
+    }
+    origins.push(msg.len());
+
+    (normalized, origins)
+}
+
+/// Checks if a name starts with one of the symbols that sort in front of all letters and digits, these are only used to
+/// show up at the top of the member list.
+pub fn is_hoisted(name: &str) -> bool {
+    match name.trim_start().chars().next() {
+        Some(c) => c.is_ascii_punctuation() && c < 'A',
+        None => false,
+    }
+}
+
 pub fn snowflake_timestamp(snowflake: u64) -> DateTime<Utc> {
     DateTime::from_utc(
         NaiveDateTime::from_timestamp(((snowflake as i64 >> 22) + DISCORD_EPOCH) / 1000, 0),
@@ -69,6 +145,25 @@ pub fn snowflake_timestamp(snowflake: u64) -> DateTime<Utc> {
     )
 }
 
+/// The lowest snowflake that could have been made at the given time, to compare ids against a moment in time.
+pub fn timestamp_snowflake(timestamp: DateTime<Utc>) -> u64 {
+    ((timestamp.timestamp_millis() - DISCORD_EPOCH).max(0) as u64) << 22
+}
+
+/// Fetches a file, like an attachment, over https. Only meant for the occasional file, every call sets up its own
+/// client.
+pub async fn download(url: &str) -> Result<Vec<u8>, DownloadError> {
+    let uri: Uri = url.parse().map_err(|_| DownloadError::InvalidUrl(url.to_string()))?;
+    let client: Client<_, Body> = Client::builder().build(HttpsConnector::with_native_roots());
+
+    let response = client.get(uri).await?;
+    if !response.status().is_success() {
+        return Err(DownloadError::Status(response.status()));
+    }
+
+    Ok(hyper::body::to_bytes(response.into_body()).await?.to_vec())
+}
+
 pub fn age(old: DateTime<Utc>, new: DateTime<Utc>, max_parts: i8) -> String {
     let mut seconds = new.signed_duration_since(old).num_seconds();
     let mut parts = 0;
@@ -139,3 +234,33 @@ pub fn age(old: DateTime<Utc>, new: DateTime<Utc>, max_parts: i8) -> String {
     output += &format!("{} seconds", seconds);
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookalikes_are_normalized() {
+        // cyrillic "а" and "о", fullwidth "ｄ", with a bit of zalgo on the first letter
+        let msg = "b\u{0301}\u{0316}аｄ wоrd";
+        let (normalized, origins) = normalize_lookalikes(msg);
+
+        assert_eq!(normalized, "bad word");
+        assert_eq!(origins.len(), normalized.len() + 1);
+
+        let start = normalized.find("word").unwrap();
+        assert_eq!(&msg[origins[start]..origins[normalized.len()]], "wоrd");
+        assert_eq!(&msg[origins[0]..origins[3]], "b\u{0301}\u{0316}аｄ");
+    }
+
+    #[test]
+    fn hoisted_names() {
+        assert!(is_hoisted("!cool"));
+        assert!(is_hoisted(" .hidden"));
+        assert!(is_hoisted("@everyone"));
+        assert!(!is_hoisted("Someone"));
+        assert!(!is_hoisted("2cool"));
+        assert!(!is_hoisted("_underscore"));
+        assert!(!is_hoisted(""));
+    }
+}